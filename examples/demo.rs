@@ -94,9 +94,9 @@ fn main() {
 
     let mut range1 = Range::new("range1");
     range1.set_listener(Box::new(range_listener));
-    range1.set_min(0);
-    range1.set_max(100);
-    range1.set_value(0);
+    range1.set_min(0.0);
+    range1.set_max(100.0);
+    range1.set_value(0.0);
     range1.set_stretched();
 
     let mut container1 = Container::new("container1");
@@ -118,11 +118,11 @@ impl MyRangeListener {
 
 impl RangeListener for MyRangeListener {
     fn on_update(&self, state: &mut RangeState) {
-        state.set_value(self.state.borrow().range());
+        state.set_value(f64::from(self.state.borrow().range()));
         state.set_disabled(self.state.borrow().disabled());
     }
     fn on_change(&self, state: &RangeState) {
-        self.state.borrow_mut().set_range(state.value());
+        self.state.borrow_mut().set_range(state.value() as i32);
     }
 }
 
@@ -1,17 +1,18 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use neutrino::utils::validation::Validator;
 use neutrino::widgets::button::{Button, ButtonListener, ButtonState};
 use neutrino::widgets::container::{Container, Direction, Position};
+use neutrino::widgets::form::{Form, FormListener, FormState};
 use neutrino::widgets::label::Label;
-use neutrino::widgets::textinput::{
-    InputType, TextInput, TextInputListener, TextInputState,
-};
+use neutrino::widgets::textinput::{InputType, TextInput};
 use neutrino::{App, Window};
 
 struct Login {
     username: String,
     password: String,
+    valid: bool,
     ok: bool,
 }
 
@@ -20,13 +21,15 @@ impl Login {
         Self {
             username: "".to_string(),
             password: "".to_string(),
+            valid: false,
             ok: false,
         }
     }
 
     fn check(&mut self) {
-        self.ok =
-            &self.username == "Neutrino" && &self.password == "is great !";
+        self.ok = self.valid
+            && &self.username == "Neutrino"
+            && &self.password == "is great !";
     }
 
     fn ok(&self) -> bool {
@@ -40,6 +43,10 @@ impl Login {
     fn set_password(&mut self, password: &str) {
         self.password = password.to_string();
     }
+
+    fn set_valid(&mut self, valid: bool) {
+        self.valid = valid;
+    }
 }
 
 struct MyButtonListener {
@@ -80,39 +87,28 @@ impl ButtonListener for MyButtonListener {
     }
 }
 
-struct MyUsernameListener {
-    login: Rc<RefCell<Login>>,
-}
-
-impl MyUsernameListener {
-    fn new(login: Rc<RefCell<Login>>) -> Self {
-        Self { login }
-    }
-}
-
-impl TextInputListener for MyUsernameListener {
-    fn on_update(&self, _state: &mut TextInputState) {}
-
-    fn on_change(&self, state: &TextInputState) {
-        self.login.borrow_mut().set_username(state.value());
-    }
-}
-
-struct MyPasswordListener {
+struct MyFormListener {
     login: Rc<RefCell<Login>>,
 }
 
-impl MyPasswordListener {
+impl MyFormListener {
     fn new(login: Rc<RefCell<Login>>) -> Self {
         Self { login }
     }
 }
 
-impl TextInputListener for MyPasswordListener {
-    fn on_update(&self, _state: &mut TextInputState) {}
+impl FormListener for MyFormListener {
+    fn on_update(&self, _state: &mut FormState) {}
 
-    fn on_change(&self, state: &TextInputState) {
-        self.login.borrow_mut().set_password(state.value());
+    fn on_change(&self, state: &FormState) {
+        let mut login = self.login.borrow_mut();
+        login.set_valid(state.valid());
+        if let Some(username) = state.value("username_input") {
+            login.set_username(&username.text());
+        }
+        if let Some(password) = state.value("password_input") {
+            login.set_password(&password.text());
+        }
     }
 }
 
@@ -123,18 +119,17 @@ fn main() {
     username_label.set_text("Username");
 
     let mut username_input = TextInput::new("username_input");
-
-    let username_listener = MyUsernameListener::new(Rc::clone(&login));
-    username_input.set_listener(Box::new(username_listener));
+    username_input.add_validator(Validator::required("Enter a username"));
 
     let mut password_label = Label::new("password_label");
     password_label.set_text("Password");
 
     let mut password_input = TextInput::new("password_input");
     password_input.set_input_type(InputType::Password);
-
-    let password_listener = MyPasswordListener::new(Rc::clone(&login));
-    password_input.set_listener(Box::new(password_listener));
+    password_input.add_validator(Validator::custom(
+        |value| value.text().len() >= 8,
+        "The password has at least 8 characters",
+    ));
 
     let mut button = Button::new("button");
     button.set_text("Log in");
@@ -142,13 +137,19 @@ fn main() {
     let button_listener = MyButtonListener::new(Rc::clone(&login));
     button.set_listener(Box::new(button_listener));
 
-    let mut form = Container::new("form");
-    form.set_direction(Direction::Vertical);
+    let mut fields = Container::new("fields");
+    fields.set_direction(Direction::Vertical);
+
+    fields.add(Box::new(username_label));
+    fields.add(Box::new(username_input));
+    fields.add(Box::new(password_label));
+    fields.add(Box::new(password_input));
+
+    let mut form = Form::new("form");
+    form.set_child(Box::new(fields));
 
-    form.add(Box::new(username_label));
-    form.add(Box::new(username_input));
-    form.add(Box::new(password_label));
-    form.add(Box::new(password_input));
+    let form_listener = MyFormListener::new(Rc::clone(&login));
+    form.set_listener(Box::new(form_listener));
 
     let mut root = Container::new("root");
     root.set_direction(Direction::Vertical);
@@ -185,7 +186,7 @@ fn main() {
 
     let mut window = Window::new();
     window.set_title("Login");
-    window.set_size(320, 240);
+    window.set_size(320, 280);
     window.set_child(Box::new(root));
     window.set_style(style);
     window.set_debug();
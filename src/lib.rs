@@ -46,11 +46,15 @@ use web_view::*;
 pub mod utils;
 pub mod widgets;
 
+use utils::dispatcher::Dispatcher;
 use utils::event::{Event, Key};
+use utils::notification::Notifier;
 use utils::style::{inline_script, inline_style, scss_to_css};
 use utils::theme::Theme;
 use widgets::menubar::MenuBar;
-use widgets::widget::Widget;
+use widgets::statusbar::StatusBar;
+use widgets::toolbar::ToolBar;
+use widgets::widget::{eval_tooltips, find_as, find_as_mut, Widget};
 
 use html_minifier::HTMLMinifier;
 use json;
@@ -197,6 +201,7 @@ impl App {
                     _ => {
                         window.trigger(&event);
                         window.trigger(&Event::Update);
+                        window.dispatch();
                     }
                 };
                 window.render(webview)
@@ -232,9 +237,13 @@ pub trait WindowListener {
 /// style: String
 /// child: Option<Box<dyn Widget>>
 /// menubar: Option<MenuBar>
+/// statusbar: Option<StatusBar>
+/// toolbar: Option<ToolBar>
 /// listener: Option<Box<dyn WindowListener>>
 /// timer: Option<u32>
 /// keys: HashSet<Key>
+/// dispatcher: Dispatcher
+/// notifier: Notifier
 /// ```
 ///
 /// # Default values
@@ -249,9 +258,13 @@ pub trait WindowListener {
 /// style: "".to_string()
 /// child: None
 /// menubar: None
+/// statusbar: None
+/// toolbar: None
 /// listener: None
 /// timer: None
 /// keys: HashSet::new()
+/// dispatcher: Dispatcher::new()
+/// notifier: Notifier::new()
 /// ```
 ///
 /// ## Example
@@ -278,9 +291,13 @@ pub struct Window {
     style: String,
     child: Option<Box<dyn Widget>>,
     menubar: Option<MenuBar>,
+    statusbar: Option<StatusBar>,
+    toolbar: Option<ToolBar>,
     listener: Option<Box<dyn WindowListener>>,
     timer: Option<u32>,
     keys: HashSet<Key>,
+    dispatcher: Dispatcher,
+    notifier: Notifier,
 }
 
 impl Default for Window {
@@ -295,9 +312,13 @@ impl Default for Window {
             style: "".to_string(),
             child: None,
             menubar: None,
+            statusbar: None,
+            toolbar: None,
             listener: None,
             timer: None,
             keys: HashSet::new(),
+            dispatcher: Dispatcher::new(),
+            notifier: Notifier::new(),
         }
     }
 }
@@ -318,6 +339,16 @@ impl Window {
         self.menubar = Some(menubar);
     }
 
+    /// Set the statusbar
+    pub fn set_statusbar(&mut self, statusbar: StatusBar) {
+        self.statusbar = Some(statusbar);
+    }
+
+    /// Set the toolbar
+    pub fn set_toolbar(&mut self, toolbar: ToolBar) {
+        self.toolbar = Some(toolbar);
+    }
+
     /// Set the title
     pub fn set_title(&mut self, title: &str) {
         self.title = title.to_string();
@@ -361,6 +392,66 @@ impl Window {
         self.timer = Some(period);
     }
 
+    /// Get a handle to mutate the widgets by name from any listener
+    pub fn dispatcher(&self) -> Dispatcher {
+        self.dispatcher.clone()
+    }
+
+    /// Get a handle to display toasts from any listener
+    pub fn notifier(&self) -> Notifier {
+        self.notifier.clone()
+    }
+
+    /// Find a widget by name in the widget tree, the toolbar and the
+    /// statusbar, and downcast it to the type T
+    pub fn find<T: Widget + 'static>(&self, name: &str) -> Option<&T> {
+        let mut roots = vec![];
+        if let Some(child) = &self.child {
+            roots.push(child.as_ref());
+        }
+        if let Some(toolbar) = &self.toolbar {
+            roots.extend(toolbar.widgets());
+        }
+        if let Some(statusbar) = &self.statusbar {
+            roots.push(statusbar as &dyn Widget);
+        }
+        roots.into_iter().find_map(|root| find_as(root, name))
+    }
+
+    /// Find a mutable widget by name in the widget tree, the toolbar and the
+    /// statusbar, and downcast it to the type T
+    pub fn find_mut<T: Widget + 'static>(
+        &mut self,
+        name: &str,
+    ) -> Option<&mut T> {
+        let mut roots = vec![];
+        if let Some(child) = &mut self.child {
+            roots.push(child.as_mut() as &mut dyn Widget);
+        }
+        if let Some(toolbar) = &mut self.toolbar {
+            roots.extend(toolbar.widgets_mut());
+        }
+        if let Some(statusbar) = &mut self.statusbar {
+            roots.push(statusbar as &mut dyn Widget);
+        }
+        roots.into_iter().find_map(|root| find_as_mut(root, name))
+    }
+
+    /// Apply the mutations queued by the dispatcher
+    fn dispatch(&mut self) {
+        let mut roots = vec![];
+        if let Some(child) = &mut self.child {
+            roots.push(child.as_mut() as &mut dyn Widget);
+        }
+        if let Some(toolbar) = &mut self.toolbar {
+            roots.extend(toolbar.widgets_mut());
+        }
+        if let Some(statusbar) = &mut self.statusbar {
+            roots.push(statusbar as &mut dyn Widget);
+        }
+        self.dispatcher.apply(roots);
+    }
+
     /// Render the menubar and widget tree
     fn render(&self, webview: &mut WebView<&str>) -> WVResult {
         let rendered = format!(
@@ -374,16 +465,40 @@ impl Window {
         })
     }
 
-    /// Return the HTML representation of the menubar and the widget tree
+    /// Return the HTML representation of the menubar, the statusbar, the
+    /// toolbar, the widget tree, the toasts and the tooltips
     fn eval(&self) -> String {
-        match (&self.menubar, &self.child) {
-            (Some(menubar), Some(child)) => {
-                format!("{}{}", menubar.eval(), child.eval())
+        let menubar = match &self.menubar {
+            Some(menubar) => menubar.eval(),
+            None => "".to_string(),
+        };
+        let (toolbar, mut tooltips) = match &self.toolbar {
+            Some(toolbar) => (toolbar.eval(), toolbar.eval_tooltips()),
+            None => ("".to_string(), "".to_string()),
+        };
+        let statusbar = match &self.statusbar {
+            Some(statusbar) => {
+                tooltips.push_str(&eval_tooltips(statusbar));
+                statusbar.eval()
             }
-            (None, Some(child)) => child.eval().to_string(),
-            (Some(menubar), None) => menubar.eval().to_string(),
-            (None, None) => "".to_string(),
-        }
+            None => "".to_string(),
+        };
+        let child = match &self.child {
+            Some(child) => {
+                tooltips.push_str(&eval_tooltips(child.as_ref()));
+                child.eval()
+            }
+            None => "".to_string(),
+        };
+        format!(
+            r#"{}{}{}{}{}<div class="tooltips">{}</div>"#,
+            menubar,
+            statusbar,
+            toolbar,
+            child,
+            self.notifier.eval(),
+            tooltips
+        )
     }
 
     /// Trigger the events in the widget tree
@@ -400,6 +515,13 @@ impl Window {
             (Some(menubar), None) => menubar.trigger(event),
             (None, None) => (),
         };
+        if let Some(toolbar) = &mut self.toolbar {
+            toolbar.trigger(event);
+        }
+        if let Some(statusbar) = &mut self.statusbar {
+            statusbar.trigger(event);
+        }
+        self.notifier.trigger(event);
         match &self.listener {
             Some(listener) => match event {
                 Event::Tick => listener.on_tick(),
@@ -414,3 +536,41 @@ impl Window {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use widgets::container::Container;
+    use widgets::label::Label;
+
+    #[test]
+    fn dispatch_reaches_every_root() {
+        let mut container = Container::new("container");
+        container.add(Box::new(Label::new("content")));
+        let mut toolbar = ToolBar::new();
+        toolbar.add_widget(Box::new(Label::new("tool")));
+        let mut window = Window::new();
+        window.set_child(Box::new(container));
+        window.set_toolbar(toolbar);
+        window.set_statusbar(StatusBar::new("statusbar"));
+
+        let dispatcher = window.dispatcher();
+        dispatcher.dispatch::<Label, _>("content", |label| {
+            label.set_text("Content");
+        });
+        dispatcher.dispatch::<Label, _>("tool", |label| {
+            label.set_text("Tool");
+        });
+        dispatcher.dispatch::<StatusBar, _>("statusbar", |statusbar| {
+            statusbar.set_message("Saved");
+        });
+        window.dispatch();
+
+        let content = window.find::<Label>("content").unwrap();
+        assert_eq!(content.state().text(), "Content");
+        let tool = window.find::<Label>("tool").unwrap();
+        assert_eq!(tool.state().text(), "Tool");
+        let statusbar = window.find::<StatusBar>("statusbar").unwrap();
+        assert_eq!(statusbar.state().message(), "Saved");
+    }
+}
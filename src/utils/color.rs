@@ -0,0 +1,234 @@
+use std::fmt;
+
+/// # A color with an alpha channel
+///
+/// A Color is displayed in the hexadecimal notation, and can be parsed from
+/// the hexadecimal, `rgb()`, `rgba()`, `hsl()` and `hsla()` CSS notations.
+/// Its text representations are valid CSS colors, so they can be given to
+/// `Image::set_background` or used in the style of a widget.
+///
+/// ## Example
+///
+/// ```
+/// use neutrino::utils::color::Color;
+///
+/// let color = Color::rgb(255, 128, 0);
+/// assert_eq!(color.to_string(), "#ff8000");
+/// assert_eq!(Color::parse("#ff8000"), Some(color));
+/// assert_eq!(Color::parse("rgb(255, 128, 0)"), Some(color));
+/// assert_eq!(Color::parse("hsl(30, 100%, 50%)"), Some(color));
+///
+/// let color = Color::rgba(0, 0, 255, 0.5);
+/// assert_eq!(color.rgb_string(), "rgba(0, 0, 255, 0.5)");
+/// assert_eq!(color.hsl_string(), "hsla(240, 100%, 50%, 0.5)");
+/// assert_eq!(color.hex(), "#0000ff80");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Color {
+    red: u8,
+    green: u8,
+    blue: u8,
+    alpha: f64,
+}
+
+impl Color {
+    /// Create an opaque Color from its red, green and blue channels
+    pub fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self::rgba(red, green, blue, 1.0)
+    }
+
+    /// Create a Color from its red, green, blue and alpha channels, the
+    /// alpha being kept between 0 and 1
+    pub fn rgba(red: u8, green: u8, blue: u8, alpha: f64) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            alpha: alpha.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Create an opaque Color from its hue in degrees, and its saturation
+    /// and lightness in percents
+    pub fn hsl(hue: f64, saturation: f64, lightness: f64) -> Self {
+        Self::hsla(hue, saturation, lightness, 1.0)
+    }
+
+    /// Create a Color from its hue in degrees, its saturation and lightness
+    /// in percents, and its alpha between 0 and 1
+    pub fn hsla(hue: f64, saturation: f64, lightness: f64, alpha: f64) -> Self {
+        let hue = hue.rem_euclid(360.0);
+        let saturation = saturation.clamp(0.0, 100.0) / 100.0;
+        let lightness = lightness.clamp(0.0, 100.0) / 100.0;
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        let x = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+        let (red, green, blue) = match (hue / 60.0) as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = lightness - chroma / 2.0;
+        let channel = |value: f64| ((value + m) * 255.0).round() as u8;
+        Self::rgba(channel(red), channel(green), channel(blue), alpha)
+    }
+
+    /// Parse a color in the hexadecimal, `rgb()`, `rgba()`, `hsl()` or
+    /// `hsla()` notation
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim().to_lowercase();
+        if let Some(hex) = text.strip_prefix('#') {
+            return parse_hex(hex);
+        }
+        let (name, arguments) = text.split_at(text.find('(')?);
+        let arguments = arguments.strip_prefix('(')?.strip_suffix(')')?;
+        let values = arguments
+            .split(',')
+            .map(|value| value.trim().trim_end_matches('%').parse::<f64>())
+            .collect::<Result<Vec<f64>, _>>()
+            .ok()?;
+        let channel = |value: f64| value.clamp(0.0, 255.0).round() as u8;
+        match (name.trim(), values.as_slice()) {
+            ("rgb", [red, green, blue]) => {
+                Some(Self::rgb(channel(*red), channel(*green), channel(*blue)))
+            }
+            ("rgba", [red, green, blue, alpha]) => Some(Self::rgba(
+                channel(*red),
+                channel(*green),
+                channel(*blue),
+                *alpha,
+            )),
+            ("hsl", [hue, saturation, lightness]) => {
+                Some(Self::hsl(*hue, *saturation, *lightness))
+            }
+            ("hsla", [hue, saturation, lightness, alpha]) => {
+                Some(Self::hsla(*hue, *saturation, *lightness, *alpha))
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the red channel
+    pub fn red(&self) -> u8 {
+        self.red
+    }
+
+    /// Get the green channel
+    pub fn green(&self) -> u8 {
+        self.green
+    }
+
+    /// Get the blue channel
+    pub fn blue(&self) -> u8 {
+        self.blue
+    }
+
+    /// Get the alpha channel, between 0 and 1
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Get the hue in degrees, and the saturation and the lightness in
+    /// percents
+    pub fn to_hsl(&self) -> (f64, f64, f64) {
+        let red = f64::from(self.red) / 255.0;
+        let green = f64::from(self.green) / 255.0;
+        let blue = f64::from(self.blue) / 255.0;
+        let max = red.max(green).max(blue);
+        let min = red.min(green).min(blue);
+        let chroma = max - min;
+        let lightness = (max + min) / 2.0;
+        if chroma == 0.0 {
+            return (0.0, 0.0, lightness * 100.0);
+        }
+        let saturation = chroma / (1.0 - (2.0 * lightness - 1.0).abs());
+        let hue = if max == red {
+            ((green - blue) / chroma).rem_euclid(6.0)
+        } else if max == green {
+            (blue - red) / chroma + 2.0
+        } else {
+            (red - green) / chroma + 4.0
+        };
+        (hue * 60.0, saturation * 100.0, lightness * 100.0)
+    }
+
+    /// Get the hexadecimal notation, the alpha being written only if the
+    /// color is not opaque
+    pub fn hex(&self) -> String {
+        let hex =
+            format!("#{:02x}{:02x}{:02x}", self.red, self.green, self.blue);
+        if self.alpha < 1.0 {
+            format!("{}{:02x}", hex, (self.alpha * 255.0).round() as u8)
+        } else {
+            hex
+        }
+    }
+
+    /// Get the `rgb()` notation, or the `rgba()` one if the color is not
+    /// opaque
+    pub fn rgb_string(&self) -> String {
+        if self.alpha < 1.0 {
+            format!(
+                "rgba({}, {}, {}, {})",
+                self.red,
+                self.green,
+                self.blue,
+                round(self.alpha, 2)
+            )
+        } else {
+            format!("rgb({}, {}, {})", self.red, self.green, self.blue)
+        }
+    }
+
+    /// Get the `hsl()` notation, or the `hsla()` one if the color is not
+    /// opaque
+    pub fn hsl_string(&self) -> String {
+        let (hue, saturation, lightness) = self.to_hsl();
+        let hsl = format!(
+            "{}, {}%, {}%",
+            hue.round(),
+            saturation.round(),
+            lightness.round()
+        );
+        if self.alpha < 1.0 {
+            format!("hsla({}, {})", hsl, round(self.alpha, 2))
+        } else {
+            format!("hsl({})", hsl)
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.hex())
+    }
+}
+
+/// Parse the digits of a color in the hexadecimal notation
+fn parse_hex(hex: &str) -> Option<Color> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let digits = match hex.len() {
+        3 | 4 => hex.chars().flat_map(|c| vec![c, c]).collect::<String>(),
+        6 | 8 => hex.to_string(),
+        _ => return None,
+    };
+    let channel = |index: usize| {
+        u8::from_str_radix(&digits[index * 2..index * 2 + 2], 16).ok()
+    };
+    let alpha = if digits.len() == 8 {
+        f64::from(channel(3)?) / 255.0
+    } else {
+        1.0
+    };
+    Some(Color::rgba(channel(0)?, channel(1)?, channel(2)?, alpha))
+}
+
+/// Round a number to the given number of decimals
+fn round(value: f64, decimals: i32) -> f64 {
+    let factor = 10_f64.powi(decimals);
+    (value * factor).round() / factor
+}
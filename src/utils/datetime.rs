@@ -0,0 +1,322 @@
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The names of the months
+pub const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// # A day of the week
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// Get the weekday from its index, Monday being 0, modulo 7
+    pub fn from_index(index: u32) -> Self {
+        match index % 7 {
+            0 => Weekday::Monday,
+            1 => Weekday::Tuesday,
+            2 => Weekday::Wednesday,
+            3 => Weekday::Thursday,
+            4 => Weekday::Friday,
+            5 => Weekday::Saturday,
+            _ => Weekday::Sunday,
+        }
+    }
+
+    /// Get the index of the weekday, Monday being 0
+    pub fn index(self) -> u32 {
+        self as u32
+    }
+
+    /// Get the short name of the weekday
+    pub fn short(self) -> &'static str {
+        match self {
+            Weekday::Monday => "Mo",
+            Weekday::Tuesday => "Tu",
+            Weekday::Wednesday => "We",
+            Weekday::Thursday => "Th",
+            Weekday::Friday => "Fr",
+            Weekday::Saturday => "Sa",
+            Weekday::Sunday => "Su",
+        }
+    }
+}
+
+/// # A date of the proleptic Gregorian calendar
+///
+/// A Date is displayed and parsed in the ISO 8601 format `YYYY-MM-DD`.
+///
+/// ## Example
+///
+/// ```
+/// use neutrino::utils::datetime::{Date, Weekday};
+///
+/// let date = Date::new(2020, 1, 31).unwrap();
+/// assert_eq!(date.weekday(), Weekday::Friday);
+/// assert_eq!(date.add_days(1), Date::new(2020, 2, 1).unwrap());
+/// assert_eq!(date.add_months(1), Date::new(2020, 2, 29).unwrap());
+/// assert_eq!(Date::parse("2020-01-31"), Some(date));
+/// assert_eq!(date.to_string(), "2020-01-31");
+/// assert_eq!(Date::new(2019, 2, 29), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// Create a Date, return None if the day does not exist
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        if (1..=12).contains(&month)
+            && (1..=days_in_month(year, month)).contains(&day)
+        {
+            Some(Self { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    /// Get the current date in UTC
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs() as i64)
+            .unwrap_or(0);
+        Self::from_days(seconds.div_euclid(86400))
+    }
+
+    /// Parse a date in the `YYYY-MM-DD` format
+    pub fn parse(text: &str) -> Option<Self> {
+        let values = text.trim().splitn(3, '-').collect::<Vec<&str>>();
+        match values.as_slice() {
+            [year, month, day] => Self::new(
+                year.parse().ok()?,
+                month.parse().ok()?,
+                day.parse().ok()?,
+            ),
+            _ => None,
+        }
+    }
+
+    /// Get the year
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Get the month, from 1 to 12
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Get the day of the month, from 1 to 31
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Get the day of the week
+    pub fn weekday(&self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        Weekday::from_index((self.days() + 3).rem_euclid(7) as u32)
+    }
+
+    /// Get the number of days of the month
+    pub fn days_in_month(&self) -> u32 {
+        days_in_month(self.year, self.month)
+    }
+
+    /// Get the first day of the month
+    pub fn first_of_month(&self) -> Self {
+        Self { day: 1, ..*self }
+    }
+
+    /// Add a number of days, which can be negative
+    pub fn add_days(&self, days: i64) -> Self {
+        Self::from_days(self.days() + days)
+    }
+
+    /// Add a number of months, which can be negative, the day being kept
+    /// in the bounds of the new month
+    pub fn add_months(&self, months: i32) -> Self {
+        let index = self.year * 12 + self.month as i32 - 1 + months;
+        let year = index.div_euclid(12);
+        let month = index.rem_euclid(12) as u32 + 1;
+        Self {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+
+    /// Get the number of days since 1970-01-01
+    fn days(&self) -> i64 {
+        let year = i64::from(self.year) - if self.month <= 2 { 1 } else { 0 };
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = i64::from((self.month + 9) % 12);
+        let day_of_year = (153 * month + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4
+            - year_of_era / 100
+            + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Create a Date from a number of days since 1970-01-01
+    fn from_days(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524
+            - day_of_era / 146_096)
+            / 365;
+        let day_of_year = day_of_era
+            - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month + 2) / 5 + 1;
+        let month = if month < 10 { month + 3 } else { month - 9 };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+        Self {
+            year: year as i32,
+            month: month as u32,
+            day: day as u32,
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Get the number of days of a month
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// # A time of the day
+///
+/// A Time is displayed and parsed in the `HH:MM:SS` format, the seconds
+/// being optional when parsing.
+///
+/// ## Example
+///
+/// ```
+/// use neutrino::utils::datetime::Time;
+///
+/// let time = Time::new(23, 45, 0).unwrap();
+/// assert_eq!(time.add_seconds(30 * 60), Time::new(0, 15, 0).unwrap());
+/// assert_eq!(Time::parse("23:45"), Some(time));
+/// assert_eq!(time.to_string(), "23:45:00");
+/// assert_eq!(Time::new(24, 0, 0), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    hour: u32,
+    minute: u32,
+    second: u32,
+}
+
+impl Time {
+    /// Create a Time, return None if the time does not exist
+    pub fn new(hour: u32, minute: u32, second: u32) -> Option<Self> {
+        if hour < 24 && minute < 60 && second < 60 {
+            Some(Self {
+                hour,
+                minute,
+                second,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Parse a time in the `HH:MM` or `HH:MM:SS` format
+    pub fn parse(text: &str) -> Option<Self> {
+        let values = text.trim().splitn(3, ':').collect::<Vec<&str>>();
+        match values.as_slice() {
+            [hour, minute] => {
+                Self::new(hour.parse().ok()?, minute.parse().ok()?, 0)
+            }
+            [hour, minute, second] => Self::new(
+                hour.parse().ok()?,
+                minute.parse().ok()?,
+                second.parse().ok()?,
+            ),
+            _ => None,
+        }
+    }
+
+    /// Get the hour, from 0 to 23
+    pub fn hour(&self) -> u32 {
+        self.hour
+    }
+
+    /// Get the minute, from 0 to 59
+    pub fn minute(&self) -> u32 {
+        self.minute
+    }
+
+    /// Get the second, from 0 to 59
+    pub fn second(&self) -> u32 {
+        self.second
+    }
+
+    /// Get the number of seconds since midnight
+    pub fn seconds(&self) -> u32 {
+        self.hour * 3600 + self.minute * 60 + self.second
+    }
+
+    /// Add a number of seconds, which can be negative, wrapping around
+    /// midnight
+    pub fn add_seconds(&self, seconds: i64) -> Self {
+        let seconds =
+            (i64::from(self.seconds()) + seconds).rem_euclid(86400) as u32;
+        Self {
+            hour: seconds / 3600,
+            minute: seconds / 60 % 60,
+            second: seconds % 60,
+        }
+    }
+
+    /// Format the time as `HH:MM`, or as `HH:MM:SS` if seconds is true
+    pub fn format(&self, seconds: bool) -> String {
+        if seconds {
+            self.to_string()
+        } else {
+            format!("{:02}:{:02}", self.hour, self.minute)
+        }
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+}
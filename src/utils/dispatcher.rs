@@ -0,0 +1,77 @@
+use crate::widgets::widget::{find_mut, Widget};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type Mutation = Box<dyn FnOnce(&mut dyn Widget)>;
+
+/// # A handle used to mutate any widget of a Window by its name
+///
+/// A Dispatcher can be cloned and given to any listener, including a
+/// WindowListener. The mutations are queued and applied by the Window once
+/// the current event has been handled, before the widget tree is rendered.
+/// A mutation is skipped if the named widget does not exist or is not of the
+/// requested type.
+///
+/// ## Example
+///
+/// ```
+/// use neutrino::utils::dispatcher::Dispatcher;
+/// use neutrino::widgets::button::{ButtonListener, ButtonState};
+/// use neutrino::widgets::label::Label;
+///
+///
+/// struct MyButtonListener {
+///     dispatcher: Dispatcher,
+/// }
+///
+/// impl ButtonListener for MyButtonListener {
+///     fn on_change(&self, _state: &ButtonState) {
+///         self.dispatcher.dispatch::<Label, _>("status", |label| {
+///             label.set_text("Saved");
+///         });
+///     }
+///
+///     fn on_update(&self, _state: &mut ButtonState) {}
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct Dispatcher {
+    mutations: Rc<RefCell<Vec<(String, Mutation)>>>,
+}
+
+impl Dispatcher {
+    /// Create a Dispatcher
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Queue a mutation of the widget named `name`
+    pub fn dispatch<T, F>(&self, name: &str, mutation: F)
+    where
+        T: Widget + 'static,
+        F: FnOnce(&mut T) + 'static,
+    {
+        self.mutations.borrow_mut().push((
+            name.to_string(),
+            Box::new(move |widget: &mut dyn Widget| {
+                if let Some(widget) = widget.as_any_mut().downcast_mut::<T>() {
+                    mutation(widget);
+                }
+            }),
+        ));
+    }
+
+    /// Apply the queued mutations to the first widget found by name in the
+    /// given widget trees
+    pub fn apply(&self, mut roots: Vec<&mut dyn Widget>) {
+        let mutations = self.mutations.replace(vec![]);
+        for (name, mutation) in mutations {
+            let widget = roots
+                .iter_mut()
+                .find_map(|root| find_mut(&mut **root, &name));
+            if let Some(widget) = widget {
+                mutation(widget);
+            }
+        }
+    }
+}
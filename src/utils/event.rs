@@ -97,6 +97,11 @@ pub enum Key {
     Space,
     Escape,
     Enter,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Asterisk,
 }
 
 impl Key {
@@ -143,13 +148,18 @@ impl Key {
             "ArrowRight" => Some(Key::Right),
             "ArrowUp" => Some(Key::Up),
             "ArrowDown" => Some(Key::Down),
-            "Shift" => Some(Key::Space),
+            "Shift" => Some(Key::Shift),
             "Control" => Some(Key::Control),
             "Super" => Some(Key::Super),
             "Alt" => Some(Key::Alt),
-            "Space" => Some(Key::Space),
+            "Space" | " " => Some(Key::Space),
             "Escape" => Some(Key::Escape),
             "Enter" => Some(Key::Enter),
+            "Home" => Some(Key::Home),
+            "End" => Some(Key::End),
+            "PageUp" => Some(Key::PageUp),
+            "PageDown" => Some(Key::PageDown),
+            "*" => Some(Key::Asterisk),
             _ => None,
         }
     }
@@ -0,0 +1,7 @@
+/// Escape the characters having a meaning in an HTML text or attribute value
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
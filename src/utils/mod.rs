@@ -1,5 +1,13 @@
+pub mod color;
+pub mod datetime;
+pub mod dispatcher;
 pub mod event;
+pub mod html;
 pub mod icon;
+pub mod notification;
 pub mod pixmap;
 pub mod style;
+pub mod svg;
 pub mod theme;
+pub mod tooltip;
+pub mod validation;
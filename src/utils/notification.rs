@@ -0,0 +1,275 @@
+use crate::utils::event::Event;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// The source of the events sent by the toasts
+const SOURCE: &str = "neutrino:toasts";
+
+/// # The severity of a Toast
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    /// Get the CSS class of the toast
+    pub fn css(self) -> &'static str {
+        match self {
+            Severity::Info => "toast-info",
+            Severity::Success => "toast-success",
+            Severity::Warning => "toast-warning",
+            Severity::Error => "toast-error",
+        }
+    }
+}
+
+/// # A non-modal notification displayed over the window
+///
+/// A Toast is dismissed by its close button, once its timeout has expired,
+/// or when its action is triggered. A Toast without timeout stays until the
+/// user dismisses it.
+///
+/// ## Fields
+///
+/// ```text
+/// message: String
+/// severity: Severity
+/// timeout: Option<u32>
+/// action: Option<(String, Box<dyn FnOnce()>)>
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// message: message.to_string()
+/// severity: Severity::Info
+/// timeout: Some(4000)
+/// action: None
+/// ```
+///
+/// ## Style
+///
+/// ```text
+/// div.toasts
+///     div.toast.toast-(info|success|warning|error)
+///         div.toast-message
+///         div.toast-action
+///         div.toast-close
+/// ```
+pub struct Toast {
+    message: String,
+    severity: Severity,
+    timeout: Option<u32>,
+    action: Option<(String, Box<dyn FnOnce()>)>,
+}
+
+impl Toast {
+    /// Create a Toast
+    pub fn new(message: &str) -> Self {
+        Self {
+            message: message.to_string(),
+            severity: Severity::Info,
+            timeout: Some(4000),
+            action: None,
+        }
+    }
+
+    /// Get the message
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Get the severity
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Get the timeout in milliseconds
+    pub fn timeout(&self) -> Option<u32> {
+        self.timeout
+    }
+
+    /// Get the label of the action
+    pub fn action(&self) -> Option<&str> {
+        self.action.as_ref().map(|(label, _)| label.as_ref())
+    }
+
+    /// Set the severity
+    pub fn set_severity(&mut self, severity: Severity) {
+        self.severity = severity;
+    }
+
+    /// Set the timeout in milliseconds
+    pub fn set_timeout(&mut self, timeout: u32) {
+        self.timeout = Some(timeout);
+    }
+
+    /// Remove the timeout, the toast staying until it is dismissed
+    pub fn set_persistent(&mut self) {
+        self.timeout = None;
+    }
+
+    /// Set the action, a button labelled `label` calling `action`
+    pub fn set_action<F>(&mut self, label: &str, action: F)
+    where
+        F: FnOnce() + 'static,
+    {
+        self.action = Some((label.to_string(), Box::new(action)));
+    }
+
+    /// Return the HTML representation of the toast
+    fn eval(&self, id: u32) -> String {
+        let action = match self.action() {
+            Some(label) => format!(
+                r#"<div class="toast-action" onclick="{}">{}</div>"#,
+                Event::change_js(SOURCE, &format!("'action;{}'", id)),
+                label,
+            ),
+            None => "".to_string(),
+        };
+        let timeout = match self.timeout {
+            Some(timeout) => format!(r#"data-timeout="{}""#, timeout),
+            None => "".to_string(),
+        };
+        format!(
+            r#"
+            <div id="toast-{}" class="toast {}" role="status" data-id="{}"
+                {}>
+                <div class="toast-message">{}</div>
+                {}
+                <div class="toast-close" onclick="{}">×</div>
+            </div>
+            "#,
+            id,
+            self.severity.css(),
+            id,
+            timeout,
+            self.message,
+            action,
+            Event::change_js(SOURCE, &format!("'dismiss;{}'", id)),
+        )
+    }
+}
+
+#[derive(Default)]
+struct Toasts {
+    next: u32,
+    toasts: Vec<(u32, Toast)>,
+}
+
+/// # A handle used to display toasts from any listener
+///
+/// A Notifier can be cloned and given to any listener, including a
+/// WindowListener. The toasts are stacked in the window, the most recent
+/// one at the bottom.
+///
+/// ## Example
+///
+/// ```
+/// use neutrino::utils::notification::{Notifier, Severity, Toast};
+/// use neutrino::widgets::button::{ButtonListener, ButtonState};
+///
+///
+/// struct MyButtonListener {
+///     notifier: Notifier,
+/// }
+///
+/// impl ButtonListener for MyButtonListener {
+///     fn on_change(&self, _state: &ButtonState) {
+///         let mut toast = Toast::new("3 files failed");
+///         toast.set_severity(Severity::Error);
+///         toast.set_persistent();
+///         toast.set_action("Retry", || println!("Retrying"));
+///         self.notifier.notify(toast);
+///     }
+///
+///     fn on_update(&self, _state: &mut ButtonState) {}
+/// }
+///
+///
+/// fn main() {
+///     let notifier = Notifier::new();
+///     notifier.notify(Toast::new("Saved"));
+///     assert_eq!(notifier.len(), 1);
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct Notifier {
+    toasts: Rc<RefCell<Toasts>>,
+}
+
+impl Notifier {
+    /// Create a Notifier
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Display a toast
+    pub fn notify(&self, toast: Toast) {
+        let mut toasts = self.toasts.borrow_mut();
+        let id = toasts.next;
+        toasts.next += 1;
+        toasts.toasts.push((id, toast));
+    }
+
+    /// Get the number of displayed toasts
+    pub fn len(&self) -> usize {
+        self.toasts.borrow().toasts.len()
+    }
+
+    /// Return true if no toast is displayed
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Dismiss all the toasts
+    pub fn clear(&self) {
+        self.toasts.borrow_mut().toasts.clear();
+    }
+
+    /// Return the HTML representation of the toasts
+    pub fn eval(&self) -> String {
+        let mut html = r#"<div class="toasts">"#.to_string();
+        for (id, toast) in self.toasts.borrow().toasts.iter() {
+            html.push_str(&toast.eval(*id));
+        }
+        html.push_str("</div>");
+        html
+    }
+
+    /// Trigger functions depending on the event
+    pub fn trigger(&self, event: &Event) {
+        if let Event::Change { source, value } = event {
+            if source == SOURCE {
+                self.on_change(value);
+            }
+        }
+    }
+
+    /// Dismiss a toast, calling its action if requested
+    fn on_change(&self, value: &str) {
+        let values = value.split(';').collect::<Vec<&str>>();
+        let (action, id) = match values.as_slice() {
+            [action, id] => match id.parse::<u32>() {
+                Ok(id) => (*action, id),
+                Err(_) => return,
+            },
+            _ => return,
+        };
+        let toast = {
+            let mut toasts = self.toasts.borrow_mut();
+            match toasts.toasts.iter().position(|(i, _)| *i == id) {
+                Some(index) => toasts.toasts.remove(index).1,
+                None => return,
+            }
+        };
+        if action == "action" {
+            if let Some((_, action)) = toast.action {
+                action();
+            }
+        }
+    }
+}
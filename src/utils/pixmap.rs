@@ -1,4 +1,5 @@
 use crate::utils::icon::Icon;
+use crate::utils::svg::Svg;
 use base64::encode;
 use std::fs;
 use std::path::Path;
@@ -6,17 +7,21 @@ use std::path::Path;
 /// # A model for an image
 ///
 /// As a webview does not have access to the local file system, the given
-/// images are encoded into text (Base64) to be displayed.
+/// images are encoded into text (Base64) to be displayed. A monochrome SVG
+/// pixmap is inlined instead, and drawn with the current text colour when
+/// used as an icon.
 ///
 /// ## Fields
 ///
 /// ```text
 /// data: String
 /// extension: String
+/// svg: Option<Svg>
 /// ```
 pub struct Pixmap {
     data: String,
     extension: String,
+    svg: Option<Svg>,
 }
 
 impl Pixmap {
@@ -25,6 +30,7 @@ impl Pixmap {
         Pixmap {
             data: data.to_string(),
             extension: extension.to_string(),
+            svg: None,
         }
     }
 
@@ -38,14 +44,22 @@ impl Pixmap {
             Ok(file) => encode(&file),
             Err(_) => "".to_string(),
         };
-        Self { data, extension }
+        Self {
+            data,
+            extension,
+            svg: None,
+        }
     }
 
     /// Create a Pixmap from an Icon
     pub fn from_icon(icon: Box<dyn Icon>) -> Self {
         let extension = icon.extension();
         let data = icon.data();
-        Self { data, extension }
+        Self {
+            data,
+            extension,
+            svg: None,
+        }
     }
 
     /// Get the data
@@ -60,4 +74,36 @@ impl Pixmap {
             ext => ext,
         }
     }
+
+    /// Get the monochrome flag
+    pub fn monochrome(&self) -> bool {
+        self.svg.is_some()
+    }
+
+    /// Set the monochrome flag
+    ///
+    /// It has no effect on a pixmap which is not an SVG image.
+    pub fn set_monochrome(&mut self, monochrome: bool) {
+        self.svg = if monochrome {
+            Svg::from_pixmap(self)
+        } else {
+            None
+        };
+    }
+
+    /// Return the HTML representation of the pixmap used as an icon
+    ///
+    /// A monochrome pixmap is inlined and drawn with the current text
+    /// colour, so it follows the theme. Any other image is displayed in an
+    /// img tag.
+    pub fn eval_icon(&self) -> String {
+        match &self.svg {
+            Some(svg) => svg.eval("icon"),
+            None => format!(
+                r#"<img src="data:image/{};base64,{}" />"#,
+                self.extension(),
+                self.data()
+            ),
+        }
+    }
 }
@@ -0,0 +1,475 @@
+use crate::utils::html::escape;
+use crate::utils::icon::Icon;
+use crate::utils::pixmap::Pixmap;
+use base64::decode;
+use roxmltree::{Document, Node};
+use std::fs;
+
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+const XLINK_NAMESPACE: &str = "http://www.w3.org/1999/xlink";
+
+/// The elements kept by the sanitizer, any other element being removed with
+/// its content
+const ELEMENTS: &[&str] = &[
+    "svg",
+    "g",
+    "defs",
+    "symbol",
+    "use",
+    "path",
+    "rect",
+    "circle",
+    "ellipse",
+    "line",
+    "polyline",
+    "polygon",
+    "text",
+    "tspan",
+    "title",
+    "desc",
+    "image",
+    "linearGradient",
+    "radialGradient",
+    "stop",
+    "pattern",
+    "clipPath",
+    "mask",
+    "marker",
+    "filter",
+    "feBlend",
+    "feColorMatrix",
+    "feComposite",
+    "feFlood",
+    "feGaussianBlur",
+    "feMerge",
+    "feMergeNode",
+    "feOffset",
+];
+
+/// The attributes kept by the sanitizer, besides the links
+const ATTRIBUTES: &[&str] = &[
+    "id",
+    "viewBox",
+    "preserveAspectRatio",
+    "x",
+    "y",
+    "x1",
+    "y1",
+    "x2",
+    "y2",
+    "cx",
+    "cy",
+    "r",
+    "rx",
+    "ry",
+    "fx",
+    "fy",
+    "dx",
+    "dy",
+    "width",
+    "height",
+    "d",
+    "points",
+    "transform",
+    "style",
+    "color",
+    "display",
+    "visibility",
+    "opacity",
+    "fill",
+    "fill-opacity",
+    "fill-rule",
+    "stroke",
+    "stroke-dasharray",
+    "stroke-dashoffset",
+    "stroke-linecap",
+    "stroke-linejoin",
+    "stroke-miterlimit",
+    "stroke-opacity",
+    "stroke-width",
+    "clip-path",
+    "clip-rule",
+    "clipPathUnits",
+    "mask",
+    "maskUnits",
+    "maskContentUnits",
+    "marker-start",
+    "marker-mid",
+    "marker-end",
+    "markerWidth",
+    "markerHeight",
+    "markerUnits",
+    "refX",
+    "refY",
+    "orient",
+    "offset",
+    "stop-color",
+    "stop-opacity",
+    "gradientUnits",
+    "gradientTransform",
+    "spreadMethod",
+    "patternUnits",
+    "patternContentUnits",
+    "patternTransform",
+    "font-family",
+    "font-size",
+    "font-style",
+    "font-weight",
+    "text-anchor",
+    "dominant-baseline",
+    "letter-spacing",
+    "textLength",
+    "lengthAdjust",
+    "vector-effect",
+    "shape-rendering",
+    "paint-order",
+    "filter",
+    "filterUnits",
+    "primitiveUnits",
+    "in",
+    "in2",
+    "result",
+    "mode",
+    "type",
+    "values",
+    "operator",
+    "k1",
+    "k2",
+    "k3",
+    "k4",
+    "stdDeviation",
+    "flood-color",
+    "flood-opacity",
+];
+
+/// # A model for an inline SVG image
+///
+/// Unlike a Pixmap, an Svg is inserted as markup in the page, so it scales
+/// crisply and can be drawn with the current text colour. The markup is
+/// sanitized on creation: it is parsed as XML and rebuilt from a list of
+/// allowed elements and attributes, so scripts, styles, foreign objects,
+/// animations, event handlers and external links are removed. The size of
+/// the root element is replaced by a viewBox. A markup which is not a
+/// well-formed SVG document gives an empty image.
+///
+/// ## Fields
+///
+/// ```text
+/// markup: String
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use neutrino::utils::svg::Svg;
+///
+///
+/// fn main() {
+///     let mut svg = Svg::new(
+///         r#"<svg width="16" height="16" onload="alert(1)">
+///             <script>alert(2)</script>
+///             <rect width="16" height="16" fill="red"/>
+///         </svg>"#
+///     );
+///     svg.recolor();
+///
+///     assert!(!svg.markup().contains("alert"));
+///     assert!(svg.markup().contains(r#"viewBox="0 0 16 16""#));
+///     assert!(svg.markup().contains(r#"fill="currentColor""#));
+/// }
+/// ```
+pub struct Svg {
+    markup: String,
+}
+
+impl Svg {
+    /// Create an Svg from markup
+    pub fn new(markup: &str) -> Self {
+        Self {
+            markup: sanitize(markup, false),
+        }
+    }
+
+    /// Create an Svg from a file path
+    pub fn from_path(path: &str) -> Self {
+        match fs::read_to_string(path) {
+            Ok(markup) => Self::new(&markup),
+            Err(_) => Self::new(""),
+        }
+    }
+
+    /// Create an Svg from an Icon, recolored with the current text colour
+    pub fn from_icon(icon: Box<dyn Icon>) -> Self {
+        let pixmap = Pixmap::from_icon(icon);
+        match Self::from_pixmap(&pixmap) {
+            Some(svg) => svg,
+            None => Self::new(""),
+        }
+    }
+
+    /// Create an Svg from an SVG Pixmap, recolored with the current text
+    /// colour
+    ///
+    /// Return None if the Pixmap is not an SVG image.
+    pub fn from_pixmap(pixmap: &Pixmap) -> Option<Self> {
+        if pixmap.extension() != "svg+xml" {
+            return None;
+        }
+        let data = decode(pixmap.data()).ok()?;
+        Some(Self {
+            markup: sanitize(&String::from_utf8_lossy(&data), true),
+        })
+    }
+
+    /// Get the sanitized markup
+    pub fn markup(&self) -> &str {
+        &self.markup
+    }
+
+    /// Replace the fill and stroke colours by the current text colour
+    ///
+    /// The `none` values and the references to gradients or patterns are
+    /// kept.
+    pub fn recolor(&mut self) {
+        self.markup = sanitize(&self.markup, true);
+    }
+
+    /// Return the HTML representation of the image with the given class
+    pub fn eval(&self, class: &str) -> String {
+        self.markup.replacen(
+            "<svg",
+            &format!(
+                r#"<svg class="{}" aria-hidden="true" focusable="false""#,
+                class
+            ),
+            1,
+        )
+    }
+}
+
+/// Return the sanitized markup of an SVG image, the colours being replaced
+/// by the current text colour if `recolor` is true
+fn sanitize(markup: &str, recolor: bool) -> String {
+    let document = match Document::parse(markup) {
+        Ok(document) => document,
+        Err(_) => return "".to_string(),
+    };
+    let root = document.root_element();
+    if !is_allowed(root) || root.tag_name().name() != "svg" {
+        return "".to_string();
+    }
+    let mut html = "".to_string();
+    write_element(&mut html, root, true, recolor);
+    html
+}
+
+/// Return true if the element is an SVG element of the allowed list
+fn is_allowed(node: Node) -> bool {
+    let namespace = node.tag_name().namespace();
+    (namespace.is_none() || namespace == Some(SVG_NAMESPACE))
+        && ELEMENTS.contains(&node.tag_name().name())
+}
+
+/// Write an allowed element, its allowed attributes and its allowed
+/// children
+fn write_element(html: &mut String, node: Node, root: bool, recolor: bool) {
+    let name = node.tag_name().name();
+    html.push('<');
+    html.push_str(name);
+    if root {
+        html.push_str(&format!(
+            r#" xmlns="{}" xmlns:xlink="{}""#,
+            SVG_NAMESPACE, XLINK_NAMESPACE
+        ));
+    }
+    for (attribute, value) in attributes(node, root, recolor) {
+        html.push_str(&format!(r#" {}="{}""#, attribute, escape(&value)));
+    }
+    html.push('>');
+    for child in node.children() {
+        if child.is_element() && is_allowed(child) {
+            write_element(html, child, false, recolor);
+        } else if child.is_text() {
+            html.push_str(&escape(child.text().unwrap_or("")));
+        }
+    }
+    html.push_str(&format!("</{}>", name));
+}
+
+/// Return the allowed attributes of an element
+///
+/// The size of the root element is replaced by a viewBox if it has none.
+fn attributes(node: Node, root: bool, recolor: bool) -> Vec<(String, String)> {
+    let mut attributes = vec![];
+    for attribute in node.attributes() {
+        let name = attribute.name();
+        let value = attribute.value();
+        let allowed = match attribute.namespace() {
+            None if root && (name == "width" || name == "height") => false,
+            None if name == "href" => is_safe_link(node, value),
+            None => ATTRIBUTES.contains(&name) && is_safe_value(value),
+            Some(XLINK_NAMESPACE) if name == "href" => {
+                is_safe_link(node, value)
+            }
+            Some(_) => false,
+        };
+        if !allowed {
+            continue;
+        }
+        let name = match attribute.namespace() {
+            Some(_) => format!("xlink:{}", name),
+            None => name.to_string(),
+        };
+        let value = match name.as_ref() {
+            "fill" | "stroke" if recolor => paint(value),
+            "style" if recolor => recolor_style(value),
+            _ => value.to_string(),
+        };
+        attributes.push((name, value));
+    }
+    if root && !node.has_attribute("viewBox") {
+        let size = |name| {
+            node.attribute(name)?
+                .trim()
+                .trim_end_matches("px")
+                .trim()
+                .parse::<f64>()
+                .ok()
+        };
+        if let (Some(width), Some(height)) = (size("width"), size("height")) {
+            attributes.push((
+                "viewBox".to_string(),
+                format!("0 0 {} {}", width, height),
+            ));
+        }
+    }
+    attributes
+}
+
+/// Return true if a link only targets the document or, for an image, an
+/// embedded raster image
+fn is_safe_link(node: Node, value: &str) -> bool {
+    let value = value.trim().to_lowercase();
+    value.starts_with('#')
+        || (node.tag_name().name() == "image"
+            && value.starts_with("data:image/")
+            && !value.starts_with("data:image/svg"))
+}
+
+/// Return true if a value only references the document
+fn is_safe_value(value: &str) -> bool {
+    let value = value.to_lowercase();
+    !value.contains("expression")
+        && !value.contains("@import")
+        && value
+            .match_indices("url(")
+            .all(|(i, _)| value[i + 4..].trim_start().starts_with('#'))
+}
+
+/// Return the current text colour unless the paint is kept
+///
+/// The `none` values and the references to gradients or patterns are kept.
+fn paint(value: &str) -> String {
+    let paint = value.trim().to_lowercase();
+    let kept = paint.is_empty()
+        || paint == "none"
+        || paint == "currentcolor"
+        || paint == "inherit"
+        || paint.starts_with("url(");
+    if kept {
+        value.to_string()
+    } else {
+        "currentColor".to_string()
+    }
+}
+
+/// Recolor the fill and stroke properties of a style attribute
+fn recolor_style(style: &str) -> String {
+    style
+        .split(';')
+        .map(|declaration| {
+            let mut parts = declaration.splitn(2, ':');
+            let property = parts.next().unwrap_or("");
+            match (property.trim().to_lowercase().as_ref(), parts.next()) {
+                ("fill", Some(value)) | ("stroke", Some(value)) => {
+                    format!("{}:{}", property, paint(value))
+                }
+                _ => declaration.to_string(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(";")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ICON: &str = r##"<svg xmlns="http://www.w3.org/2000/svg"
+        xmlns:xlink="http://www.w3.org/1999/xlink" width="16" height="16">
+        <path style="fill:#232629; stroke: url(#a)" d="M 0 0 L 16 16"/>
+        <use xlink:href="#p"/>
+    </svg>"##;
+
+    #[test]
+    fn malformed_markup_is_emptied() {
+        let markups = [
+            "<svg/onload=alert(1)>",
+            "<img/src=x/onerror=alert(1)>",
+            "<scr<script>x</script>ipt>alert(1)</script>",
+            "<svg><a href=javascript:alert(1)>x</a></svg>",
+            r##"<html><svg onload="alert(1)"></svg></html>"##,
+        ];
+        for markup in markups.iter() {
+            assert_eq!(Svg::new(markup).markup(), "");
+        }
+    }
+
+    #[test]
+    fn unsafe_elements_and_attributes_are_removed() {
+        let svg = Svg::new(
+            r##"<svg xmlns="http://www.w3.org/2000/svg"
+                xmlns:xlink="http://www.w3.org/1999/xlink">
+                <a href="#x">
+                    <set attributeName="href" to="javascript:alert(1)"/>
+                    <animate attributeName="href"
+                        values="javascript:alert(2)"/>
+                </a>
+                <use href=" javascript:alert(3)"/>
+                <use xlink:href="https://example.com/icons.svg#a"/>
+                <image href="data:image/svg+xml;base64,PHN2Zz4="/>
+                <rect fill="url(javascript:alert(4))" onclick="alert(5)"/>
+                <script>alert(6)</script>
+                <style>rect { fill: red; }</style>
+                <foreignObject><div>alert(7)</div></foreignObject>
+                <!-- <script>alert(8)</script> -->
+            </svg>"##,
+        );
+        assert!(!svg.markup().contains("alert"));
+        assert!(!svg.markup().contains("example.com"));
+        assert!(!svg.markup().contains("svg+xml"));
+        assert!(svg.markup().contains("<rect></rect>"));
+    }
+
+    #[test]
+    fn safe_markup_is_kept() {
+        let svg = Svg::new(ICON);
+        let root = format!(
+            r##"<svg xmlns="{}" xmlns:xlink="{}" viewBox="0 0 16 16">"##,
+            SVG_NAMESPACE, XLINK_NAMESPACE
+        );
+        let style = r##"style="fill:#232629; stroke: url(#a)""##;
+        assert!(svg.markup().starts_with(&root));
+        assert!(svg.markup().contains(style));
+        assert!(svg.markup().contains(r##"<use xlink:href="#p"></use>"##));
+    }
+
+    #[test]
+    fn recolor_keeps_references() {
+        let mut svg = Svg::new(ICON);
+        svg.recolor();
+        let style = r##"style="fill:currentColor; stroke: url(#a)""##;
+        assert!(svg.markup().contains(style));
+        assert!(svg.markup().contains(r##"<use xlink:href="#p"></use>"##));
+    }
+}
@@ -0,0 +1,131 @@
+/// # The side of a widget where its tooltip is displayed
+///
+/// The tooltip is moved to the opposite side when it does not fit in the
+/// window on the preferred one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TooltipPosition {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl TooltipPosition {
+    /// Get the name used to position the tooltip
+    pub fn css(self) -> &'static str {
+        match self {
+            TooltipPosition::Top => "top",
+            TooltipPosition::Bottom => "bottom",
+            TooltipPosition::Left => "left",
+            TooltipPosition::Right => "right",
+        }
+    }
+}
+
+/// # A hint displayed when a widget is hovered or focused with the keyboard
+///
+/// The content is HTML, so a tooltip can hold formatted text, images or
+/// icons. It is displayed after the hover delay, or as soon as the widget
+/// gets the keyboard focus.
+///
+/// Any widget of the tree, the StatusBar, the ToolButtons and the widgets of
+/// a ToolBar can have a tooltip. The MenuBar and the ToolBar themselves are
+/// not widgets and do not have one.
+///
+/// ## Fields
+///
+/// ```text
+/// content: String
+/// delay: u32
+/// position: TooltipPosition
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// content: content.to_string()
+/// delay: 500
+/// position: TooltipPosition::Bottom
+/// ```
+///
+/// ## Style
+///
+/// ```text
+/// div.tooltips
+///     div.tooltip[.tooltip-(top|bottom|left|right)][.shown]
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use neutrino::utils::tooltip::{Tooltip, TooltipPosition};
+/// use neutrino::widgets::button::Button;
+///
+/// let mut tooltip = Tooltip::new("Save the document <b>(Ctrl+S)</b>");
+/// tooltip.set_delay(300);
+/// tooltip.set_position(TooltipPosition::Right);
+///
+/// let mut my_button = Button::new("my_button");
+/// my_button.set_tooltip(tooltip);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tooltip {
+    content: String,
+    delay: u32,
+    position: TooltipPosition,
+}
+
+impl Tooltip {
+    /// Create a Tooltip
+    pub fn new(content: &str) -> Self {
+        Self {
+            content: content.to_string(),
+            delay: 500,
+            position: TooltipPosition::Bottom,
+        }
+    }
+
+    /// Get the content
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// Get the hover delay in milliseconds
+    pub fn delay(&self) -> u32 {
+        self.delay
+    }
+
+    /// Get the position
+    pub fn position(&self) -> TooltipPosition {
+        self.position
+    }
+
+    /// Set the content
+    pub fn set_content(&mut self, content: &str) {
+        self.content = content.to_string();
+    }
+
+    /// Set the hover delay in milliseconds
+    pub fn set_delay(&mut self, delay: u32) {
+        self.delay = delay;
+    }
+
+    /// Set the position
+    pub fn set_position(&mut self, position: TooltipPosition) {
+        self.position = position;
+    }
+
+    /// Return the HTML representation of the tooltip of the widget `name`
+    pub fn eval(&self, name: &str) -> String {
+        format!(
+            r#"
+            <div class="tooltip" role="tooltip" data-for="{}"
+                data-delay="{}" data-position="{}">{}</div>
+            "#,
+            name,
+            self.delay,
+            self.position.css(),
+            self.content,
+        )
+    }
+}
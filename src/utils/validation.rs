@@ -0,0 +1,371 @@
+use regex::Regex;
+
+/// # The value of a field checked by a Validator
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    Text(String),
+    Number(f64),
+    Bool(bool),
+}
+
+impl FieldValue {
+    /// Return the value as text
+    pub fn text(&self) -> String {
+        match self {
+            FieldValue::Text(text) => text.to_string(),
+            FieldValue::Number(number) => number.to_string(),
+            FieldValue::Bool(flag) => flag.to_string(),
+        }
+    }
+}
+
+enum Rule {
+    Required,
+    Pattern(Regex),
+    Range(f64, f64),
+    Custom(Box<dyn Fn(&FieldValue) -> bool>),
+}
+
+/// # A rule checked on the value of a field
+///
+/// A Validator holds the message displayed when the value does not follow
+/// the rule.
+///
+/// ## Example
+///
+/// ```
+/// use neutrino::utils::validation::{FieldValue, Validator};
+///
+/// let required = Validator::required("The username is required");
+/// assert!(!required.check(&FieldValue::Text("".to_string())));
+///
+/// let pattern = Validator::pattern(r"^\d{5}$", "Invalid zip code").unwrap();
+/// assert!(pattern.check(&FieldValue::Text("75001".to_string())));
+///
+/// let range = Validator::range(0.0, 10.0, "Out of range");
+/// assert!(!range.check(&FieldValue::Number(12.0)));
+///
+/// let even = Validator::custom(
+///     |value| value.text().len() % 2 == 0,
+///     "The length has to be even"
+/// );
+/// assert!(even.check(&FieldValue::Text("ab".to_string())));
+/// ```
+pub struct Validator {
+    rule: Rule,
+    message: String,
+}
+
+impl Validator {
+    /// Create a Validator rejecting empty texts and unchecked boxes
+    pub fn required(message: &str) -> Self {
+        Self {
+            rule: Rule::Required,
+            message: message.to_string(),
+        }
+    }
+
+    /// Create a Validator rejecting the values not matching a regular
+    /// expression
+    pub fn pattern(pattern: &str, message: &str) -> Result<Self, regex::Error> {
+        Ok(Self {
+            rule: Rule::Pattern(Regex::new(pattern)?),
+            message: message.to_string(),
+        })
+    }
+
+    /// Create a Validator rejecting the numbers out of `min..=max`
+    ///
+    /// Texts are parsed as numbers.
+    pub fn range(min: f64, max: f64, message: &str) -> Self {
+        Self {
+            rule: Rule::Range(min, max),
+            message: message.to_string(),
+        }
+    }
+
+    /// Create a Validator rejecting the values for which a function returns
+    /// false
+    pub fn custom<F>(function: F, message: &str) -> Self
+    where
+        F: Fn(&FieldValue) -> bool + 'static,
+    {
+        Self {
+            rule: Rule::Custom(Box::new(function)),
+            message: message.to_string(),
+        }
+    }
+
+    /// Get the message
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Return true if the value follows the rule
+    pub fn check(&self, value: &FieldValue) -> bool {
+        match (&self.rule, value) {
+            (Rule::Required, FieldValue::Text(text)) => !text.trim().is_empty(),
+            (Rule::Required, FieldValue::Bool(flag)) => *flag,
+            (Rule::Required, FieldValue::Number(_)) => true,
+            (Rule::Pattern(regex), value) => regex.is_match(&value.text()),
+            (Rule::Range(min, max), FieldValue::Number(number)) => {
+                number >= min && number <= max
+            }
+            (Rule::Range(min, max), FieldValue::Text(text)) => {
+                match text.trim().parse::<f64>() {
+                    Ok(number) => number >= *min && number <= *max,
+                    Err(_) => false,
+                }
+            }
+            (Rule::Range(_, _), FieldValue::Bool(_)) => true,
+            (Rule::Custom(function), value) => function(value),
+        }
+    }
+}
+
+/// # The validators of a field and the last error found
+///
+/// ## Fields
+///
+/// ```text
+/// validators: Vec<Validator>
+/// error: Option<String>
+/// ```
+#[derive(Default)]
+pub struct Validation {
+    validators: Vec<Validator>,
+    error: Option<String>,
+}
+
+impl Validation {
+    /// Create a Validation
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Get the validators
+    pub fn validators(&self) -> &Vec<Validator> {
+        &self.validators
+    }
+
+    /// Get the message of the last error found
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_ref().map(String::as_ref)
+    }
+
+    /// Add a validator
+    pub fn add(&mut self, validator: Validator) {
+        self.validators.push(validator);
+    }
+
+    /// Return the message of the first validator rejecting the value
+    pub fn check(&self, value: &FieldValue) -> Option<&str> {
+        self.validators
+            .iter()
+            .find(|validator| !validator.check(value))
+            .map(Validator::message)
+    }
+
+    /// Check the value and keep the error found, return true if the value
+    /// is valid
+    pub fn validate(&mut self, value: &FieldValue) -> bool {
+        self.error = self.check(value).map(str::to_string);
+        self.error.is_none()
+    }
+
+    /// Get the CSS class of the field
+    pub fn css(&self) -> &str {
+        match self.error {
+            Some(_) => "invalid",
+            None => "",
+        }
+    }
+
+    /// Return the HTML representation of the error
+    pub fn eval(&self) -> String {
+        match &self.error {
+            Some(error) => {
+                format!(r#"<div class="validation-error">{}</div>"#, error)
+            }
+            None => "".to_string(),
+        }
+    }
+}
+
+/// # Trait that any of the validated widgets have to implement
+pub trait Field {
+    /// Get the value
+    fn value(&self) -> FieldValue;
+
+    /// Get the validation
+    fn validation(&self) -> &Validation;
+
+    /// Check the value and display the error found, return true if the value
+    /// is valid
+    fn validate(&mut self) -> bool;
+}
+
+#[cfg(feature = "serde")]
+mod de {
+    use super::FieldValue;
+    use serde::de::value::Error;
+    use serde::de::{self, Deserializer, IntoDeserializer, Visitor};
+    use serde::forward_to_deserialize_any;
+
+    /// Conversion of a number into a type able to hold it exactly
+    trait Exact: Sized {
+        /// Convert the number, return None if it is out of the bounds of
+        /// the type or if it is not an integer and the type is
+        fn exact(number: f64) -> Option<Self>;
+    }
+
+    macro_rules! exact_integer {
+        ($($type:ty),*) => {$(
+            impl Exact for $type {
+                fn exact(number: f64) -> Option<Self> {
+                    let min = <$type>::MIN as f64;
+                    let max = <$type>::MAX as f64 + 1.0;
+                    if number.fract() == 0.0 && number >= min && number < max
+                    {
+                        Some(number as $type)
+                    } else {
+                        None
+                    }
+                }
+            }
+        )*};
+    }
+
+    exact_integer!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+    impl Exact for f32 {
+        fn exact(number: f64) -> Option<Self> {
+            let converted = number as f32;
+            let overflowed = converted.is_infinite() && number.is_finite();
+            if number.is_nan() || overflowed {
+                None
+            } else {
+                Some(converted)
+            }
+        }
+    }
+
+    impl Exact for f64 {
+        fn exact(number: f64) -> Option<Self> {
+            Some(number)
+        }
+    }
+
+    /// Deserialize numbers from numbers or from texts
+    ///
+    /// A number which does not fit in the requested type is an error rather
+    /// than being truncated or saturated.
+    macro_rules! deserialize_number {
+        ($($method:ident => $visit:ident: $type:ty),*) => {$(
+            fn $method<V: Visitor<'de>>(
+                self,
+                visitor: V,
+            ) -> Result<V::Value, Error> {
+                match self {
+                    FieldValue::Number(number) => {
+                        match <$type>::exact(number) {
+                            Some(number) => visitor.$visit(number),
+                            None => Err(de::Error::custom(format!(
+                                "{} is not a valid {}",
+                                number,
+                                stringify!($type),
+                            ))),
+                        }
+                    }
+                    FieldValue::Text(text) => {
+                        match text.trim().parse::<$type>() {
+                            Ok(number) => visitor.$visit(number),
+                            Err(_) => visitor.visit_string(text),
+                        }
+                    }
+                    value => value.deserialize_any(visitor),
+                }
+            }
+        )*};
+    }
+
+    impl<'de> Deserializer<'de> for FieldValue {
+        type Error = Error;
+
+        fn deserialize_any<V: Visitor<'de>>(
+            self,
+            visitor: V,
+        ) -> Result<V::Value, Error> {
+            match self {
+                FieldValue::Text(text) => visitor.visit_string(text),
+                FieldValue::Number(number) => visitor.visit_f64(number),
+                FieldValue::Bool(flag) => visitor.visit_bool(flag),
+            }
+        }
+
+        fn deserialize_option<V: Visitor<'de>>(
+            self,
+            visitor: V,
+        ) -> Result<V::Value, Error> {
+            match self {
+                FieldValue::Text(ref text) if text.is_empty() => {
+                    visitor.visit_none()
+                }
+                value => visitor.visit_some(value),
+            }
+        }
+
+        deserialize_number! {
+            deserialize_i8 => visit_i8: i8,
+            deserialize_i16 => visit_i16: i16,
+            deserialize_i32 => visit_i32: i32,
+            deserialize_i64 => visit_i64: i64,
+            deserialize_u8 => visit_u8: u8,
+            deserialize_u16 => visit_u16: u16,
+            deserialize_u32 => visit_u32: u32,
+            deserialize_u64 => visit_u64: u64,
+            deserialize_f32 => visit_f32: f32,
+            deserialize_f64 => visit_f64: f64
+        }
+
+        forward_to_deserialize_any! {
+            bool i128 u128 char str string bytes byte_buf unit unit_struct
+            newtype_struct seq tuple tuple_struct map struct enum identifier
+            ignored_any
+        }
+    }
+
+    impl<'de> IntoDeserializer<'de> for FieldValue {
+        type Deserializer = Self;
+
+        fn into_deserializer(self) -> Self {
+            self
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::FieldValue;
+        use serde::Deserialize;
+
+        #[test]
+        fn fractional_number_is_not_an_integer() {
+            assert!(u32::deserialize(FieldValue::Number(3.7)).is_err());
+            assert_eq!(u32::deserialize(FieldValue::Number(3.0)), Ok(3));
+        }
+
+        #[test]
+        fn negative_number_is_not_unsigned() {
+            assert!(u8::deserialize(FieldValue::Number(-1.0)).is_err());
+            assert_eq!(i8::deserialize(FieldValue::Number(-1.0)), Ok(-1));
+        }
+
+        #[test]
+        fn number_out_of_bounds_is_not_saturated() {
+            assert!(u8::deserialize(FieldValue::Number(300.0)).is_err());
+            assert!(u64::deserialize(FieldValue::Number(2e19)).is_err());
+            assert!(f32::deserialize(FieldValue::Number(1e39)).is_err());
+            assert_eq!(u8::deserialize(FieldValue::Number(255.0)), Ok(255));
+        }
+    }
+}
@@ -2,6 +2,8 @@ use crate::utils::event::Event;
 use crate::utils::icon::Icon;
 use crate::utils::pixmap::Pixmap;
 use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::svg::Svg;
+use crate::utils::tooltip::Tooltip;
 use crate::widgets::widget::Widget;
 
 /// # The state of a Button
@@ -12,17 +14,23 @@ use crate::widgets::widget::Widget;
 /// text: Option<String>
 /// icon_data: Option<String>
 /// icon_extension: Option<String>
+/// icon_svg: Option<Svg>
+/// monochrome: bool
 /// disabled: bool
 /// stretched: bool
 /// style: String
+/// tooltip: Option<Tooltip>
 /// ```
 pub struct ButtonState {
     text: Option<String>,
     icon_data: Option<String>,
     icon_extension: Option<String>,
+    icon_svg: Option<Svg>,
+    monochrome: bool,
     disabled: bool,
     stretched: bool,
     style: String,
+    tooltip: Option<Tooltip>,
 }
 
 impl ButtonState {
@@ -39,6 +47,11 @@ impl ButtonState {
         }
     }
 
+    /// Get the monochrome flag
+    pub fn monochrome(&self) -> bool {
+        self.monochrome
+    }
+
     /// Get the disabled flag
     pub fn disabled(&self) -> bool {
         self.disabled
@@ -54,6 +67,11 @@ impl ButtonState {
         &self.style
     }
 
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
     /// Set the text
     pub fn set_text(&mut self, text: &str) {
         self.text = Some(text.to_string());
@@ -64,6 +82,18 @@ impl ButtonState {
         let pixmap = Pixmap::from_icon(icon);
         self.icon_data = Some(pixmap.data().to_string());
         self.icon_extension = Some(pixmap.extension().to_string());
+        self.set_monochrome(self.monochrome);
+    }
+
+    /// Set the monochrome flag
+    ///
+    /// A monochrome SVG icon is inlined and drawn with the text colour.
+    pub fn set_monochrome(&mut self, monochrome: bool) {
+        self.monochrome = monochrome;
+        self.icon_svg = match self.icon() {
+            Some(icon) if monochrome => Svg::from_pixmap(&icon),
+            _ => None,
+        };
     }
 
     /// Set the disabled flag
@@ -80,6 +110,19 @@ impl ButtonState {
     pub fn set_style(&mut self, style: &str) {
         self.style = style.to_string();
     }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
+
+    /// Return the HTML representation of the icon
+    fn eval_icon(&self) -> Option<String> {
+        match &self.icon_svg {
+            Some(svg) => Some(svg.eval("icon")),
+            None => self.icon().map(|icon| icon.eval_icon()),
+        }
+    }
 }
 
 /// # The listener of a Button
@@ -93,6 +136,10 @@ pub trait ButtonListener {
 
 /// # A clickable button with a label
 ///
+/// A button displaying only an icon should be given a tooltip describing
+/// its action. A monochrome SVG icon is inlined and drawn with the text
+/// colour.
+///
 /// ## Fields
 ///
 /// ```text
@@ -109,9 +156,12 @@ pub trait ButtonListener {
 ///     text: None
 ///     icon_data: None
 ///     icon_extension: None
+///     icon_svg: None
+///     monochrome: false
 ///     disabled: false
 ///     stretched: false
 ///     style: "".to_string()
+///     tooltip: None
 /// listener: None
 /// ```
 ///
@@ -119,7 +169,8 @@ pub trait ButtonListener {
 ///
 /// ```text
 /// div.button[.disabled]
-///     img
+///     svg.icon | img
+///     span
 /// ```
 ///
 /// ## Example
@@ -198,9 +249,12 @@ impl Button {
                 text: None,
                 icon_data: None,
                 icon_extension: None,
+                icon_svg: None,
+                monochrome: false,
                 disabled: false,
                 stretched: false,
                 style: "".to_string(),
+                tooltip: None,
             },
             listener: None,
         }
@@ -216,6 +270,11 @@ impl Button {
         self.state.set_icon(icon);
     }
 
+    /// Set the monochrome flag to true
+    pub fn set_monochrome(&mut self) {
+        self.state.set_monochrome(true);
+    }
+
     /// Set the disabled flag to true
     pub fn set_disabled(&mut self) {
         self.state.set_disabled(true);
@@ -226,6 +285,16 @@ impl Button {
         self.state.set_stretched(true);
     }
 
+    /// Get the state
+    pub fn state(&self) -> &ButtonState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut ButtonState {
+        &mut self.state
+    }
+
     /// Set the listener
     pub fn set_listener(&mut self, listener: Box<dyn ButtonListener>) {
         self.listener = Some(listener);
@@ -235,6 +304,11 @@ impl Button {
     pub fn set_style(&mut self, style: &str) {
         self.state.set_style(style);
     }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
 }
 
 impl Widget for Button {
@@ -254,11 +328,11 @@ impl Widget for Button {
             self.name,
             self.state.style(),
         )));
-        let html = match (self.state.text(), self.state.icon()) {
+        let html = match (self.state.text(), self.state.eval_icon()) {
             (Some(text), Some(icon)) => format!(
                 r#"
                 <div id="{}" class="button {} {}" onclick="{}">
-                    <img src="data:image/{};base64,{}" />
+                    {}
                     <span>{}</span>
                 </div>
                 "#,
@@ -266,8 +340,7 @@ impl Widget for Button {
                 disabled,
                 stretched,
                 Event::change_js(&self.name, "''"),
-                icon.extension(),
-                icon.data(),
+                icon,
                 text,
             ),
             (Some(text), None) => format!(
@@ -285,15 +358,14 @@ impl Widget for Button {
             (None, Some(icon)) => format!(
                 r#"
                 <div id="{}" class="button {} {}" onclick="{}">
-                    <img src="data:image/{};base64,{}" />
+                    {}
                 </div>
                 "#,
                 self.name,
                 disabled,
                 stretched,
                 Event::change_js(&self.name, "''"),
-                icon.extension(),
-                icon.data(),
+                icon,
             ),
             (None, None) => format!(
                 r#"
@@ -340,4 +412,12 @@ impl Widget for Button {
             }
         }
     }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
 }
@@ -0,0 +1,615 @@
+use std::collections::HashSet;
+
+use crate::utils::datetime::{Date, Weekday, MONTHS};
+use crate::utils::event::{Event, Key};
+use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
+use crate::widgets::widget::Widget;
+
+/// # The state of a Calendar
+///
+/// ## Fields
+///
+/// ```text
+/// value: Option<Date>
+/// month: Date
+/// cursor: Date
+/// min: Option<Date>
+/// max: Option<Date>
+/// disabled_days: HashSet<Date>
+/// disabled_weekdays: HashSet<Weekday>
+/// week_start: Weekday
+/// disabled: bool
+/// stretched: bool
+/// style: String
+/// tooltip: Option<Tooltip>
+/// ```
+pub struct CalendarState {
+    value: Option<Date>,
+    month: Date,
+    cursor: Date,
+    min: Option<Date>,
+    max: Option<Date>,
+    disabled_days: HashSet<Date>,
+    disabled_weekdays: HashSet<Weekday>,
+    week_start: Weekday,
+    disabled: bool,
+    stretched: bool,
+    style: String,
+    tooltip: Option<Tooltip>,
+}
+
+impl CalendarState {
+    /// Create the state of a Calendar with the default values
+    pub(crate) fn new() -> Self {
+        let today = Date::today();
+        Self {
+            value: None,
+            month: today.first_of_month(),
+            cursor: today,
+            min: None,
+            max: None,
+            disabled_days: HashSet::new(),
+            disabled_weekdays: HashSet::new(),
+            week_start: Weekday::Monday,
+            disabled: false,
+            stretched: false,
+            style: "".to_string(),
+            tooltip: None,
+        }
+    }
+
+    /// Get the selected date
+    pub fn value(&self) -> Option<Date> {
+        self.value
+    }
+
+    /// Get the first day of the displayed month
+    pub fn month(&self) -> Date {
+        self.month
+    }
+
+    /// Get the date focused with the keyboard
+    pub fn cursor(&self) -> Date {
+        self.cursor
+    }
+
+    /// Get the min
+    pub fn min(&self) -> Option<Date> {
+        self.min
+    }
+
+    /// Get the max
+    pub fn max(&self) -> Option<Date> {
+        self.max
+    }
+
+    /// Get the disabled days
+    pub fn disabled_days(&self) -> &HashSet<Date> {
+        &self.disabled_days
+    }
+
+    /// Get the disabled weekdays
+    pub fn disabled_weekdays(&self) -> &HashSet<Weekday> {
+        &self.disabled_weekdays
+    }
+
+    /// Get the first day of the week
+    pub fn week_start(&self) -> Weekday {
+        self.week_start
+    }
+
+    /// Get the disabled flag
+    pub fn disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Get the stretched flag
+    pub fn stretched(&self) -> bool {
+        self.stretched
+    }
+
+    /// Get the style
+    pub fn style(&self) -> &str {
+        &self.style
+    }
+
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
+    /// Set the selected date, displaying its month
+    pub fn set_value(&mut self, value: Option<Date>) {
+        self.value = value;
+        if let Some(date) = value {
+            self.set_cursor(date);
+        }
+    }
+
+    /// Set the displayed month
+    pub fn set_month(&mut self, month: Date) {
+        self.month = month.first_of_month();
+    }
+
+    /// Set the date focused with the keyboard, displaying its month
+    pub fn set_cursor(&mut self, cursor: Date) {
+        self.cursor = cursor;
+        self.month = cursor.first_of_month();
+    }
+
+    /// Set the min
+    pub fn set_min(&mut self, min: Option<Date>) {
+        self.min = min;
+    }
+
+    /// Set the max
+    pub fn set_max(&mut self, max: Option<Date>) {
+        self.max = max;
+    }
+
+    /// Disable a day
+    pub fn disable_day(&mut self, day: Date) {
+        self.disabled_days.insert(day);
+    }
+
+    /// Disable a day of the week
+    pub fn disable_weekday(&mut self, weekday: Weekday) {
+        self.disabled_weekdays.insert(weekday);
+    }
+
+    /// Set the first day of the week
+    pub fn set_week_start(&mut self, week_start: Weekday) {
+        self.week_start = week_start;
+    }
+
+    /// Set the disabled flag
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+
+    /// Set the stretched flag
+    pub fn set_stretched(&mut self, stretched: bool) {
+        self.stretched = stretched;
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.style = style.to_string();
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
+
+    /// Return true if the date is between the min and the max and is not
+    /// disabled
+    pub fn enabled(&self, date: Date) -> bool {
+        self.min.iter().all(|min| date >= *min)
+            && self.max.iter().all(|max| date <= *max)
+            && !self.disabled_days.contains(&date)
+            && !self.disabled_weekdays.contains(&date.weekday())
+    }
+
+    /// Move the cursor according to the keys pressed, return false if none
+    /// of them moves it
+    ///
+    /// The arrows move the cursor by a day or a week, PageUp and PageDown by
+    /// a month, Home and End to the bounds of the week. The cursor is kept
+    /// between the min and the max.
+    pub fn move_cursor(&mut self, keys: &HashSet<Key>) -> bool {
+        let cursor = self.cursor;
+        let position = i64::from(
+            (cursor.weekday().index() + 7 - self.week_start.index()) % 7,
+        );
+        let target = if keys.contains(&Key::Left) {
+            cursor.add_days(-1)
+        } else if keys.contains(&Key::Right) {
+            cursor.add_days(1)
+        } else if keys.contains(&Key::Up) {
+            cursor.add_days(-7)
+        } else if keys.contains(&Key::Down) {
+            cursor.add_days(7)
+        } else if keys.contains(&Key::PageUp) {
+            cursor.add_months(-1)
+        } else if keys.contains(&Key::PageDown) {
+            cursor.add_months(1)
+        } else if keys.contains(&Key::Home) {
+            cursor.add_days(-position)
+        } else if keys.contains(&Key::End) {
+            cursor.add_days(6 - position)
+        } else {
+            return false;
+        };
+        let target = self.min.map_or(target, |min| target.max(min));
+        let target = self.max.map_or(target, |max| target.min(max));
+        self.set_cursor(target);
+        true
+    }
+}
+
+/// Return the HTML representation of the days of a month, sending
+/// `month;<offset>` and `select;<date>` change events
+pub(crate) fn eval_month(
+    name: &str,
+    state: &CalendarState,
+    interactive: bool,
+) -> String {
+    let month = state.month();
+    let onclick = |value: String| {
+        if interactive {
+            format!(r#"onclick="{}""#, Event::change_js(name, &value))
+        } else {
+            "".to_string()
+        }
+    };
+    let mut html = format!(
+        r#"
+        <div class="calendar-header">
+            <div class="calendar-previous" {}>&#9664;</div>
+            <div class="calendar-title">{} {}</div>
+            <div class="calendar-next" {}>&#9654;</div>
+        </div>
+        <div class="calendar-days">
+        "#,
+        onclick("'month;-1'".to_string()),
+        MONTHS[month.month() as usize - 1],
+        month.year(),
+        onclick("'month;1'".to_string()),
+    );
+    for i in 0..7 {
+        let weekday = Weekday::from_index(state.week_start().index() + i);
+        html.push_str(&format!(
+            r#"<div class="calendar-weekday">{}</div>"#,
+            weekday.short()
+        ));
+    }
+    let offset = (month.weekday().index() + 7 - state.week_start().index()) % 7;
+    let first = month.add_days(-i64::from(offset));
+    let today = Date::today();
+    for i in 0..42 {
+        let date = first.add_days(i);
+        let mut classes = vec!["calendar-day"];
+        if date.month() != month.month() {
+            classes.push("outside");
+        }
+        if date == today {
+            classes.push("today");
+        }
+        if state.value() == Some(date) {
+            classes.push("selected");
+        }
+        if state.cursor() == date {
+            classes.push("cursor");
+        }
+        let onclick = if state.enabled(date) {
+            onclick(format!("'select;{}'", date))
+        } else {
+            classes.push("disabled");
+            "".to_string()
+        };
+        html.push_str(&format!(
+            r#"<div class="{}" {}>{}</div>"#,
+            classes.join(" "),
+            onclick,
+            date.day(),
+        ));
+    }
+    html.push_str("</div>");
+    html
+}
+
+/// # The listener of a Calendar
+pub trait CalendarListener {
+    /// Function triggered on update event
+    fn on_update(&self, state: &mut CalendarState);
+
+    /// Function triggered on change event
+    fn on_change(&self, state: &CalendarState);
+}
+
+/// # A month calendar to select a date
+///
+/// The days out of the bounds or disabled cannot be selected. Once the
+/// calendar is focused, the arrows move the cursor by a day or a week,
+/// PageUp and PageDown by a month, Home and End to the bounds of the week,
+/// and Enter or Space select the day under the cursor.
+///
+/// ## Fields
+///
+/// ```text
+/// name: String
+/// state: CalendarState
+/// listener: Option<Box<dyn CalendarListener>>
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// name: name.to_string()
+/// state:
+///     value: None
+///     month: Date::today().first_of_month()
+///     cursor: Date::today()
+///     min: None
+///     max: None
+///     disabled_days: HashSet::new()
+///     disabled_weekdays: HashSet::new()
+///     week_start: Weekday::Monday
+///     disabled: false
+///     stretched: false
+///     style: "".to_string()
+///     tooltip: None
+/// listener: None
+/// ```
+///
+/// ## Style
+///
+/// ```text
+/// div.calendar[.disabled]
+///     div.calendar-header
+///         div.calendar-previous
+///         div.calendar-title
+///         div.calendar-next
+///     div.calendar-days
+///         div.calendar-weekday
+///         div.calendar-day[.outside][.today][.selected][.cursor][.disabled]
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use neutrino::utils::datetime::{Date, Weekday};
+/// use neutrino::widgets::calendar::{
+///     Calendar,
+///     CalendarListener,
+///     CalendarState
+/// };
+///
+///
+/// struct Meeting {
+///     day: Option<Date>,
+/// }
+///
+/// impl Meeting {
+///     fn new() -> Self {
+///         Self { day: None }
+///     }
+///
+///     fn day(&self) -> Option<Date> {
+///         self.day
+///     }
+///
+///     fn set_day(&mut self, day: Option<Date>) {
+///         self.day = day;
+///     }
+/// }
+///
+///
+/// struct MyCalendarListener {
+///     meeting: Rc<RefCell<Meeting>>,
+/// }
+///
+/// impl MyCalendarListener {
+///    pub fn new(meeting: Rc<RefCell<Meeting>>) -> Self {
+///        Self { meeting }
+///    }
+/// }
+///
+/// impl CalendarListener for MyCalendarListener {
+///     fn on_change(&self, state: &CalendarState) {
+///         self.meeting.borrow_mut().set_day(state.value());
+///     }
+///
+///     fn on_update(&self, _state: &mut CalendarState) {}
+/// }
+///
+///
+/// fn main() {
+///     let meeting = Rc::new(RefCell::new(Meeting::new()));
+///
+///     let my_listener = MyCalendarListener::new(Rc::clone(&meeting));
+///
+///     let mut my_calendar = Calendar::new("my_calendar");
+///     my_calendar.set_min(Date::new(2020, 1, 1).unwrap());
+///     my_calendar.disable_weekday(Weekday::Saturday);
+///     my_calendar.disable_weekday(Weekday::Sunday);
+///     my_calendar.set_week_start(Weekday::Sunday);
+///     my_calendar.set_listener(Box::new(my_listener));
+/// }
+/// ```
+pub struct Calendar {
+    name: String,
+    state: CalendarState,
+    listener: Option<Box<dyn CalendarListener>>,
+}
+
+impl Calendar {
+    /// Create a Calendar
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            state: CalendarState::new(),
+            listener: None,
+        }
+    }
+
+    /// Set the selected date
+    pub fn set_value(&mut self, value: Date) {
+        self.state.set_value(Some(value));
+    }
+
+    /// Set the min
+    pub fn set_min(&mut self, min: Date) {
+        self.state.set_min(Some(min));
+    }
+
+    /// Set the max
+    pub fn set_max(&mut self, max: Date) {
+        self.state.set_max(Some(max));
+    }
+
+    /// Disable a day
+    pub fn disable_day(&mut self, day: Date) {
+        self.state.disable_day(day);
+    }
+
+    /// Disable a day of the week
+    pub fn disable_weekday(&mut self, weekday: Weekday) {
+        self.state.disable_weekday(weekday);
+    }
+
+    /// Set the first day of the week
+    pub fn set_week_start(&mut self, week_start: Weekday) {
+        self.state.set_week_start(week_start);
+    }
+
+    /// Set the disabled flag to true
+    pub fn set_disabled(&mut self) {
+        self.state.set_disabled(true);
+    }
+
+    /// Set the stretched flag to true
+    pub fn set_stretched(&mut self) {
+        self.state.set_stretched(true);
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &CalendarState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut CalendarState {
+        &mut self.state
+    }
+
+    /// Set the listener
+    pub fn set_listener(&mut self, listener: Box<dyn CalendarListener>) {
+        self.listener = Some(listener);
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.state.set_style(style);
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
+
+    /// Function triggered on keypress event
+    fn on_keys(&mut self, keys: &HashSet<Key>) {
+        if keys.contains(&Key::Enter) || keys.contains(&Key::Space) {
+            self.on_select(self.state.cursor());
+        } else {
+            self.state.move_cursor(keys);
+        }
+    }
+
+    /// Select a date and notify the listener
+    fn on_select(&mut self, date: Date) {
+        if !self.state.enabled(date) {
+            return;
+        }
+        self.state.set_value(Some(date));
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_change(&self.state);
+            }
+        }
+    }
+}
+
+impl Widget for Calendar {
+    fn eval(&self) -> String {
+        let stretched = if self.state.stretched() {
+            "stretched"
+        } else {
+            ""
+        };
+        let disabled = if self.state.disabled() {
+            "disabled"
+        } else {
+            ""
+        };
+        let style = inline_style(&scss_to_css(&format!(
+            r##"#{}{{{}}}"##,
+            self.name,
+            self.state.style(),
+        )));
+        let html = format!(
+            r#"
+            <div id="{}" class="calendar {} {}" tabindex="0"
+                onkeydown="navigate(event); {}"
+            >
+                {}
+            </div>
+            "#,
+            self.name,
+            disabled,
+            stretched,
+            Event::keypress_js(&self.name, "down"),
+            eval_month(&self.name, &self.state, !self.state.disabled()),
+        );
+        format!("{}{}", style, html)
+    }
+
+    fn trigger(&mut self, event: &Event) {
+        match event {
+            Event::Update => self.on_update(),
+            Event::Change { source, value } => {
+                if source == &self.name && !self.state.disabled() {
+                    self.on_change(value);
+                }
+            }
+            Event::Keypress { source, keys } => {
+                if source == &self.name && !self.state.disabled() {
+                    self.on_keys(keys);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn on_update(&mut self) {
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_update(&mut self.state);
+            }
+        }
+    }
+
+    fn on_change(&mut self, value: &str) {
+        let values = value.splitn(2, ';').collect::<Vec<&str>>();
+        match values.as_slice() {
+            ["month", offset] => {
+                if let Ok(offset) = offset.parse::<i32>() {
+                    let month = self.state.month().add_months(offset);
+                    self.state.set_month(month);
+                }
+            }
+            ["select", date] => {
+                if let Some(date) = Date::parse(date) {
+                    self.on_select(date);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+}
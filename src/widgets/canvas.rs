@@ -0,0 +1,613 @@
+use crate::utils::event::Event;
+use crate::utils::pixmap::Pixmap;
+use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
+use crate::widgets::widget::Widget;
+use json::JsonValue;
+
+/// # A drawing command of a Canvas
+///
+/// The commands are run in order on the 2D context of the canvas, as the
+/// methods and properties of the same name. The angles are in radians and
+/// the styles are CSS colors.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Save,
+    Restore,
+    Translate(f64, f64),
+    Rotate(f64),
+    Scale(f64, f64),
+    Transform(f64, f64, f64, f64, f64, f64),
+    ResetTransform,
+    BeginPath,
+    ClosePath,
+    MoveTo(f64, f64),
+    LineTo(f64, f64),
+    QuadraticCurveTo(f64, f64, f64, f64),
+    BezierCurveTo(f64, f64, f64, f64, f64, f64),
+    Arc(f64, f64, f64, f64, f64),
+    Ellipse(f64, f64, f64, f64, f64, f64, f64),
+    Rect(f64, f64, f64, f64),
+    Fill,
+    Stroke,
+    Clip,
+    FillRect(f64, f64, f64, f64),
+    StrokeRect(f64, f64, f64, f64),
+    ClearRect(f64, f64, f64, f64),
+    FillText(String, f64, f64),
+    StrokeText(String, f64, f64),
+    DrawImage(String, f64, f64, f64, f64),
+    FillStyle(String),
+    StrokeStyle(String),
+    LineWidth(f64),
+    LineDash(Vec<f64>),
+    Font(String),
+    TextAlign(String),
+    TextBaseline(String),
+    GlobalAlpha(f64),
+}
+
+impl Command {
+    /// Create a command drawing a Pixmap in the rectangle `x, y, width,
+    /// height`
+    pub fn image(
+        pixmap: &Pixmap,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    ) -> Self {
+        Command::DrawImage(
+            format!(
+                "data:image/{};base64,{}",
+                pixmap.extension(),
+                pixmap.data()
+            ),
+            x,
+            y,
+            width,
+            height,
+        )
+    }
+
+    /// Return the JSON representation of the command
+    fn to_json(&self) -> JsonValue {
+        let call = |name: &str, args: &[f64]| {
+            let mut values = vec![JsonValue::from(name)];
+            values.extend(args.iter().map(|arg| JsonValue::from(*arg)));
+            JsonValue::Array(values)
+        };
+        let text = |name: &str, text: &str, args: &[f64]| {
+            let mut values = vec![JsonValue::from(name), JsonValue::from(text)];
+            values.extend(args.iter().map(|arg| JsonValue::from(*arg)));
+            JsonValue::Array(values)
+        };
+        match self {
+            Command::Save => call("save", &[]),
+            Command::Restore => call("restore", &[]),
+            Command::Translate(x, y) => call("translate", &[*x, *y]),
+            Command::Rotate(angle) => call("rotate", &[*angle]),
+            Command::Scale(x, y) => call("scale", &[*x, *y]),
+            Command::Transform(a, b, c, d, e, f) => {
+                call("transform", &[*a, *b, *c, *d, *e, *f])
+            }
+            Command::ResetTransform => {
+                call("setTransform", &[1.0, 0.0, 0.0, 1.0, 0.0, 0.0])
+            }
+            Command::BeginPath => call("beginPath", &[]),
+            Command::ClosePath => call("closePath", &[]),
+            Command::MoveTo(x, y) => call("moveTo", &[*x, *y]),
+            Command::LineTo(x, y) => call("lineTo", &[*x, *y]),
+            Command::QuadraticCurveTo(cpx, cpy, x, y) => {
+                call("quadraticCurveTo", &[*cpx, *cpy, *x, *y])
+            }
+            Command::BezierCurveTo(cp1x, cp1y, cp2x, cp2y, x, y) => {
+                call("bezierCurveTo", &[*cp1x, *cp1y, *cp2x, *cp2y, *x, *y])
+            }
+            Command::Arc(x, y, radius, start, end) => {
+                call("arc", &[*x, *y, *radius, *start, *end])
+            }
+            Command::Ellipse(x, y, rx, ry, rotation, start, end) => {
+                call("ellipse", &[*x, *y, *rx, *ry, *rotation, *start, *end])
+            }
+            Command::Rect(x, y, w, h) => call("rect", &[*x, *y, *w, *h]),
+            Command::Fill => call("fill", &[]),
+            Command::Stroke => call("stroke", &[]),
+            Command::Clip => call("clip", &[]),
+            Command::FillRect(x, y, w, h) => {
+                call("fillRect", &[*x, *y, *w, *h])
+            }
+            Command::StrokeRect(x, y, w, h) => {
+                call("strokeRect", &[*x, *y, *w, *h])
+            }
+            Command::ClearRect(x, y, w, h) => {
+                call("clearRect", &[*x, *y, *w, *h])
+            }
+            Command::FillText(value, x, y) => {
+                text("fillText", value, &[*x, *y])
+            }
+            Command::StrokeText(value, x, y) => {
+                text("strokeText", value, &[*x, *y])
+            }
+            Command::DrawImage(source, x, y, w, h) => {
+                text("drawImage", source, &[*x, *y, *w, *h])
+            }
+            Command::FillStyle(style) => text("=fillStyle", style, &[]),
+            Command::StrokeStyle(style) => text("=strokeStyle", style, &[]),
+            Command::LineWidth(width) => call("=lineWidth", &[*width]),
+            Command::LineDash(segments) => JsonValue::Array(vec![
+                JsonValue::from("setLineDash"),
+                JsonValue::Array(
+                    segments.iter().map(|s| JsonValue::from(*s)).collect(),
+                ),
+            ]),
+            Command::Font(font) => text("=font", font, &[]),
+            Command::TextAlign(align) => text("=textAlign", align, &[]),
+            Command::TextBaseline(baseline) => {
+                text("=textBaseline", baseline, &[])
+            }
+            Command::GlobalAlpha(alpha) => call("=globalAlpha", &[*alpha]),
+        }
+    }
+}
+
+/// # The kind of a pointer event
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PointerKind {
+    Down,
+    Up,
+    Move,
+}
+
+/// # A pointer event, in the coordinates of a Canvas
+///
+/// ## Fields
+///
+/// ```text
+/// kind: PointerKind
+/// x: f64
+/// y: f64
+/// buttons: u32
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pointer {
+    kind: PointerKind,
+    x: f64,
+    y: f64,
+    buttons: u32,
+}
+
+impl Pointer {
+    /// Get the kind
+    pub fn kind(&self) -> PointerKind {
+        self.kind
+    }
+
+    /// Get the x coordinate
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    /// Get the y coordinate
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    /// Get the pressed buttons, as a bitmask (1 for the primary button, 2
+    /// for the secondary one and 4 for the middle one)
+    pub fn buttons(&self) -> u32 {
+        self.buttons
+    }
+}
+
+/// # The state of a Canvas
+///
+/// ## Fields
+///
+/// ```text
+/// width: u32
+/// height: u32
+/// commands: Vec<Command>
+/// pointer: Option<Pointer>
+/// tracking: bool
+/// stretched: bool
+/// style: String
+/// tooltip: Option<Tooltip>
+/// ```
+pub struct CanvasState {
+    width: u32,
+    height: u32,
+    commands: Vec<Command>,
+    pointer: Option<Pointer>,
+    tracking: bool,
+    stretched: bool,
+    style: String,
+    tooltip: Option<Tooltip>,
+}
+
+impl CanvasState {
+    /// Get the width
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Get the height
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Get the commands
+    pub fn commands(&self) -> &Vec<Command> {
+        &self.commands
+    }
+
+    /// Get the last pointer event
+    pub fn pointer(&self) -> Option<Pointer> {
+        self.pointer
+    }
+
+    /// Get the tracking flag
+    pub fn tracking(&self) -> bool {
+        self.tracking
+    }
+
+    /// Get the stretched flag
+    pub fn stretched(&self) -> bool {
+        self.stretched
+    }
+
+    /// Get the style
+    pub fn style(&self) -> &str {
+        &self.style
+    }
+
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
+    /// Set the size (width and height)
+    pub fn set_size(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Set the commands
+    pub fn set_commands(&mut self, commands: Vec<Command>) {
+        self.commands = commands;
+    }
+
+    /// Add a command
+    pub fn draw(&mut self, command: Command) {
+        self.commands.push(command);
+    }
+
+    /// Remove the commands
+    pub fn clear(&mut self) {
+        self.commands.clear();
+    }
+
+    /// Set the tracking flag
+    pub fn set_tracking(&mut self, tracking: bool) {
+        self.tracking = tracking;
+    }
+
+    /// Set the stretched flag
+    pub fn set_stretched(&mut self, stretched: bool) {
+        self.stretched = stretched;
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.style = style.to_string();
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
+}
+
+/// # The listener of a Canvas
+pub trait CanvasListener {
+    /// Function triggered on update event
+    fn on_update(&self, state: &mut CanvasState);
+
+    /// Function triggered on change event
+    fn on_change(&self, state: &CanvasState);
+}
+
+/// # A surface drawn with commands
+///
+/// The commands are sent to the 2D context of an HTML canvas, which is
+/// redrawn each time they change. The pointer events are given in the
+/// coordinates of the canvas, whatever its displayed size. The moves are
+/// only reported while a button is pressed, unless the tracking flag is
+/// true.
+///
+/// ## Fields
+///
+/// ```text
+/// name: String
+/// state: CanvasState
+/// listener: Option<Box<dyn CanvasListener>>
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// name: name.to_string()
+/// state:
+///     width: 300
+///     height: 150
+///     commands: vec![]
+///     pointer: None
+///     tracking: false
+///     stretched: false
+///     style: "".to_string()
+///     tooltip: None
+/// listener: None
+/// ```
+///
+/// ## Style
+///
+/// ```text
+/// div.canvas
+///     canvas
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::f64::consts::PI;
+/// use std::rc::Rc;
+///
+/// use neutrino::widgets::canvas::{
+///     Canvas,
+///     CanvasListener,
+///     CanvasState,
+///     Command,
+///     PointerKind
+/// };
+///
+///
+/// struct Drawing {
+///     points: Vec<(f64, f64)>,
+/// }
+///
+/// impl Drawing {
+///     fn new() -> Self {
+///         Self { points: vec![] }
+///     }
+///
+///     fn points(&self) -> &Vec<(f64, f64)> {
+///         &self.points
+///     }
+///
+///     fn add(&mut self, x: f64, y: f64) {
+///         self.points.push((x, y));
+///     }
+/// }
+///
+///
+/// struct MyCanvasListener {
+///     drawing: Rc<RefCell<Drawing>>,
+/// }
+///
+/// impl MyCanvasListener {
+///    pub fn new(drawing: Rc<RefCell<Drawing>>) -> Self {
+///        Self { drawing }
+///    }
+/// }
+///
+/// impl CanvasListener for MyCanvasListener {
+///     fn on_change(&self, state: &CanvasState) {
+///         if let Some(pointer) = state.pointer() {
+///             if pointer.kind() == PointerKind::Down {
+///                 self.drawing.borrow_mut().add(pointer.x(), pointer.y());
+///             }
+///         }
+///     }
+///
+///     fn on_update(&self, state: &mut CanvasState) {
+///         state.clear();
+///         state.draw(Command::FillStyle("steelblue".to_string()));
+///         for (x, y) in self.drawing.borrow().points() {
+///             state.draw(Command::BeginPath);
+///             state.draw(Command::Arc(*x, *y, 5.0, 0.0, 2.0 * PI));
+///             state.draw(Command::Fill);
+///         }
+///     }
+/// }
+///
+///
+/// fn main() {
+///     let drawing = Rc::new(RefCell::new(Drawing::new()));
+///
+///     let my_listener = MyCanvasListener::new(Rc::clone(&drawing));
+///
+///     let mut my_canvas = Canvas::new("my_canvas");
+///     my_canvas.set_size(400, 300);
+///     my_canvas.set_listener(Box::new(my_listener));
+/// }
+/// ```
+pub struct Canvas {
+    name: String,
+    state: CanvasState,
+    listener: Option<Box<dyn CanvasListener>>,
+}
+
+impl Canvas {
+    /// Create a Canvas
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            state: CanvasState {
+                width: 300,
+                height: 150,
+                commands: vec![],
+                pointer: None,
+                tracking: false,
+                stretched: false,
+                style: "".to_string(),
+                tooltip: None,
+            },
+            listener: None,
+        }
+    }
+
+    /// Set the size (width and height)
+    pub fn set_size(&mut self, width: u32, height: u32) {
+        self.state.set_size(width, height);
+    }
+
+    /// Set the commands
+    pub fn set_commands(&mut self, commands: Vec<Command>) {
+        self.state.set_commands(commands);
+    }
+
+    /// Set the tracking flag to true
+    pub fn set_tracking(&mut self) {
+        self.state.set_tracking(true);
+    }
+
+    /// Set the stretched flag to true
+    pub fn set_stretched(&mut self) {
+        self.state.set_stretched(true);
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &CanvasState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut CanvasState {
+        &mut self.state
+    }
+
+    /// Set the listener
+    pub fn set_listener(&mut self, listener: Box<dyn CanvasListener>) {
+        self.listener = Some(listener);
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.state.set_style(style);
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
+}
+
+impl Widget for Canvas {
+    fn eval(&self) -> String {
+        let stretched = if self.state.stretched() {
+            "stretched"
+        } else {
+            ""
+        };
+        let style = inline_style(&scss_to_css(&format!(
+            r##"#{}{{{}}}"##,
+            self.name,
+            self.state.style(),
+        )));
+        let commands = JsonValue::Array(
+            self.state.commands().iter().map(Command::to_json).collect(),
+        )
+        .dump()
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('\\', "&#92;");
+        let pointer = |kind: &str| {
+            Event::change_js(
+                &self.name,
+                &format!("'pointer;{};' + point(event)", kind),
+            )
+        };
+        let html = format!(
+            r#"
+            <div id="{}" class="canvas {}">
+                <canvas width="{}" height="{}" data-commands="{}"
+                    onmousedown="{}"
+                    onmouseup="{}"
+                    onmousemove="if (event.buttons || {}) {{ {} }}"
+                ></canvas>
+            </div>
+            "#,
+            self.name,
+            stretched,
+            self.state.width(),
+            self.state.height(),
+            commands,
+            pointer("down"),
+            pointer("up"),
+            self.state.tracking(),
+            pointer("move"),
+        );
+        format!("{}{}", style, html)
+    }
+
+    fn trigger(&mut self, event: &Event) {
+        match event {
+            Event::Update => self.on_update(),
+            Event::Change { source, value } => {
+                if source == &self.name {
+                    self.on_change(value);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn on_update(&mut self) {
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_update(&mut self.state);
+            }
+        }
+    }
+
+    fn on_change(&mut self, value: &str) {
+        let values = value.split(';').collect::<Vec<&str>>();
+        let (kind, x, y, buttons) = match values.as_slice() {
+            ["pointer", kind, x, y, buttons] => (*kind, *x, *y, *buttons),
+            _ => return,
+        };
+        let kind = match kind {
+            "down" => PointerKind::Down,
+            "up" => PointerKind::Up,
+            "move" => PointerKind::Move,
+            _ => return,
+        };
+        if let (Ok(x), Ok(y), Ok(buttons)) =
+            (x.parse::<f64>(), y.parse::<f64>(), buttons.parse::<u32>())
+        {
+            self.state.pointer = Some(Pointer {
+                kind,
+                x,
+                y,
+                buttons,
+            });
+            match &self.listener {
+                None => (),
+                Some(listener) => {
+                    listener.on_change(&self.state);
+                }
+            }
+        }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+}
@@ -0,0 +1,887 @@
+use crate::utils::event::Event;
+use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
+use crate::widgets::widget::Widget;
+use std::f64::consts::PI;
+
+/// # The kind of a Chart
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChartKind {
+    Line,
+    Bar,
+    Pie,
+}
+
+impl ChartKind {
+    /// Get the CSS class of the chart
+    pub fn css(self) -> &'static str {
+        match self {
+            ChartKind::Line => "chart-line",
+            ChartKind::Bar => "chart-bar",
+            ChartKind::Pie => "chart-pie",
+        }
+    }
+}
+
+/// # A named list of values displayed by a Chart
+///
+/// The values are matched with the labels of the chart by their index. The
+/// series are colored with the palette of the theme unless a color is set.
+///
+/// ## Fields
+///
+/// ```text
+/// name: String
+/// values: Vec<f64>
+/// color: Option<String>
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series {
+    name: String,
+    values: Vec<f64>,
+    color: Option<String>,
+}
+
+impl Series {
+    /// Create a Series
+    pub fn new(name: &str, values: Vec<f64>) -> Self {
+        Self {
+            name: name.to_string(),
+            values,
+            color: None,
+        }
+    }
+
+    /// Get the name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the values
+    pub fn values(&self) -> &Vec<f64> {
+        &self.values
+    }
+
+    /// Get the color
+    pub fn color(&self) -> Option<&str> {
+        self.color.as_ref().map(String::as_ref)
+    }
+
+    /// Set the values
+    pub fn set_values(&mut self, values: Vec<f64>) {
+        self.values = values;
+    }
+
+    /// Add a value
+    pub fn push(&mut self, value: f64) {
+        self.values.push(value);
+    }
+
+    /// Set the color
+    pub fn set_color(&mut self, color: &str) {
+        self.color = Some(color.to_string());
+    }
+}
+
+/// # The state of a Chart
+///
+/// ## Fields
+///
+/// ```text
+/// kind: ChartKind
+/// labels: Vec<String>
+/// series: Vec<Series>
+/// width: u32
+/// height: u32
+/// legend: bool
+/// stretched: bool
+/// style: String
+/// tooltip: Option<Tooltip>
+/// ```
+pub struct ChartState {
+    kind: ChartKind,
+    labels: Vec<String>,
+    series: Vec<Series>,
+    width: u32,
+    height: u32,
+    legend: bool,
+    stretched: bool,
+    style: String,
+    tooltip: Option<Tooltip>,
+}
+
+impl ChartState {
+    /// Get the kind
+    pub fn kind(&self) -> ChartKind {
+        self.kind
+    }
+
+    /// Get the labels
+    pub fn labels(&self) -> &Vec<String> {
+        &self.labels
+    }
+
+    /// Get the series
+    pub fn series(&self) -> &Vec<Series> {
+        &self.series
+    }
+
+    /// Get the width
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Get the height
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Get the legend flag
+    pub fn legend(&self) -> bool {
+        self.legend
+    }
+
+    /// Get the stretched flag
+    pub fn stretched(&self) -> bool {
+        self.stretched
+    }
+
+    /// Get the style
+    pub fn style(&self) -> &str {
+        &self.style
+    }
+
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
+    /// Set the kind
+    pub fn set_kind(&mut self, kind: ChartKind) {
+        self.kind = kind;
+    }
+
+    /// Set the labels
+    pub fn set_labels(&mut self, labels: Vec<&str>) {
+        self.labels = labels.iter().map(|label| label.to_string()).collect();
+    }
+
+    /// Set the series
+    pub fn set_series(&mut self, series: Vec<Series>) {
+        self.series = series;
+    }
+
+    /// Get a mutable series by name
+    pub fn series_mut(&mut self, name: &str) -> Option<&mut Series> {
+        self.series.iter_mut().find(|series| series.name() == name)
+    }
+
+    /// Add a label and a value to each series, removing the first ones
+    /// when there are more than `limit` labels
+    ///
+    /// The series without a value in `values` are given 0.
+    pub fn push(&mut self, label: &str, values: Vec<f64>, limit: usize) {
+        self.labels.push(label.to_string());
+        for (i, series) in self.series.iter_mut().enumerate() {
+            series.push(values.get(i).cloned().unwrap_or(0.0));
+        }
+        while self.labels.len() > limit {
+            self.labels.remove(0);
+            for series in self.series.iter_mut() {
+                if !series.values.is_empty() {
+                    series.values.remove(0);
+                }
+            }
+        }
+    }
+
+    /// Set the size (width and height)
+    pub fn set_size(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Set the legend flag
+    pub fn set_legend(&mut self, legend: bool) {
+        self.legend = legend;
+    }
+
+    /// Set the stretched flag
+    pub fn set_stretched(&mut self, stretched: bool) {
+        self.stretched = stretched;
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.style = style.to_string();
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
+}
+
+/// # The listener of a Chart
+pub trait ChartListener {
+    /// Function triggered on update event
+    fn on_update(&self, state: &mut ChartState);
+}
+
+/// # A line, bar or pie chart
+///
+/// The chart is drawn in SVG. The line and bar charts display each series
+/// along a vertical axis, with the labels on the horizontal axis. The pie
+/// chart displays the values of the first series, a slice per label. The
+/// values are shown when a point, a bar or a slice is hovered.
+///
+/// The chart is redrawn on each update, so it can be fed from the `on_tick`
+/// function of a WindowListener.
+///
+/// ## Fields
+///
+/// ```text
+/// name: String
+/// state: ChartState
+/// listener: Option<Box<dyn ChartListener>>
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// name: name.to_string()
+/// state:
+///     kind: ChartKind::Line
+///     labels: vec![]
+///     series: vec![]
+///     width: 400
+///     height: 250
+///     legend: true
+///     stretched: false
+///     style: "".to_string()
+///     tooltip: None
+/// listener: None
+/// ```
+///
+/// ## Style
+///
+/// ```text
+/// div.chart.chart-(line|bar|pie)
+///     svg
+///         line.chart-grid
+///         line.chart-axis
+///         text.chart-tick
+///         text.chart-label
+///         polyline.chart-color-N
+///         circle.chart-color-N
+///         rect.chart-color-N
+///         path.chart-color-N
+///     div.chart-legend
+///         div.chart-legend-item
+///             span.chart-swatch.chart-color-N
+///             span
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use neutrino::widgets::chart::{
+///     Chart,
+///     ChartKind,
+///     ChartListener,
+///     ChartState,
+///     Series
+/// };
+///
+///
+/// struct Metrics {
+///     ticks: u32,
+///     load: f64,
+/// }
+///
+/// impl Metrics {
+///     fn new() -> Self {
+///         Self { ticks: 0, load: 0.0 }
+///     }
+///
+///     fn ticks(&self) -> u32 {
+///         self.ticks
+///     }
+///
+///     fn load(&self) -> f64 {
+///         self.load
+///     }
+/// }
+///
+///
+/// struct MyChartListener {
+///     metrics: Rc<RefCell<Metrics>>,
+/// }
+///
+/// impl MyChartListener {
+///    pub fn new(metrics: Rc<RefCell<Metrics>>) -> Self {
+///        Self { metrics }
+///    }
+/// }
+///
+/// impl ChartListener for MyChartListener {
+///     fn on_update(&self, state: &mut ChartState) {
+///         let metrics = self.metrics.borrow();
+///         let label = metrics.ticks().to_string();
+///         if state.labels().last() != Some(&label) {
+///             state.push(&label, vec![metrics.load()], 30);
+///         }
+///     }
+/// }
+///
+///
+/// fn main() {
+///     let metrics = Rc::new(RefCell::new(Metrics::new()));
+///
+///     let my_listener = MyChartListener::new(Rc::clone(&metrics));
+///
+///     let mut my_chart = Chart::new("my_chart");
+///     my_chart.set_kind(ChartKind::Line);
+///     my_chart.add_series(Series::new("Load", vec![]));
+///     my_chart.set_listener(Box::new(my_listener));
+/// }
+/// ```
+pub struct Chart {
+    name: String,
+    state: ChartState,
+    listener: Option<Box<dyn ChartListener>>,
+}
+
+impl Chart {
+    /// Create a Chart
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            state: ChartState {
+                kind: ChartKind::Line,
+                labels: vec![],
+                series: vec![],
+                width: 400,
+                height: 250,
+                legend: true,
+                stretched: false,
+                style: "".to_string(),
+                tooltip: None,
+            },
+            listener: None,
+        }
+    }
+
+    /// Set the kind
+    pub fn set_kind(&mut self, kind: ChartKind) {
+        self.state.set_kind(kind);
+    }
+
+    /// Set the labels
+    pub fn set_labels(&mut self, labels: Vec<&str>) {
+        self.state.set_labels(labels);
+    }
+
+    /// Add a series
+    pub fn add_series(&mut self, series: Series) {
+        self.state.series.push(series);
+    }
+
+    /// Set the size (width and height)
+    pub fn set_size(&mut self, width: u32, height: u32) {
+        self.state.set_size(width, height);
+    }
+
+    /// Set the legend flag to false
+    pub fn hide_legend(&mut self) {
+        self.state.set_legend(false);
+    }
+
+    /// Set the stretched flag to true
+    pub fn set_stretched(&mut self) {
+        self.state.set_stretched(true);
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &ChartState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut ChartState {
+        &mut self.state
+    }
+
+    /// Set the listener
+    pub fn set_listener(&mut self, listener: Box<dyn ChartListener>) {
+        self.listener = Some(listener);
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.state.set_style(style);
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
+
+    /// Return the HTML representation of the axes of a line or bar chart,
+    /// and the function placing a value on the vertical axis
+    fn eval_axes(&self, area: &Area) -> (String, Scale) {
+        let values = self
+            .state
+            .series()
+            .iter()
+            .flat_map(|series| series.values().iter().cloned())
+            .collect::<Vec<f64>>();
+        let scale = Scale::new(&values, area);
+        let mut html = "".to_string();
+        for tick in scale.ticks() {
+            let y = scale.y(tick);
+            html.push_str(&format!(
+                r#"
+                <line class="chart-grid" x1="{0:.1}" y1="{1:.1}" x2="{2:.1}"
+                    y2="{1:.1}" />
+                <text class="chart-tick" x="{3:.1}" y="{1:.1}"
+                    text-anchor="end" dominant-baseline="middle">{4}</text>
+                "#,
+                area.left,
+                y,
+                area.right,
+                area.left - 6.0,
+                number(tick),
+            ));
+        }
+        let count = self.state.labels().len();
+        let every = (count as f64 / 10.0).ceil().max(1.0) as usize;
+        for (i, label) in self.state.labels().iter().enumerate() {
+            if i % every != 0 {
+                continue;
+            }
+            html.push_str(&format!(
+                r#"
+                <text class="chart-label" x="{:.1}" y="{:.1}"
+                    text-anchor="middle" dominant-baseline="hanging">{}</text>
+                "#,
+                self.x(area, i),
+                area.bottom + 6.0,
+                label,
+            ));
+        }
+        html.push_str(&format!(
+            r#"
+            <line class="chart-axis" x1="{0:.1}" y1="{1:.1}" x2="{0:.1}"
+                y2="{2:.1}" />
+            <line class="chart-axis" x1="{0:.1}" y1="{3:.1}" x2="{4:.1}"
+                y2="{3:.1}" />
+            "#,
+            area.left,
+            area.top,
+            area.bottom,
+            scale.y(0.0_f64.max(scale.min).min(scale.max)),
+            area.right,
+        ));
+        (html, scale)
+    }
+
+    /// Return the horizontal center of the label at `index`
+    fn x(&self, area: &Area, index: usize) -> f64 {
+        let count = self.state.labels().len().max(1) as f64;
+        match self.state.kind() {
+            ChartKind::Bar => {
+                area.left + (index as f64 + 0.5) * area.width() / count
+            }
+            _ if count <= 1.0 => area.left + area.width() / 2.0,
+            _ => area.left + index as f64 * area.width() / (count - 1.0),
+        }
+    }
+
+    /// Return the label at `index`, or the index if there is no label
+    fn label(&self, index: usize) -> String {
+        match self.state.labels().get(index) {
+            Some(label) => label.to_string(),
+            None => (index + 1).to_string(),
+        }
+    }
+
+    /// Return the HTML representation of the lines
+    fn eval_lines(&self, area: &Area, scale: &Scale) -> String {
+        let mut html = "".to_string();
+        for (i, series) in self.state.series().iter().enumerate() {
+            let points = series
+                .values()
+                .iter()
+                .enumerate()
+                .map(|(j, value)| {
+                    format!("{:.1},{:.1}", self.x(area, j), scale.y(*value))
+                })
+                .collect::<Vec<String>>()
+                .join(" ");
+            html.push_str(&format!(
+                r#"<polyline class="{}" {} fill="none" points="{}" />"#,
+                color_class(i),
+                color_style(series, "stroke"),
+                points,
+            ));
+            for (j, value) in series.values().iter().enumerate() {
+                html.push_str(&format!(
+                    r#"
+                    <circle class="{}" {} cx="{:.1}" cy="{:.1}" r="3">
+                        <title>{}: {} = {}</title>
+                    </circle>
+                    "#,
+                    color_class(i),
+                    color_style(series, "fill"),
+                    self.x(area, j),
+                    scale.y(*value),
+                    series.name(),
+                    self.label(j),
+                    number(*value),
+                ));
+            }
+        }
+        html
+    }
+
+    /// Return the HTML representation of the bars
+    fn eval_bars(&self, area: &Area, scale: &Scale) -> String {
+        let count = self.state.labels().len().max(1) as f64;
+        let group = area.width() / count;
+        let width = group * 0.8 / self.state.series().len().max(1) as f64;
+        let zero = scale.y(0.0_f64.max(scale.min).min(scale.max));
+        let mut html = "".to_string();
+        for (i, series) in self.state.series().iter().enumerate() {
+            for (j, value) in series.values().iter().enumerate() {
+                let y = scale.y(*value);
+                html.push_str(&format!(
+                    r#"
+                    <rect class="{}" {} x="{:.1}" y="{:.1}" width="{:.1}"
+                        height="{:.1}">
+                        <title>{}: {} = {}</title>
+                    </rect>
+                    "#,
+                    color_class(i),
+                    color_style(series, "fill"),
+                    area.left
+                        + j as f64 * group
+                        + group * 0.1
+                        + i as f64 * width,
+                    y.min(zero),
+                    width,
+                    (zero - y).abs(),
+                    series.name(),
+                    self.label(j),
+                    number(*value),
+                ));
+            }
+        }
+        html
+    }
+
+    /// Return the HTML representation of the slices
+    fn eval_slices(&self) -> String {
+        let series = match self.state.series().first() {
+            Some(series) => series,
+            None => return "".to_string(),
+        };
+        let values = series
+            .values()
+            .iter()
+            .map(|value| value.max(0.0))
+            .collect::<Vec<f64>>();
+        let total = values.iter().sum::<f64>();
+        if total <= 0.0 {
+            return "".to_string();
+        }
+        let cx = f64::from(self.state.width()) / 2.0;
+        let cy = f64::from(self.state.height()) / 2.0;
+        let radius = (cx.min(cy) - 10.0).max(0.0);
+        let point =
+            |angle: f64| (cx + radius * angle.sin(), cy - radius * angle.cos());
+        let mut html = "".to_string();
+        let mut start = 0.0;
+        for (i, value) in values.iter().enumerate() {
+            let angle = value / total * 2.0 * PI;
+            let (tag, attributes) = if angle >= 2.0 * PI - 1e-9 {
+                (
+                    "circle",
+                    format!(
+                        r#"cx="{:.1}" cy="{:.1}" r="{:.1}""#,
+                        cx, cy, radius
+                    ),
+                )
+            } else {
+                let (x0, y0) = point(start);
+                let (x1, y1) = point(start + angle);
+                (
+                    "path",
+                    format!(
+                        r#"d="M {:.1} {:.1} L {:.1} {:.1}
+                            A {:.1} {:.1} 0 {} 1 {:.1} {:.1} Z""#,
+                        cx,
+                        cy,
+                        x0,
+                        y0,
+                        radius,
+                        radius,
+                        if angle > PI { 1 } else { 0 },
+                        x1,
+                        y1,
+                    ),
+                )
+            };
+            html.push_str(&format!(
+                r#"
+                <{0} {1} class="{2}">
+                    <title>{3} = {4} ({5}%)</title>
+                </{0}>
+                "#,
+                tag,
+                attributes,
+                color_class(i),
+                self.label(i),
+                number(series.values()[i]),
+                number(value / total * 100.0),
+            ));
+            start += angle;
+        }
+        html
+    }
+
+    /// Return the HTML representation of the legend
+    fn eval_legend(&self) -> String {
+        if !self.state.legend() {
+            return "".to_string();
+        }
+        let items = match self.state.kind() {
+            ChartKind::Pie => (0..self.state.labels().len())
+                .map(|i| (self.label(i), "".to_string()))
+                .collect::<Vec<(String, String)>>(),
+            _ => self
+                .state
+                .series()
+                .iter()
+                .map(|series| {
+                    (
+                        series.name().to_string(),
+                        color_style(series, "background"),
+                    )
+                })
+                .collect(),
+        };
+        let mut html = r#"<div class="chart-legend">"#.to_string();
+        for (i, (name, style)) in items.iter().enumerate() {
+            html.push_str(&format!(
+                r#"
+                <div class="chart-legend-item">
+                    <span class="chart-swatch {}" {}></span>
+                    <span>{}</span>
+                </div>
+                "#,
+                color_class(i),
+                style,
+                name,
+            ));
+        }
+        html.push_str("</div>");
+        html
+    }
+}
+
+impl Widget for Chart {
+    fn eval(&self) -> String {
+        let stretched = if self.state.stretched() {
+            "stretched"
+        } else {
+            ""
+        };
+        let style = inline_style(&scss_to_css(&format!(
+            r##"#{}{{{}}}"##,
+            self.name,
+            self.state.style(),
+        )));
+        let area = Area::new(self.state.width(), self.state.height());
+        let svg = match self.state.kind() {
+            ChartKind::Line => {
+                let (axes, scale) = self.eval_axes(&area);
+                format!("{}{}", axes, self.eval_lines(&area, &scale))
+            }
+            ChartKind::Bar => {
+                let (axes, scale) = self.eval_axes(&area);
+                format!("{}{}", axes, self.eval_bars(&area, &scale))
+            }
+            ChartKind::Pie => self.eval_slices(),
+        };
+        let html = format!(
+            r#"
+            <div id="{}" class="chart {} {}">
+                <svg viewBox="0 0 {} {}" preserveAspectRatio="xMidYMid meet">
+                    {}
+                </svg>
+                {}
+            </div>
+            "#,
+            self.name,
+            self.state.kind().css(),
+            stretched,
+            self.state.width(),
+            self.state.height(),
+            svg,
+            self.eval_legend(),
+        );
+        format!("{}{}", style, html)
+    }
+
+    fn trigger(&mut self, event: &Event) {
+        match event {
+            Event::Update => self.on_update(),
+            Event::Change { source, value } => {
+                if source == &self.name {
+                    self.on_change(value)
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn on_update(&mut self) {
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_update(&mut self.state);
+            }
+        }
+    }
+
+    fn on_change(&mut self, _value: &str) {}
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+}
+
+/// The plotting area of a line or bar chart
+struct Area {
+    left: f64,
+    top: f64,
+    right: f64,
+    bottom: f64,
+}
+
+impl Area {
+    /// Create the Area of a chart of the given size, empty if the chart is
+    /// too small to hold the axes
+    fn new(width: u32, height: u32) -> Self {
+        let (left, top) = (44.0, 10.0);
+        Self {
+            left,
+            top,
+            right: (f64::from(width) - 14.0).max(left),
+            bottom: (f64::from(height) - 24.0).max(top),
+        }
+    }
+
+    /// Get the width
+    fn width(&self) -> f64 {
+        self.right - self.left
+    }
+}
+
+/// The vertical axis of a line or bar chart
+struct Scale {
+    min: f64,
+    max: f64,
+    step: f64,
+    top: f64,
+    bottom: f64,
+}
+
+impl Scale {
+    /// Create a Scale fitting the values and 0 with round ticks
+    fn new(values: &[f64], area: &Area) -> Self {
+        let min = values.iter().cloned().fold(0.0, f64::min);
+        let max = values.iter().cloned().fold(0.0, f64::max);
+        let range = if max > min { max - min } else { 1.0 };
+        let rough = range / 4.0;
+        let magnitude = 10_f64.powf(rough.log10().floor());
+        let step = match rough / magnitude {
+            f if f <= 1.0 => magnitude,
+            f if f <= 2.0 => 2.0 * magnitude,
+            f if f <= 5.0 => 5.0 * magnitude,
+            _ => 10.0 * magnitude,
+        };
+        Self {
+            min: (min / step).floor() * step,
+            max: ((max / step).ceil() * step).max(step),
+            step,
+            top: area.top,
+            bottom: area.bottom,
+        }
+    }
+
+    /// Get the values of the ticks
+    fn ticks(&self) -> Vec<f64> {
+        let count = ((self.max - self.min) / self.step).round() as usize;
+        (0..=count)
+            .map(|i| self.min + i as f64 * self.step)
+            .collect()
+    }
+
+    /// Get the vertical position of a value
+    fn y(&self, value: f64) -> f64 {
+        self.bottom
+            - (value - self.min) / (self.max - self.min)
+                * (self.bottom - self.top)
+    }
+}
+
+/// Get the palette class of the series or slice at `index`
+fn color_class(index: usize) -> String {
+    format!("chart-color-{}", index % 8)
+}
+
+/// Get the style attribute overriding the palette color of a series
+fn color_style(series: &Series, property: &str) -> String {
+    match series.color() {
+        Some(color) => format!(r#"style="{}: {};""#, property, color),
+        None => "".to_string(),
+    }
+}
+
+/// Format a number with at most 2 decimals
+fn number(value: f64) -> String {
+    let rounded = (value * 100.0).round() / 100.0;
+    if rounded == 0.0 {
+        "0".to_string()
+    } else {
+        rounded.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_charts_have_no_negative_sizes() {
+        let mut chart = Chart::new("chart");
+        chart.set_kind(ChartKind::Bar);
+        chart.set_labels(vec!["a", "b"]);
+        chart.add_series(Series::new("s", vec![1.0, -2.0]));
+        chart.set_size(20, 10);
+        assert!(!chart.eval().contains("width=\"-"));
+        assert!(!chart.eval().contains("height=\"-"));
+        chart.set_kind(ChartKind::Pie);
+        assert!(!chart.eval().contains("r=\"-"));
+        assert!(!chart.eval().contains("A -"));
+    }
+}
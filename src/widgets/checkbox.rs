@@ -1,7 +1,32 @@
-use crate::utils::event::Event;
+use std::collections::HashSet;
+
+use crate::utils::event::{Event, Key};
 use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
+use crate::utils::validation::{Field, FieldValue, Validation, Validator};
 use crate::widgets::widget::Widget;
 
+/// # The position of the label of a CheckBox
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LabelPosition {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl LabelPosition {
+    /// Get the CSS class of the position
+    pub fn css(&self) -> &str {
+        match self {
+            LabelPosition::Left => "label-left",
+            LabelPosition::Right => "label-right",
+            LabelPosition::Top => "label-top",
+            LabelPosition::Bottom => "label-bottom",
+        }
+    }
+}
+
 /// # The state of a CheckBox
 ///
 /// ## Fields
@@ -9,16 +34,26 @@ use crate::widgets::widget::Widget;
 /// ```text
 /// text: String
 /// checked: bool
+/// indeterminate: bool
+/// switch: bool
+/// label_position: LabelPosition
+/// validation: Validation
 /// disabled: bool
 /// stretched: bool
 /// style: String
+/// tooltip: Option<Tooltip>
 /// ```
 pub struct CheckBoxState {
     text: String,
     checked: bool,
+    indeterminate: bool,
+    switch: bool,
+    label_position: LabelPosition,
+    validation: Validation,
     disabled: bool,
     stretched: bool,
     style: String,
+    tooltip: Option<Tooltip>,
 }
 
 impl CheckBoxState {
@@ -32,6 +67,26 @@ impl CheckBoxState {
         self.checked
     }
 
+    /// Get the indeterminate flag
+    pub fn indeterminate(&self) -> bool {
+        self.indeterminate
+    }
+
+    /// Get the switch flag
+    pub fn switch(&self) -> bool {
+        self.switch
+    }
+
+    /// Get the label position
+    pub fn label_position(&self) -> LabelPosition {
+        self.label_position
+    }
+
+    /// Get the validation
+    pub fn validation(&self) -> &Validation {
+        &self.validation
+    }
+
     /// Get the disabled flag
     pub fn disabled(&self) -> bool {
         self.disabled
@@ -47,6 +102,11 @@ impl CheckBoxState {
         &self.style
     }
 
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
     /// Set the text
     pub fn set_text(&mut self, text: &str) {
         self.text = text.to_string();
@@ -57,6 +117,27 @@ impl CheckBoxState {
         self.checked = checked;
     }
 
+    /// Set the indeterminate flag
+    pub fn set_indeterminate(&mut self, indeterminate: bool) {
+        self.indeterminate = indeterminate;
+    }
+
+    /// Set the switch flag
+    pub fn set_switch(&mut self, switch: bool) {
+        self.switch = switch;
+    }
+
+    /// Set the label position
+    pub fn set_label_position(&mut self, label_position: LabelPosition) {
+        self.label_position = label_position;
+    }
+
+    /// Toggle the checked flag, an indeterminate checkbox becoming checked
+    pub fn toggle(&mut self) {
+        self.checked = self.indeterminate || !self.checked;
+        self.indeterminate = false;
+    }
+
     /// Set the disabled flag
     pub fn set_disabled(&mut self, disabled: bool) {
         self.disabled = disabled;
@@ -71,6 +152,11 @@ impl CheckBoxState {
     pub fn set_style(&mut self, style: &str) {
         self.style = style.to_string();
     }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
 }
 
 /// # The listener of a Checkbox
@@ -84,6 +170,11 @@ pub trait CheckBoxListener {
 
 /// # A togglable checkbox with a label
 ///
+/// The checkbox is toggled by a click or with the Space key once focused.
+/// An indeterminate checkbox, showing for instance that a list is partially
+/// selected, becomes checked when it is toggled. The switch flag displays
+/// the checkbox as a toggle switch.
+///
 /// ## Fields
 ///
 /// ```text
@@ -99,19 +190,28 @@ pub trait CheckBoxListener {
 /// state:
 ///     text: "CheckBox".to_string()
 ///     checked: false
+///     indeterminate: false
+///     switch: false
+///     label_position: LabelPosition::Right
+///     validation: Validation::new()
 ///     disabled: false
 ///     stretched: false
 ///     style: "".to_string()
+///     tooltip: None
 /// listener: None
 /// ```
 ///
 /// ## Style
 ///
 /// ```text
-/// div.checkbox[.disabled][.checked]
+/// div.checkbox[.disabled][.checked][.indeterminate][.switch][.invalid]
+///     [.label-left][.label-right][.label-top][.label-bottom]
 ///     div.checkbox-outer
 ///         div.checkbox-inner
+///     div.switch-track
+///         div.switch-thumb
 ///     label
+///     div.validation-error
 /// ```
 ///
 /// ## Example
@@ -193,9 +293,14 @@ impl CheckBox {
             state: CheckBoxState {
                 text: "CheckBox".to_string(),
                 checked: false,
+                indeterminate: false,
+                switch: false,
+                label_position: LabelPosition::Right,
+                validation: Validation::new(),
                 disabled: false,
                 stretched: false,
                 style: "".to_string(),
+                tooltip: None,
             },
             listener: None,
         }
@@ -211,6 +316,21 @@ impl CheckBox {
         self.state.set_checked(true);
     }
 
+    /// Set the indeterminate flag to true
+    pub fn set_indeterminate(&mut self) {
+        self.state.set_indeterminate(true);
+    }
+
+    /// Set the switch flag to true
+    pub fn set_switch(&mut self) {
+        self.state.set_switch(true);
+    }
+
+    /// Set the label position
+    pub fn set_label_position(&mut self, label_position: LabelPosition) {
+        self.state.set_label_position(label_position);
+    }
+
     /// Set the disabled flag to true
     pub fn set_disabled(&mut self) {
         self.state.set_disabled(true);
@@ -221,6 +341,21 @@ impl CheckBox {
         self.state.set_stretched(true);
     }
 
+    /// Add a validator
+    pub fn add_validator(&mut self, validator: Validator) {
+        self.state.validation.add(validator);
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &CheckBoxState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut CheckBoxState {
+        &mut self.state
+    }
+
     /// Set the listener
     pub fn set_listener(&mut self, listener: Box<dyn CheckBoxListener>) {
         self.listener = Some(listener);
@@ -230,11 +365,29 @@ impl CheckBox {
     pub fn set_style(&mut self, style: &str) {
         self.state.set_style(style);
     }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
+
+    /// Function triggered on keypress event
+    fn on_keys(&mut self, keys: &HashSet<Key>) {
+        if keys.contains(&Key::Space) {
+            self.on_change("");
+        }
+    }
 }
 
 impl Widget for CheckBox {
     fn eval(&self) -> String {
         let checked = if self.state.checked() { "checked" } else { "" };
+        let indeterminate = if self.state.indeterminate() {
+            "indeterminate"
+        } else {
+            ""
+        };
+        let switch = if self.state.switch() { "switch" } else { "" };
         let stretched = if self.state.stretched() {
             "stretched"
         } else {
@@ -250,22 +403,48 @@ impl Widget for CheckBox {
             self.name,
             self.state.style(),
         )));
-        let html = format!(
+        let indicator = if self.state.switch() {
+            r#"
+            <div class="switch-track">
+                <div class="switch-thumb">
+                </div>
+            </div>
+            "#
+        } else {
             r#"
-            <div id="{}" class="checkbox {} {} {}" onclick="{}">
-                <div class="checkbox-outer">
-                    <div class="checkbox-inner">
-                    </div>
+            <div class="checkbox-outer">
+                <div class="checkbox-inner">
                 </div>
+            </div>
+            "#
+        };
+        let keydown = format!(
+            "event.preventDefault(); {}",
+            Event::keypress_js(&self.name, "down")
+        );
+        let html = format!(
+            r#"
+            <div id="{}" class="checkbox {} {} {} {} {} {} {}" tabindex="0"
+                onclick="{}" onkeydown="if (event.key === ' ') {{ {} }}"
+            >
+                {}
                 <label>{}</label>
+                {}
             </div>
             "#,
             self.name,
             disabled,
             checked,
+            indeterminate,
+            switch,
+            self.state.label_position().css(),
             stretched,
+            self.state.validation().css(),
             Event::change_js(&self.name, "''"),
+            keydown,
+            indicator,
             self.state.text,
+            self.state.validation().eval(),
         );
         format!("{}{}", style, html)
     }
@@ -278,6 +457,11 @@ impl Widget for CheckBox {
                     self.on_change(value)
                 }
             }
+            Event::Keypress { source, keys } => {
+                if source == &self.name && !self.state.disabled() {
+                    self.on_keys(keys);
+                }
+            }
             _ => (),
         }
     }
@@ -292,7 +476,8 @@ impl Widget for CheckBox {
     }
 
     fn on_change(&mut self, _value: &str) {
-        self.state.set_checked(!self.state.checked());
+        self.state.toggle();
+        self.validate();
         match &self.listener {
             None => (),
             Some(listener) => {
@@ -300,4 +485,35 @@ impl Widget for CheckBox {
             }
         }
     }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+
+    fn field(&self) -> Option<&dyn Field> {
+        Some(self)
+    }
+
+    fn field_mut(&mut self) -> Option<&mut dyn Field> {
+        Some(self)
+    }
+}
+
+impl Field for CheckBox {
+    fn value(&self) -> FieldValue {
+        FieldValue::Bool(self.state.checked())
+    }
+
+    fn validation(&self) -> &Validation {
+        self.state.validation()
+    }
+
+    fn validate(&mut self) -> bool {
+        let value = self.value();
+        self.state.validation.validate(&value)
+    }
 }
@@ -0,0 +1,643 @@
+use crate::utils::color::Color;
+use crate::utils::event::Event;
+use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
+use crate::widgets::widget::Widget;
+
+/// # The state of a ColorPicker
+///
+/// ## Fields
+///
+/// ```text
+/// color: Color
+/// hue: f64
+/// saturation: f64
+/// lightness: f64
+/// swatches: Vec<Color>
+/// alpha: bool
+/// request: u32
+/// disabled: bool
+/// stretched: bool
+/// style: String
+/// tooltip: Option<Tooltip>
+/// ```
+pub struct ColorPickerState {
+    color: Color,
+    hue: f64,
+    saturation: f64,
+    lightness: f64,
+    swatches: Vec<Color>,
+    alpha: bool,
+    request: u32,
+    disabled: bool,
+    stretched: bool,
+    style: String,
+    tooltip: Option<Tooltip>,
+}
+
+impl ColorPickerState {
+    /// Get the color
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    /// Get the hue in degrees
+    pub fn hue(&self) -> f64 {
+        self.hue
+    }
+
+    /// Get the saturation in percents
+    pub fn saturation(&self) -> f64 {
+        self.saturation
+    }
+
+    /// Get the lightness in percents
+    pub fn lightness(&self) -> f64 {
+        self.lightness
+    }
+
+    /// Get the swatches
+    pub fn swatches(&self) -> &Vec<Color> {
+        &self.swatches
+    }
+
+    /// Get the alpha flag
+    pub fn alpha(&self) -> bool {
+        self.alpha
+    }
+
+    /// Get the disabled flag
+    pub fn disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Get the stretched flag
+    pub fn stretched(&self) -> bool {
+        self.stretched
+    }
+
+    /// Get the style
+    pub fn style(&self) -> &str {
+        &self.style
+    }
+
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
+    /// Set the color
+    ///
+    /// The hue and the saturation are kept when the new color does not
+    /// define them, as for greys.
+    pub fn set_color(&mut self, color: Color) {
+        if color == self.color {
+            return;
+        }
+        let (hue, saturation, lightness) = color.to_hsl();
+        if saturation > 0.0 && lightness > 0.0 && lightness < 100.0 {
+            self.hue = hue;
+            self.saturation = saturation;
+        }
+        self.lightness = lightness;
+        self.color = color;
+        self.request += 1;
+    }
+
+    /// Set the color from its hue in degrees, and its saturation and
+    /// lightness in percents, keeping the alpha
+    pub fn set_hsl(&mut self, hue: f64, saturation: f64, lightness: f64) {
+        self.hue = hue.clamp(0.0, 360.0);
+        self.saturation = saturation.clamp(0.0, 100.0);
+        self.lightness = lightness.clamp(0.0, 100.0);
+        self.color = Color::hsla(
+            self.hue,
+            self.saturation,
+            self.lightness,
+            self.color.alpha(),
+        );
+        self.request += 1;
+    }
+
+    /// Set the alpha of the color
+    pub fn set_opacity(&mut self, alpha: f64) {
+        let color = self.color;
+        self.color =
+            Color::rgba(color.red(), color.green(), color.blue(), alpha);
+        self.request += 1;
+    }
+
+    /// Set the swatches
+    pub fn set_swatches(&mut self, swatches: Vec<Color>) {
+        self.swatches = swatches;
+    }
+
+    /// Set the alpha flag
+    pub fn set_alpha(&mut self, alpha: bool) {
+        self.alpha = alpha;
+    }
+
+    /// Set the disabled flag
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+
+    /// Set the stretched flag
+    pub fn set_stretched(&mut self, stretched: bool) {
+        self.stretched = stretched;
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.style = style.to_string();
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
+}
+
+/// # The listener of a ColorPicker
+pub trait ColorPickerListener {
+    /// Function triggered on update event
+    fn on_update(&self, state: &mut ColorPickerState);
+
+    /// Function triggered on change event
+    ///
+    /// The texts which are not colors are rejected and do not trigger this
+    /// function.
+    fn on_change(&self, state: &ColorPickerState);
+}
+
+/// # A color chooser
+///
+/// The hue and the saturation are picked in an area, the lightness and the
+/// alpha with sliders. The color can also be typed in the hexadecimal, RGB
+/// or HSL notations, or picked in a palette of swatches. The alpha slider
+/// is hidden and the colors are opaque when the alpha flag is false.
+///
+/// ## Fields
+///
+/// ```text
+/// name: String
+/// state: ColorPickerState
+/// listener: Option<Box<dyn ColorPickerListener>>
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// name: name.to_string()
+/// state:
+///     color: Color::rgb(255, 0, 0)
+///     hue: 0.0
+///     saturation: 100.0
+///     lightness: 50.0
+///     swatches: 16 common colors
+///     alpha: true
+///     request: 0
+///     disabled: false
+///     stretched: false
+///     style: "".to_string()
+///     tooltip: None
+/// listener: None
+/// ```
+///
+/// ## Style
+///
+/// ```text
+/// div.colorpicker[.disabled]
+///     div.colorpicker-picker
+///         div.colorpicker-area
+///             div.colorpicker-marker
+///         div.colorpicker-sliders
+///             input.colorpicker-lightness
+///             input.colorpicker-alpha
+///         div.colorpicker-preview
+///             div.colorpicker-color
+///     div.colorpicker-fields
+///         label
+///         input.colorpicker-hex
+///         label
+///         input.colorpicker-rgb
+///         label
+///         input.colorpicker-hsl
+///     div.colorpicker-swatches
+///         div.colorpicker-swatch[.selected]
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use neutrino::utils::color::Color;
+/// use neutrino::widgets::colorpicker::{
+///     ColorPicker,
+///     ColorPickerListener,
+///     ColorPickerState
+/// };
+///
+///
+/// struct Palette {
+///     accent: Color,
+/// }
+///
+/// impl Palette {
+///     fn new() -> Self {
+///         Self { accent: Color::rgb(53, 132, 228) }
+///     }
+///
+///     fn accent(&self) -> Color {
+///         self.accent
+///     }
+///
+///     fn set_accent(&mut self, accent: Color) {
+///         self.accent = accent;
+///     }
+/// }
+///
+///
+/// struct MyColorPickerListener {
+///     palette: Rc<RefCell<Palette>>,
+/// }
+///
+/// impl MyColorPickerListener {
+///    pub fn new(palette: Rc<RefCell<Palette>>) -> Self {
+///        Self { palette }
+///    }
+/// }
+///
+/// impl ColorPickerListener for MyColorPickerListener {
+///     fn on_change(&self, state: &ColorPickerState) {
+///         self.palette.borrow_mut().set_accent(state.color());
+///     }
+///
+///     fn on_update(&self, state: &mut ColorPickerState) {
+///         state.set_color(self.palette.borrow().accent());
+///     }
+/// }
+///
+///
+/// fn main() {
+///     let palette = Rc::new(RefCell::new(Palette::new()));
+///
+///     let my_listener = MyColorPickerListener::new(Rc::clone(&palette));
+///
+///     let mut my_colorpicker = ColorPicker::new("my_colorpicker");
+///     my_colorpicker.set_color(palette.borrow().accent());
+///     my_colorpicker.add_swatch(Color::rgb(53, 132, 228));
+///     my_colorpicker.set_listener(Box::new(my_listener));
+/// }
+/// ```
+pub struct ColorPicker {
+    name: String,
+    state: ColorPickerState,
+    listener: Option<Box<dyn ColorPickerListener>>,
+}
+
+impl ColorPicker {
+    /// Create a ColorPicker
+    pub fn new(name: &str) -> Self {
+        let swatches = [
+            (0, 0, 0),
+            (128, 128, 128),
+            (192, 192, 192),
+            (255, 255, 255),
+            (128, 0, 0),
+            (255, 0, 0),
+            (255, 128, 0),
+            (255, 255, 0),
+            (0, 128, 0),
+            (0, 255, 0),
+            (0, 128, 128),
+            (0, 255, 255),
+            (0, 0, 128),
+            (0, 0, 255),
+            (128, 0, 128),
+            (255, 0, 255),
+        ];
+        Self {
+            name: name.to_string(),
+            state: ColorPickerState {
+                color: Color::rgb(255, 0, 0),
+                hue: 0.0,
+                saturation: 100.0,
+                lightness: 50.0,
+                swatches: swatches
+                    .iter()
+                    .map(|(red, green, blue)| Color::rgb(*red, *green, *blue))
+                    .collect(),
+                alpha: true,
+                request: 0,
+                disabled: false,
+                stretched: false,
+                style: "".to_string(),
+                tooltip: None,
+            },
+            listener: None,
+        }
+    }
+
+    /// Set the color
+    pub fn set_color(&mut self, color: Color) {
+        self.state.set_color(color);
+    }
+
+    /// Set the swatches
+    pub fn set_swatches(&mut self, swatches: Vec<Color>) {
+        self.state.set_swatches(swatches);
+    }
+
+    /// Add a swatch
+    pub fn add_swatch(&mut self, swatch: Color) {
+        self.state.swatches.push(swatch);
+    }
+
+    /// Set the alpha flag to false, hiding the alpha slider
+    pub fn set_opaque(&mut self) {
+        self.state.set_alpha(false);
+    }
+
+    /// Set the disabled flag to true
+    pub fn set_disabled(&mut self) {
+        self.state.set_disabled(true);
+    }
+
+    /// Set the stretched flag to true
+    pub fn set_stretched(&mut self) {
+        self.state.set_stretched(true);
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &ColorPickerState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut ColorPickerState {
+        &mut self.state
+    }
+
+    /// Set the listener
+    pub fn set_listener(&mut self, listener: Box<dyn ColorPickerListener>) {
+        self.listener = Some(listener);
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.state.set_style(style);
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
+
+    /// Return the HTML representation of the area and the sliders
+    fn eval_picker(&self) -> String {
+        let color = self.state.color();
+        let lightness = self.state.lightness();
+        let hues = (0..=6)
+            .map(|i| format!("hsl({}, 100%, {}%)", i * 60, lightness))
+            .collect::<Vec<String>>()
+            .join(", ");
+        let area = format!(
+            "background: linear-gradient(to bottom, \
+             hsla(0, 0%, {0}%, 0), hsl(0, 0%, {0}%)), \
+             linear-gradient(to right, {1});",
+            lightness, hues,
+        );
+        let pick = Event::change_js(&self.name, "'area;' + locate(event)");
+        let alpha = if self.state.alpha() {
+            format!(
+                r#"
+                <input class="colorpicker-alpha" type="range" min="0"
+                    max="100" value="{}" style="background: linear-gradient(
+                        to right, transparent, rgb({}, {}, {}));"
+                    oninput="{}" />
+                "#,
+                (color.alpha() * 100.0).round(),
+                color.red(),
+                color.green(),
+                color.blue(),
+                Event::change_js(&self.name, "'alpha;' + this.value"),
+            )
+        } else {
+            "".to_string()
+        };
+        format!(
+            r#"
+            <div class="colorpicker-picker">
+                <div class="colorpicker-area" style="{}"
+                    onmousedown="{}"
+                    onmousemove="if (event.buttons === 1) {{ {} }}"
+                >
+                    <div class="colorpicker-marker"
+                        style="left: {}%; top: {}%;"></div>
+                </div>
+                <div class="colorpicker-sliders">
+                    <input class="colorpicker-lightness" type="range"
+                        min="0" max="100" value="{}"
+                        style="background: linear-gradient(to right, black,
+                            hsl({}, {}%, 50%), white);"
+                        oninput="{}" />
+                    {}
+                </div>
+                <div class="colorpicker-preview">
+                    <div class="colorpicker-color"
+                        style="background: {};"></div>
+                </div>
+            </div>
+            "#,
+            area,
+            pick,
+            pick,
+            self.state.hue() / 3.6,
+            100.0 - self.state.saturation(),
+            lightness.round(),
+            self.state.hue(),
+            self.state.saturation(),
+            Event::change_js(&self.name, "'lightness;' + this.value"),
+            alpha,
+            color.rgb_string(),
+        )
+    }
+
+    /// Return the HTML representation of the text fields
+    fn eval_fields(&self) -> String {
+        let color = self.state.color();
+        let fields = [
+            ("Hex", "colorpicker-hex", color.hex()),
+            ("RGB", "colorpicker-rgb", color.rgb_string()),
+            ("HSL", "colorpicker-hsl", color.hsl_string()),
+        ];
+        let mut html = r#"<div class="colorpicker-fields">"#.to_string();
+        for (label, class, value) in fields.iter() {
+            html.push_str(&format!(
+                r#"
+                <label>{}</label>
+                <input class="{}" type="text" value="{}"
+                    data-value-request="{}" onchange="{}" />
+                "#,
+                label,
+                class,
+                value,
+                self.state.request,
+                Event::change_js(&self.name, "'input;' + this.value"),
+            ));
+        }
+        html.push_str("</div>");
+        html
+    }
+
+    /// Return the HTML representation of the swatches
+    fn eval_swatches(&self) -> String {
+        let mut html = r#"<div class="colorpicker-swatches">"#.to_string();
+        for (i, swatch) in self.state.swatches().iter().enumerate() {
+            let selected = if *swatch == self.state.color() {
+                "selected"
+            } else {
+                ""
+            };
+            html.push_str(&format!(
+                r#"
+                <div class="colorpicker-swatch {}" title="{}"
+                    style="background: {};" onclick="{}"></div>
+                "#,
+                selected,
+                swatch.hex(),
+                swatch.rgb_string(),
+                Event::change_js(&self.name, &format!("'swatch;{}'", i)),
+            ));
+        }
+        html.push_str("</div>");
+        html
+    }
+
+    /// Notify the listener
+    fn on_color(&mut self) {
+        if !self.state.alpha() {
+            self.state.set_opacity(1.0);
+        }
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_change(&self.state);
+            }
+        }
+    }
+}
+
+impl Widget for ColorPicker {
+    fn eval(&self) -> String {
+        let stretched = if self.state.stretched() {
+            "stretched"
+        } else {
+            ""
+        };
+        let disabled = if self.state.disabled() {
+            "disabled"
+        } else {
+            ""
+        };
+        let style = inline_style(&scss_to_css(&format!(
+            r##"#{}{{{}}}"##,
+            self.name,
+            self.state.style(),
+        )));
+        let html = format!(
+            r#"
+            <div id="{}" class="colorpicker {} {}">
+                {}
+                {}
+                {}
+            </div>
+            "#,
+            self.name,
+            disabled,
+            stretched,
+            self.eval_picker(),
+            self.eval_fields(),
+            self.eval_swatches(),
+        );
+        format!("{}{}", style, html)
+    }
+
+    fn trigger(&mut self, event: &Event) {
+        match event {
+            Event::Update => self.on_update(),
+            Event::Change { source, value } => {
+                if source == &self.name && !self.state.disabled() {
+                    self.on_change(value);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn on_update(&mut self) {
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_update(&mut self.state);
+            }
+        }
+    }
+
+    fn on_change(&mut self, value: &str) {
+        let values = value.split(';').collect::<Vec<&str>>();
+        match values.as_slice() {
+            ["area", x, y] => {
+                if let (Ok(x), Ok(y)) = (x.parse::<f64>(), y.parse::<f64>()) {
+                    let lightness = self.state.lightness();
+                    self.state.set_hsl(x * 360.0, (1.0 - y) * 100.0, lightness);
+                    self.on_color();
+                }
+            }
+            ["lightness", lightness] => {
+                if let Ok(lightness) = lightness.parse::<f64>() {
+                    let hue = self.state.hue();
+                    let saturation = self.state.saturation();
+                    self.state.set_hsl(hue, saturation, lightness);
+                    self.on_color();
+                }
+            }
+            ["alpha", alpha] => {
+                if let Ok(alpha) = alpha.parse::<f64>() {
+                    self.state.set_opacity(alpha / 100.0);
+                    self.on_color();
+                }
+            }
+            ["swatch", index] => {
+                let swatch = index
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|index| self.state.swatches().get(index))
+                    .cloned();
+                if let Some(swatch) = swatch {
+                    self.state.set_color(swatch);
+                    self.on_color();
+                }
+            }
+            ["input", text] => match Color::parse(text) {
+                Some(color) => {
+                    self.state.set_color(color);
+                    self.on_color();
+                }
+                None => self.state.request += 1,
+            },
+            _ => self.state.request += 1,
+        }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+}
@@ -1,26 +1,60 @@
-use crate::utils::event::Event;
+use lazy_static::lazy_static;
+use std::collections::HashSet;
+
+use crate::utils::event::{Event, Key};
+use crate::utils::html::escape;
+use crate::utils::icon::DefaultIcon;
 use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::svg::Svg;
+use crate::utils::tooltip::Tooltip;
+use crate::utils::validation::{Field, FieldValue, Validation, Validator};
 use crate::widgets::widget::Widget;
 
+lazy_static! {
+    /// The arrow of the button, drawn with the text colour
+    static ref ARROW: String =
+        Svg::from_icon(Box::new(DefaultIcon::Down)).eval("icon");
+}
+
 /// # The state of a Combo
 ///
 /// ## Fields
 ///
 /// ```text
 /// choices: Vec<String>
-/// selected: u32
+/// groups: Vec<(u32, String)>
+/// disabled_choices: HashSet<u32>
+/// selected: Option<u32>
+/// cursor: Option<u32>
+/// text: String
+/// text_request: u32
+/// filter: String
+/// placeholder: String
 /// opened: bool
+/// editable: bool
+/// validation: Validation
 /// disabled: bool
 /// stretched: bool
 /// style: String
+/// tooltip: Option<Tooltip>
 /// ```
 pub struct ComboState {
     choices: Vec<String>,
-    selected: u32,
+    groups: Vec<(u32, String)>,
+    disabled_choices: HashSet<u32>,
+    selected: Option<u32>,
+    cursor: Option<u32>,
+    text: String,
+    text_request: u32,
+    filter: String,
+    placeholder: String,
     opened: bool,
+    editable: bool,
+    validation: Validation,
     disabled: bool,
     stretched: bool,
     style: String,
+    tooltip: Option<Tooltip>,
 }
 
 impl ComboState {
@@ -29,16 +63,68 @@ impl ComboState {
         &self.choices
     }
 
-    /// Get the selected flag
+    /// Get the groups, made of the index of their first choice and a title
+    pub fn groups(&self) -> &Vec<(u32, String)> {
+        &self.groups
+    }
+
+    /// Get the indexes of the disabled choices
+    pub fn disabled_choices(&self) -> &HashSet<u32> {
+        &self.disabled_choices
+    }
+
+    /// Get the index of the selected choice, 0 if nothing is selected
     pub fn selected(&self) -> u32 {
+        self.selected.unwrap_or(0)
+    }
+
+    /// Get the index of the selected choice, None if nothing is selected
+    pub fn selection(&self) -> Option<u32> {
         self.selected
     }
 
+    /// Get the selected choice
+    pub fn selected_choice(&self) -> Option<&str> {
+        self.selected
+            .and_then(|selected| self.choices.get(selected as usize))
+            .map(String::as_ref)
+    }
+
+    /// Get the index of the choice highlighted by the keyboard
+    pub fn cursor(&self) -> Option<u32> {
+        self.cursor
+    }
+
+    /// Get the text of the editable mode
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Get the filter of the choices
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    /// Get the placeholder
+    pub fn placeholder(&self) -> &str {
+        &self.placeholder
+    }
+
     /// Get the opened flag
     pub fn opened(&self) -> bool {
         self.opened
     }
 
+    /// Get the editable flag
+    pub fn editable(&self) -> bool {
+        self.editable
+    }
+
+    /// Get the validation
+    pub fn validation(&self) -> &Validation {
+        &self.validation
+    }
+
     /// Get the disabled flag
     pub fn disabled(&self) -> bool {
         self.disabled
@@ -54,22 +140,93 @@ impl ComboState {
         &self.style
     }
 
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
     /// Set the choices
+    ///
+    /// The groups and the disabled choices are reset, and the selection is
+    /// cleared if it is out of range.
     pub fn set_choices(&mut self, choices: Vec<&str>) {
         self.choices = choices
             .iter()
             .map(|c| c.to_string())
             .collect::<Vec<String>>();
+        self.groups.clear();
+        self.disabled_choices.clear();
+        let len = self.choices.len();
+        self.selected = self.selected.filter(|s| (*s as usize) < len);
+        self.cursor = None;
+    }
+
+    /// Add a group of choices under a title
+    pub fn add_group(&mut self, title: &str, choices: Vec<&str>) {
+        self.groups
+            .push((self.choices.len() as u32, title.to_string()));
+        self.choices.extend(choices.iter().map(|c| c.to_string()));
+    }
+
+    /// Set the disabled flag of a choice
+    pub fn set_choice_disabled(&mut self, index: u32, disabled: bool) {
+        if disabled {
+            self.disabled_choices.insert(index);
+        } else {
+            self.disabled_choices.remove(&index);
+        }
     }
 
-    /// Set the selected flag
+    /// Set the index of the selected choice
+    ///
+    /// An index out of range or of a disabled choice is ignored. In editable
+    /// mode, the text becomes the selected choice.
     pub fn set_selected(&mut self, selected: u32) {
-        self.selected = selected;
+        if selected as usize >= self.choices.len()
+            || self.disabled_choices.contains(&selected)
+        {
+            return;
+        }
+        self.selected = Some(selected);
+        if self.editable {
+            if let Some(choice) = self.selected_choice() {
+                let choice = choice.to_string();
+                self.set_text(&choice);
+            }
+        }
+    }
+
+    /// Clear the selection
+    pub fn clear_selection(&mut self) {
+        self.selected = None;
+    }
+
+    /// Set the text of the editable mode
+    ///
+    /// A text different from the current one replaces the text typed in the
+    /// input, even if the input has the focus.
+    pub fn set_text(&mut self, text: &str) {
+        if self.text != text {
+            self.text = text.to_string();
+            self.text_request += 1;
+        }
+    }
+
+    /// Set the placeholder
+    pub fn set_placeholder(&mut self, placeholder: &str) {
+        self.placeholder = placeholder.to_string();
     }
 
     /// Set the opened flag
     pub fn set_opened(&mut self, opened: bool) {
         self.opened = opened;
+        self.filter.clear();
+        self.cursor = if opened { self.selected } else { None };
+    }
+
+    /// Set the editable flag
+    pub fn set_editable(&mut self, editable: bool) {
+        self.editable = editable;
     }
 
     /// Set the disabled flag
@@ -86,11 +243,63 @@ impl ComboState {
     pub fn set_style(&mut self, style: &str) {
         self.style = style.to_string();
     }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
+
+    /// Get the indexes of the choices matching the filter
+    pub fn visible(&self) -> Vec<u32> {
+        let filter = self.filter.to_lowercase();
+        self.choices
+            .iter()
+            .enumerate()
+            .filter(|(_, choice)| choice.to_lowercase().contains(&filter))
+            .map(|(index, _)| index as u32)
+            .collect()
+    }
+
+    /// Get the indexes of the choices matching the filter and not disabled
+    fn selectable(&self) -> Vec<u32> {
+        self.visible()
+            .into_iter()
+            .filter(|index| !self.disabled_choices.contains(index))
+            .collect()
+    }
+
+    /// Set the filter and move the cursor to the first matching choice
+    fn set_filter(&mut self, filter: &str) {
+        self.filter = filter.to_string();
+        self.cursor = self.selectable().first().cloned();
+    }
+
+    /// Move the cursor by the given number of selectable choices
+    fn move_cursor(&mut self, offset: i32) {
+        let selectable = self.selectable();
+        if selectable.is_empty() {
+            self.cursor = None;
+            return;
+        }
+        let position = self
+            .cursor
+            .and_then(|cursor| selectable.iter().position(|i| *i == cursor));
+        let position = match position {
+            Some(position) => (position as i32 + offset)
+                .max(0)
+                .min(selectable.len() as i32 - 1),
+            None if offset < 0 => selectable.len() as i32 - 1,
+            None => 0,
+        };
+        self.cursor = Some(selectable[position as usize]);
+    }
 }
 
 /// # The listener of a Combo
 pub trait ComboListener {
     /// Function triggered on change event
+    ///
+    /// The selected choice or the text of the editable mode has changed.
     fn on_change(&self, state: &ComboState);
 
     /// Function triggered on update event
@@ -99,6 +308,15 @@ pub trait ComboListener {
 
 /// # A collapsible list of strings
 ///
+/// The choices can be grouped under titles, and disabled. The opened list
+/// is navigated with the Up and Down keys, a choice is selected with the
+/// Enter key, and the list is closed with the Escape key or by clicking
+/// outside of it. Typing filters the opened list.
+///
+/// In editable mode, any text can be typed in the combo, and the choices
+/// are filtered by this text. Selecting a choice or setting the text from
+/// the code replaces the typed text, even if the input has the focus.
+///
 /// ## Fields
 ///
 /// ```text
@@ -113,22 +331,39 @@ pub trait ComboListener {
 /// name: name.to_string()
 /// state:
 ///     choices: vec!["Choice 1".to_string(), "Choice 2".to_string()],
-///     selected: 0,
+///     groups: vec![],
+///     disabled_choices: HashSet::new(),
+///     selected: None,
+///     cursor: None,
+///     text: "".to_string(),
+///     text_request: 0,
+///     filter: "".to_string(),
+///     placeholder: "".to_string(),
 ///     opened: false,
+///     editable: false,
+///     validation: Validation::new(),
 ///     disabled: false,
 ///     stretched: false,
 ///     style: "".to_string()
+///     tooltip: None
 /// listener: None
 /// ```
 ///
 /// ## Style
 ///
 /// ```text
-/// div.combo[.opened][.disabled]
+/// div.combo[.opened][.disabled][.editable][.invalid]
 ///     div.combo-button
+///         span[.placeholder]
+///         input.combo-input
 ///         div.combo-icon
+///             svg.icon
+///     div.combo-backdrop
 ///     div.combo-choices
-///         div.combo-choice
+///         div.combo-filter
+///         div.combo-group
+///         div.combo-choice[.selected][.cursor][.disabled][.last]
+///     div.validation-error
 /// ```
 ///
 /// ## Example
@@ -143,16 +378,16 @@ pub trait ComboListener {
 ///
 ///
 /// struct Dessert {
-///     index: u32,
+///     index: Option<u32>,
 ///     value: String,
 /// }
 ///
 /// impl Dessert {
 ///     fn new() -> Self {
-///         Self { index: 0, value: "Cake".to_string() }
+///         Self { index: None, value: "".to_string() }
 ///     }
 ///
-///     fn index(&self) -> u32 {
+///     fn index(&self) -> Option<u32> {
 ///         self.index
 ///     }
 ///
@@ -160,7 +395,7 @@ pub trait ComboListener {
 ///         &self.value
 ///     }
 ///
-///     fn set(&mut self, index: u32, value: &str) {
+///     fn set(&mut self, index: Option<u32>, value: &str) {
 ///         self.index = index;
 ///         self.value = value.to_string();
 ///     }
@@ -179,15 +414,17 @@ pub trait ComboListener {
 ///
 /// impl ComboListener for MyComboListener {
 ///     fn on_change(&self, state: &ComboState) {
-///         let index = state.selected();
 ///         self.dessert.borrow_mut().set(
-///             index,
-///             &state.choices()[index as usize]
+///             state.selection(),
+///             state.selected_choice().unwrap_or("")
 ///         );
 ///     }
 ///
 ///     fn on_update(&self, state: &mut ComboState) {
-///         state.set_selected(self.dessert.borrow().index());
+///         match self.dessert.borrow().index() {
+///             Some(index) => state.set_selected(index),
+///             None => state.clear_selection(),
+///         }
 ///     }
 /// }
 ///
@@ -199,6 +436,8 @@ pub trait ComboListener {
 ///
 ///     let mut my_combo = Combo::new("my_combo");
 ///     my_combo.set_choices(vec!["Cake", "Ice Cream", "Pie"]);
+///     my_combo.add_group("Fruits", vec!["Apple", "Banana"]);
+///     my_combo.set_placeholder("Choose a dessert");
 ///     my_combo.set_listener(Box::new(my_listener));
 /// }
 /// ```
@@ -215,31 +454,67 @@ impl Combo {
             name: name.to_string(),
             state: ComboState {
                 choices: vec!["Choice 1".to_string(), "Choice 2".to_string()],
-                selected: 0,
+                groups: vec![],
+                disabled_choices: HashSet::new(),
+                selected: None,
+                cursor: None,
+                text: "".to_string(),
+                text_request: 0,
+                filter: "".to_string(),
+                placeholder: "".to_string(),
                 opened: false,
+                editable: false,
+                validation: Validation::new(),
                 disabled: false,
                 stretched: false,
                 style: "".to_string(),
+                tooltip: None,
             },
             listener: None,
         }
     }
 
     /// Set the choices
+    ///
+    /// The groups and the disabled choices are reset, and the selection is
+    /// cleared if it is out of range.
     pub fn set_choices(&mut self, choices: Vec<&str>) {
         self.state.set_choices(choices);
     }
 
+    /// Add a group of choices under a title
+    pub fn add_group(&mut self, title: &str, choices: Vec<&str>) {
+        self.state.add_group(title, choices);
+    }
+
+    /// Disable the choice at the given index
+    pub fn disable_choice(&mut self, index: u32) {
+        self.state.set_choice_disabled(index, true);
+    }
+
     /// Set the index of the selected choice
+    ///
+    /// The choices have to be set first, as an index out of range or of a
+    /// disabled choice is ignored.
     pub fn set_selected(&mut self, selected: u32) {
         self.state.set_selected(selected);
     }
 
+    /// Set the placeholder
+    pub fn set_placeholder(&mut self, placeholder: &str) {
+        self.state.set_placeholder(placeholder);
+    }
+
     /// Set the opened flag to true
     pub fn set_opened(&mut self) {
         self.state.set_opened(true);
     }
 
+    /// Set the editable flag to true
+    pub fn set_editable(&mut self) {
+        self.state.set_editable(true);
+    }
+
     /// Set the disabled flag to true
     pub fn set_disabled(&mut self) {
         self.state.set_disabled(true);
@@ -250,6 +525,21 @@ impl Combo {
         self.state.set_stretched(true);
     }
 
+    /// Add a validator
+    pub fn add_validator(&mut self, validator: Validator) {
+        self.state.validation.add(validator);
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &ComboState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut ComboState {
+        &mut self.state
+    }
+
     /// Set the listener
     pub fn set_listener(&mut self, listener: Box<dyn ComboListener>) {
         self.listener = Some(listener);
@@ -259,6 +549,127 @@ impl Combo {
     pub fn set_style(&mut self, style: &str) {
         self.state.set_style(style);
     }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
+
+    /// Function triggered on keypress event
+    fn on_keys(&mut self, keys: &HashSet<Key>) {
+        if keys.contains(&Key::Escape) {
+            self.state.set_opened(false);
+        } else if keys.contains(&Key::Down) || keys.contains(&Key::Up) {
+            let offset = if keys.contains(&Key::Down) { 1 } else { -1 };
+            if self.state.opened() {
+                self.state.move_cursor(offset);
+            } else {
+                self.state.set_opened(true);
+                if self.state.cursor.is_none() {
+                    self.state.move_cursor(offset);
+                }
+            }
+        } else if keys.contains(&Key::Enter) {
+            match self.state.cursor {
+                Some(cursor) if self.state.opened() => self.on_select(cursor),
+                _ => self.state.set_opened(!self.state.opened()),
+            }
+        }
+    }
+
+    /// Select a choice and close the list
+    fn on_select(&mut self, index: u32) {
+        if index as usize >= self.state.choices.len()
+            || self.state.disabled_choices.contains(&index)
+        {
+            return;
+        }
+        self.state.set_selected(index);
+        self.state.set_opened(false);
+        self.on_value();
+    }
+
+    /// Validate the new value and notify the listener
+    fn on_value(&mut self) {
+        self.validate();
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_change(&self.state);
+            }
+        }
+    }
+
+    /// Return the HTML representation of the opened list
+    fn eval_choices(&self) -> String {
+        let mut html = format!(
+            r#"
+            <div class="combo-backdrop" onclick="{}"></div>
+            <div class="combo-choices">
+            "#,
+            Event::change_js(&self.name, "'close'"),
+        );
+        if !self.state.editable() && !self.state.filter().is_empty() {
+            html.push_str(&format!(
+                r#"<div class="combo-filter">{}</div>"#,
+                self.state.filter()
+            ));
+        }
+        let visible = self.state.visible();
+        let mut group = None;
+        for (i, index) in visible.iter().enumerate() {
+            let current = self
+                .state
+                .groups()
+                .iter()
+                .rposition(|(first, _)| first <= index);
+            if current.is_some() && current != group {
+                html.push_str(&format!(
+                    r#"<div class="combo-group">{}</div>"#,
+                    self.state.groups()[current.unwrap_or(0)].1
+                ));
+            }
+            group = current;
+            let selected = if self.state.selection() == Some(*index) {
+                "selected"
+            } else {
+                ""
+            };
+            let cursor = if self.state.cursor() == Some(*index) {
+                "cursor"
+            } else {
+                ""
+            };
+            let last = if i == visible.len() - 1 { "last" } else { "" };
+            let (disabled, onclick) =
+                if self.state.disabled_choices().contains(index) {
+                    ("disabled", "".to_string())
+                } else {
+                    (
+                        "",
+                        Event::change_js(
+                            &self.name,
+                            &format!("'select;{}'", index),
+                        ),
+                    )
+                };
+            html.push_str(&format!(
+                r#"
+                <div class="combo-choice {} {} {} {}" onclick="{}">
+                    {}
+                </div>
+                "#,
+                selected,
+                cursor,
+                disabled,
+                last,
+                onclick,
+                self.state.choices()[*index as usize],
+            ));
+        }
+        html.push_str(r#"</div>"#);
+        html
+    }
 }
 
 impl Widget for Combo {
@@ -274,44 +685,80 @@ impl Widget for Combo {
             ""
         };
         let opened = if self.state.opened() { "opened" } else { "" };
+        let editable = if self.state.editable() {
+            "editable"
+        } else {
+            ""
+        };
         let style = inline_style(&scss_to_css(&format!(
             r##"#{}{{{}}}"##,
             self.name,
             self.state.style(),
         )));
-        let mut html = format!(
-            r#"
-            <div id="{}" class="combo {} {} {}">
+        let toggle = Event::change_js(&self.name, "'toggle'");
+        let arrow = ARROW.as_str();
+        let button = if self.state.editable() {
+            format!(
+                r#"
+                <div class="combo-button">
+                    <input {} class="combo-input" type="text" value="{}"
+                        placeholder="{}" data-value-request="{}"
+                        oninput="{}" />
+                    <div class="combo-icon" onclick="{}">{}</div>
+                </div>
+                "#,
+                disabled,
+                escape(self.state.text()),
+                escape(self.state.placeholder()),
+                self.state.text_request,
+                Event::change_js(&self.name, "'input;' + this.value"),
+                toggle,
+                arrow,
+            )
+        } else {
+            let (placeholder, text) = match self.state.selected_choice() {
+                Some(choice) => ("", choice),
+                None => ("placeholder", self.state.placeholder()),
+            };
+            format!(
+                r#"
                 <div onclick="{}" class="combo-button">
-                    {}
-                    <div class="combo-icon"></div>
+                    <span class="{}">{}</span>
+                    <div class="combo-icon">{}</div>
                 </div>
+                "#,
+                toggle,
+                placeholder,
+                escape(text),
+                arrow,
+            )
+        };
+        let keydown = format!(
+            "if (event.target === this && typeahead(event)) {{ {} }} \
+             else if (choose(event)) {{ {} }}",
+            Event::change_js(&self.name, "typeahead(event)"),
+            Event::keypress_js(&self.name, "down"),
+        );
+        let mut html = format!(
+            r#"
+            <div id="{}" class="combo {} {} {} {} {}" tabindex="0"
+                onkeydown="{}"
+            >
+                {}
             "#,
             self.name,
             stretched,
             opened,
             disabled,
-            Event::change_js(&self.name, "'-1'"),
-            self.state.choices()[self.state.selected() as usize],
+            editable,
+            self.state.validation().css(),
+            keydown,
+            button,
         );
         if self.state.opened() {
-            html.push_str(r#"<div class="combo-choices">"#);
-            let combos_length = self.state.choices().len();
-            for (i, choice) in self.state.choices().iter().enumerate() {
-                let last = if i == combos_length - 1 { "last" } else { "" };
-                html.push_str(&format!(
-                    r#"
-                    <div class="combo-choice {}" onclick="{}">
-                        {}
-                    </div>
-                    "#,
-                    last,
-                    Event::change_js(&self.name, &format!("'{}'", i)),
-                    choice
-                ));
-            }
-            html.push_str(r#"</div>"#);
+            html.push_str(&self.eval_choices());
         }
+        html.push_str(&self.state.validation().eval());
         html.push_str("</div>");
         format!("{}{}", style, html)
     }
@@ -322,11 +769,18 @@ impl Widget for Combo {
             Event::Change { source, value } => {
                 if source == &self.name && !self.state.disabled() {
                     self.on_change(value);
-                } else {
+                } else if self.state.opened() {
+                    self.state.set_opened(false);
+                }
+            }
+            Event::Keypress { source, keys } => {
+                if source == &self.name && !self.state.disabled() {
+                    self.on_keys(keys);
+                } else if keys.contains(&Key::Escape) && self.state.opened() {
                     self.state.set_opened(false);
                 }
             }
-            _ => self.state.set_opened(false),
+            _ => (),
         }
     }
 
@@ -340,16 +794,129 @@ impl Widget for Combo {
     }
 
     fn on_change(&mut self, value: &str) {
-        self.state.set_opened(!self.state.opened());
-        let selected = value.parse::<i32>().unwrap();
-        if selected > -1 {
-            self.state.set_selected(selected as u32);
-        }
-        match &self.listener {
-            None => (),
-            Some(listener) => {
-                listener.on_change(&self.state);
+        let values = value.splitn(2, ';').collect::<Vec<&str>>();
+        match values.as_slice() {
+            ["toggle"] => self.state.set_opened(!self.state.opened()),
+            ["close"] => self.state.set_opened(false),
+            ["select", index] => {
+                if let Ok(index) = index.parse::<u32>() {
+                    self.on_select(index);
+                }
+            }
+            ["type", text] => {
+                if !self.state.opened() {
+                    self.state.set_opened(true);
+                }
+                let filter = format!("{}{}", self.state.filter(), text);
+                self.state.set_filter(&filter);
+            }
+            ["back"] => {
+                let mut filter = self.state.filter().to_string();
+                filter.pop();
+                self.state.set_filter(&filter);
+            }
+            ["input", text] => {
+                self.state.text = text.to_string();
+                self.state.selected = self
+                    .state
+                    .choices()
+                    .iter()
+                    .position(|choice| choice == text)
+                    .map(|index| index as u32);
+                self.state.opened = true;
+                self.state.set_filter(text);
+                self.on_value();
             }
+            _ => (),
+        }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+
+    fn field(&self) -> Option<&dyn Field> {
+        Some(self)
+    }
+
+    fn field_mut(&mut self) -> Option<&mut dyn Field> {
+        Some(self)
+    }
+}
+
+impl Field for Combo {
+    fn value(&self) -> FieldValue {
+        if self.state.editable() {
+            FieldValue::Text(self.state.text().to_string())
+        } else {
+            FieldValue::Text(
+                self.state.selected_choice().unwrap_or_default().to_string(),
+            )
         }
     }
+
+    fn validation(&self) -> &Validation {
+        self.state.validation()
+    }
+
+    fn validate(&mut self) -> bool {
+        let value = self.value();
+        self.state.validation.validate(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_selected_ignores_invalid_indexes() {
+        let mut combo = Combo::new("combo");
+        combo.set_choices(vec!["Cake", "Pie"]);
+        combo.add_group("Fruits", vec!["Apple"]);
+        combo.disable_choice(1);
+        combo.set_selected(3);
+        assert_eq!(combo.state().selection(), None);
+        combo.set_selected(1);
+        assert_eq!(combo.state().selection(), None);
+        combo.set_selected(2);
+        assert_eq!(combo.state().selected_choice(), Some("Apple"));
+    }
+
+    #[test]
+    fn set_choices_resets_groups_and_disabled_choices() {
+        let mut combo = Combo::new("combo");
+        combo.set_choices(vec!["Cake", "Pie"]);
+        combo.add_group("Fruits", vec!["Apple"]);
+        combo.disable_choice(0);
+        combo.set_selected(2);
+        combo.set_choices(vec!["Tea"]);
+        assert!(combo.state().groups().is_empty());
+        assert!(combo.state().disabled_choices().is_empty());
+        assert_eq!(combo.state().selection(), None);
+        assert_eq!(combo.state().selected(), 0);
+    }
+
+    #[test]
+    fn set_text_requests_a_render_of_the_text() {
+        let mut combo = Combo::new("combo");
+        combo.set_editable();
+        combo.on_change("input;Ca");
+        assert_eq!(combo.state().text(), "Ca");
+        assert_eq!(combo.state.text_request, 0);
+        combo.set_selected(1);
+        assert_eq!(combo.state().text(), "Choice 2");
+        assert_eq!(combo.state.text_request, 1);
+        combo.state.set_text("Choice 2");
+        assert_eq!(combo.state.text_request, 1);
+        combo.state.set_text("Pie \"&\" cake");
+        assert_eq!(combo.state.text_request, 2);
+        assert!(combo
+            .eval()
+            .contains(r#"value="Pie &quot;&amp;&quot; cake""#));
+    }
 }
@@ -1,6 +1,7 @@
 use crate::utils::event::Event;
 use crate::utils::style::{inline_style, scss_to_css};
-use crate::widgets::widget::Widget;
+use crate::utils::tooltip::Tooltip;
+use crate::widgets::widget::{find_as, find_as_mut, Widget};
 
 /// # The state of a Container
 ///
@@ -12,6 +13,7 @@ use crate::widgets::widget::Widget;
 /// position: Position
 /// alignment: Alignment
 /// style: String
+/// tooltip: Option<Tooltip>
 /// ```
 pub struct ContainerState {
     children: Vec<Box<dyn Widget>>,
@@ -20,6 +22,7 @@ pub struct ContainerState {
     alignment: Alignment,
     stretched: bool,
     style: String,
+    tooltip: Option<Tooltip>,
 }
 
 impl ContainerState {
@@ -53,6 +56,11 @@ impl ContainerState {
         &self.style
     }
 
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
     /// Set the children
     pub fn set_children(&mut self, children: Vec<Box<dyn Widget>>) {
         self.children = children;
@@ -83,10 +91,65 @@ impl ContainerState {
         self.style = style.to_string();
     }
 
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
+
     /// Add a child
-    fn add(&mut self, child: Box<dyn Widget>) {
+    pub fn add(&mut self, child: Box<dyn Widget>) {
         self.children.push(child);
     }
+
+    /// Insert a child at the given index
+    pub fn insert(&mut self, index: usize, child: Box<dyn Widget>) {
+        let index = index.min(self.children.len());
+        self.children.insert(index, child);
+    }
+
+    /// Remove the child having the given name
+    pub fn remove(&mut self, name: &str) -> Option<Box<dyn Widget>> {
+        match self.index(name) {
+            Some(index) => Some(self.children.remove(index)),
+            None => None,
+        }
+    }
+
+    /// Move the child having the given name to the given index
+    pub fn move_to(&mut self, name: &str, index: usize) {
+        if let Some(child) = self.remove(name) {
+            self.insert(index, child);
+        }
+    }
+
+    /// Get the index of the child having the given name
+    pub fn index(&self, name: &str) -> Option<usize> {
+        self.children.iter().position(|child| child.name() == name)
+    }
+
+    /// Set the children from a list of items
+    ///
+    /// Each item is identified by a key which is used as the name of its
+    /// widget. The children whose name matches a key are kept with their
+    /// state and listener, the missing ones are created by `build` from the
+    /// key and the item, and the children whose name matches no key are
+    /// removed. The children are ordered as the items.
+    pub fn set_children_from<T, K, B>(&mut self, items: &[T], key: K, build: B)
+    where
+        K: Fn(&T) -> String,
+        B: Fn(&str, &T) -> Box<dyn Widget>,
+    {
+        let mut children = vec![];
+        for item in items {
+            let name = key(item);
+            let child = match self.remove(&name) {
+                Some(child) => child,
+                None => build(&name, item),
+            };
+            children.push(child);
+        }
+        self.children = children;
+    }
 }
 
 /// # The listener of a Container
@@ -116,6 +179,7 @@ pub trait ContainerListener {
 ///     alignment: Alignment::None
 ///     stretched: false
 ///     style: "".to_string()
+///     tooltip: None
 /// listener: None
 /// ```
 ///
@@ -169,15 +233,16 @@ pub trait ContainerListener {
 ///
 /// impl ContainerListener for MyContainerListener {
 ///     fn on_update(&self, state: &mut ContainerState) {
-///         let labels = self.quotes.borrow().values().iter().enumerate().map(
-///         |(i, q)| {
-///             let name = format!("quote-{}", i);
-///             let mut w = Label::new(&name);
-///             w.set_text(q);
-///             let b: Box<dyn Widget> = Box::new(w);
-///             b
-///         }).collect::<Vec<Box<dyn Widget>>>();
-///         state.set_children(labels);
+///         state.set_children_from(
+///             self.quotes.borrow().values(),
+///             |q| format!("quote-{}", q.to_lowercase().replace(" ", "-")),
+///             |name, q| {
+///                 let mut w = Label::new(name);
+///                 w.set_text(q);
+///                 let b: Box<dyn Widget> = Box::new(w);
+///                 b
+///             },
+///         );
 ///     }
 /// }
 ///
@@ -209,6 +274,7 @@ impl Container {
                 alignment: Alignment::None,
                 stretched: false,
                 style: "".to_string(),
+                tooltip: None,
             },
             listener: None,
         }
@@ -235,6 +301,16 @@ impl Container {
         self.state.set_stretched(true);
     }
 
+    /// Get the state
+    pub fn state(&self) -> &ContainerState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut ContainerState {
+        &mut self.state
+    }
+
     /// Set the listener
     pub fn set_listener(&mut self, listener: Box<dyn ContainerListener>) {
         self.listener.replace(listener);
@@ -245,10 +321,29 @@ impl Container {
         self.state.set_style(style);
     }
 
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
+
     /// Add a widget
     pub fn add(&mut self, widget: Box<dyn Widget>) {
         self.state.add(widget);
     }
+
+    /// Find a widget by name in the container and downcast it to the type T
+    pub fn find<T: Widget + 'static>(&self, name: &str) -> Option<&T> {
+        find_as(self, name)
+    }
+
+    /// Find a mutable widget by name in the container and downcast it to the
+    /// type T
+    pub fn find_mut<T: Widget + 'static>(
+        &mut self,
+        name: &str,
+    ) -> Option<&mut T> {
+        find_as_mut(self, name)
+    }
 }
 
 impl Widget for Container {
@@ -303,6 +398,30 @@ impl Widget for Container {
     }
 
     fn on_change(&mut self, _value: &str) {}
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+
+    fn children(&self) -> Vec<&dyn Widget> {
+        self.state
+            .children
+            .iter()
+            .map(|child| child.as_ref())
+            .collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Widget> {
+        self.state
+            .children
+            .iter_mut()
+            .map(|child| child.as_mut() as &mut dyn Widget)
+            .collect()
+    }
 }
 
 /// # The direction of a Container
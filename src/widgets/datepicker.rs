@@ -0,0 +1,571 @@
+use std::collections::HashSet;
+
+use crate::utils::datetime::{Date, Weekday};
+use crate::utils::event::{Event, Key};
+use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
+use crate::utils::validation::{Field, FieldValue, Validation, Validator};
+use crate::widgets::calendar::{eval_month, CalendarState};
+use crate::widgets::widget::Widget;
+
+/// # The state of a DatePicker
+///
+/// ## Fields
+///
+/// ```text
+/// calendar: CalendarState
+/// placeholder: String
+/// opened: bool
+/// request: u32
+/// validation: Validation
+/// disabled: bool
+/// stretched: bool
+/// style: String
+/// tooltip: Option<Tooltip>
+/// ```
+pub struct DatePickerState {
+    calendar: CalendarState,
+    placeholder: String,
+    opened: bool,
+    request: u32,
+    validation: Validation,
+    disabled: bool,
+    stretched: bool,
+    style: String,
+    tooltip: Option<Tooltip>,
+}
+
+impl DatePickerState {
+    /// Get the state of the calendar, holding the value, the bounds and the
+    /// disabled days
+    pub fn calendar(&self) -> &CalendarState {
+        &self.calendar
+    }
+
+    /// Get the mutable state of the calendar
+    pub fn calendar_mut(&mut self) -> &mut CalendarState {
+        &mut self.calendar
+    }
+
+    /// Get the selected date
+    pub fn value(&self) -> Option<Date> {
+        self.calendar.value()
+    }
+
+    /// Get the text displayed
+    pub fn text(&self) -> String {
+        self.value()
+            .map(|date| date.to_string())
+            .unwrap_or_default()
+    }
+
+    /// Get the placeholder
+    pub fn placeholder(&self) -> &str {
+        &self.placeholder
+    }
+
+    /// Get the opened flag
+    pub fn opened(&self) -> bool {
+        self.opened
+    }
+
+    /// Get the validation
+    pub fn validation(&self) -> &Validation {
+        &self.validation
+    }
+
+    /// Get the disabled flag
+    pub fn disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Get the stretched flag
+    pub fn stretched(&self) -> bool {
+        self.stretched
+    }
+
+    /// Get the style
+    pub fn style(&self) -> &str {
+        &self.style
+    }
+
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
+    /// Set the selected date
+    pub fn set_value(&mut self, value: Option<Date>) {
+        if value != self.value() {
+            self.request += 1;
+        }
+        self.calendar.set_value(value);
+    }
+
+    /// Set the placeholder
+    pub fn set_placeholder(&mut self, placeholder: &str) {
+        self.placeholder = placeholder.to_string();
+    }
+
+    /// Set the opened flag, the calendar showing the selected date when it
+    /// is opened
+    pub fn set_opened(&mut self, opened: bool) {
+        self.opened = opened;
+        if opened {
+            let cursor = self.value().unwrap_or_else(Date::today);
+            self.calendar.set_cursor(cursor);
+        }
+    }
+
+    /// Set the disabled flag
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+
+    /// Set the stretched flag
+    pub fn set_stretched(&mut self, stretched: bool) {
+        self.stretched = stretched;
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.style = style.to_string();
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
+
+    /// Parse a text typed by the user, an empty text clearing the date
+    ///
+    /// Return None if the text is not a date in the `YYYY-MM-DD` format or
+    /// if the date cannot be selected.
+    pub fn parse(&self, text: &str) -> Option<Option<Date>> {
+        if text.trim().is_empty() {
+            return Some(None);
+        }
+        match Date::parse(text) {
+            Some(date) if self.calendar.enabled(date) => Some(Some(date)),
+            _ => None,
+        }
+    }
+}
+
+/// # The listener of a DatePicker
+pub trait DatePickerListener {
+    /// Function triggered on update event
+    fn on_update(&self, state: &mut DatePickerState);
+
+    /// Function triggered on change event
+    ///
+    /// The texts which are not dates that can be selected are rejected and
+    /// do not trigger this function.
+    fn on_change(&self, state: &DatePickerState);
+}
+
+/// # A field holding a date, with a calendar to pick it
+///
+/// The date can be typed in the `YYYY-MM-DD` format or picked in the
+/// calendar opened with the button or the Down key. In the opened calendar,
+/// the arrows move the cursor by a day or a week, PageUp and PageDown by a
+/// month, Home and End to the bounds of the week, Enter selects the day
+/// under the cursor and Escape closes the calendar.
+///
+/// ## Fields
+///
+/// ```text
+/// name: String
+/// state: DatePickerState
+/// listener: Option<Box<dyn DatePickerListener>>
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// name: name.to_string()
+/// state:
+///     calendar: the default state of a Calendar
+///     placeholder: "YYYY-MM-DD".to_string()
+///     opened: false
+///     request: 0
+///     validation: Validation::new()
+///     disabled: false
+///     stretched: false
+///     style: "".to_string()
+///     tooltip: None
+/// listener: None
+/// ```
+///
+/// ## Style
+///
+/// ```text
+/// div.datepicker[.opened][.disabled][.invalid]
+///     div.datepicker-field
+///         input
+///         div.datepicker-icon
+///     div.datepicker-backdrop
+///     div.calendar.datepicker-calendar
+///         div.calendar-header
+///             div.calendar-previous
+///             div.calendar-title
+///             div.calendar-next
+///         div.calendar-days
+///             div.calendar-weekday
+///             div.calendar-day[.outside][.today][.selected][.cursor]
+///                 [.disabled]
+///     div.validation-error
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use neutrino::utils::datetime::{Date, Weekday};
+/// use neutrino::widgets::datepicker::{
+///     DatePicker,
+///     DatePickerListener,
+///     DatePickerState
+/// };
+///
+///
+/// struct Booking {
+///     arrival: Option<Date>,
+/// }
+///
+/// impl Booking {
+///     fn new() -> Self {
+///         Self { arrival: None }
+///     }
+///
+///     fn arrival(&self) -> Option<Date> {
+///         self.arrival
+///     }
+///
+///     fn set_arrival(&mut self, arrival: Option<Date>) {
+///         self.arrival = arrival;
+///     }
+/// }
+///
+///
+/// struct MyDatePickerListener {
+///     booking: Rc<RefCell<Booking>>,
+/// }
+///
+/// impl MyDatePickerListener {
+///    pub fn new(booking: Rc<RefCell<Booking>>) -> Self {
+///        Self { booking }
+///    }
+/// }
+///
+/// impl DatePickerListener for MyDatePickerListener {
+///     fn on_change(&self, state: &DatePickerState) {
+///         self.booking.borrow_mut().set_arrival(state.value());
+///     }
+///
+///     fn on_update(&self, state: &mut DatePickerState) {
+///         state.set_value(self.booking.borrow().arrival());
+///     }
+/// }
+///
+///
+/// fn main() {
+///     let booking = Rc::new(RefCell::new(Booking::new()));
+///
+///     let my_listener = MyDatePickerListener::new(Rc::clone(&booking));
+///
+///     let mut my_datepicker = DatePicker::new("my_datepicker");
+///     my_datepicker.set_range(
+///         Date::new(2020, 1, 1).unwrap(),
+///         Date::new(2020, 12, 31).unwrap(),
+///     );
+///     my_datepicker.disable_day(Date::new(2020, 12, 25).unwrap());
+///     my_datepicker.set_week_start(Weekday::Sunday);
+///     my_datepicker.set_listener(Box::new(my_listener));
+/// }
+/// ```
+pub struct DatePicker {
+    name: String,
+    state: DatePickerState,
+    listener: Option<Box<dyn DatePickerListener>>,
+}
+
+impl DatePicker {
+    /// Create a DatePicker
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            state: DatePickerState {
+                calendar: CalendarState::new(),
+                placeholder: "YYYY-MM-DD".to_string(),
+                opened: false,
+                request: 0,
+                validation: Validation::new(),
+                disabled: false,
+                stretched: false,
+                style: "".to_string(),
+                tooltip: None,
+            },
+            listener: None,
+        }
+    }
+
+    /// Set the selected date
+    pub fn set_value(&mut self, value: Date) {
+        self.state.set_value(Some(value));
+    }
+
+    /// Set the min and the max
+    pub fn set_range(&mut self, min: Date, max: Date) {
+        self.state.calendar.set_min(Some(min));
+        self.state.calendar.set_max(Some(max));
+    }
+
+    /// Disable a day
+    pub fn disable_day(&mut self, day: Date) {
+        self.state.calendar.disable_day(day);
+    }
+
+    /// Disable a day of the week
+    pub fn disable_weekday(&mut self, weekday: Weekday) {
+        self.state.calendar.disable_weekday(weekday);
+    }
+
+    /// Set the first day of the week
+    pub fn set_week_start(&mut self, week_start: Weekday) {
+        self.state.calendar.set_week_start(week_start);
+    }
+
+    /// Set the placeholder
+    pub fn set_placeholder(&mut self, placeholder: &str) {
+        self.state.set_placeholder(placeholder);
+    }
+
+    /// Set the disabled flag to true
+    pub fn set_disabled(&mut self) {
+        self.state.set_disabled(true);
+    }
+
+    /// Set the stretched flag to true
+    pub fn set_stretched(&mut self) {
+        self.state.set_stretched(true);
+    }
+
+    /// Add a validator
+    pub fn add_validator(&mut self, validator: Validator) {
+        self.state.validation.add(validator);
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &DatePickerState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut DatePickerState {
+        &mut self.state
+    }
+
+    /// Set the listener
+    pub fn set_listener(&mut self, listener: Box<dyn DatePickerListener>) {
+        self.listener = Some(listener);
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.state.set_style(style);
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
+
+    /// Function triggered on keypress event
+    fn on_keys(&mut self, keys: &HashSet<Key>) {
+        if keys.contains(&Key::Escape) {
+            self.state.set_opened(false);
+        } else if !self.state.opened() {
+            if keys.contains(&Key::Down) {
+                self.state.set_opened(true);
+            }
+        } else if keys.contains(&Key::Enter) {
+            self.on_select(self.state.calendar.cursor());
+        } else {
+            self.state.calendar.move_cursor(keys);
+        }
+    }
+
+    /// Select a date and close the calendar
+    fn on_select(&mut self, date: Date) {
+        if !self.state.calendar.enabled(date) {
+            return;
+        }
+        self.state.set_value(Some(date));
+        self.state.set_opened(false);
+        self.on_value();
+    }
+
+    /// Validate the new value and notify the listener
+    fn on_value(&mut self) {
+        self.validate();
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_change(&self.state);
+            }
+        }
+    }
+}
+
+impl Widget for DatePicker {
+    fn eval(&self) -> String {
+        let stretched = if self.state.stretched() {
+            "stretched"
+        } else {
+            ""
+        };
+        let disabled = if self.state.disabled() {
+            "disabled"
+        } else {
+            ""
+        };
+        let opened = if self.state.opened() { "opened" } else { "" };
+        let style = inline_style(&scss_to_css(&format!(
+            r##"#{}{{{}}}"##,
+            self.name,
+            self.state.style(),
+        )));
+        let keydown = format!(
+            "if (pick(event, {})) {{ {} }}",
+            self.state.opened(),
+            Event::keypress_js(&self.name, "down"),
+        );
+        let mut html = format!(
+            r#"
+            <div id="{}" class="datepicker {} {} {} {}">
+                <div class="datepicker-field">
+                    <input {} type="text" value="{}" placeholder="{}"
+                        data-value-request="{}" onchange="{}" onblur="{}"
+                        onkeydown="{}" />
+                    <div class="datepicker-icon" onclick="{}">&#9660;</div>
+                </div>
+            "#,
+            self.name,
+            opened,
+            disabled,
+            stretched,
+            self.state.validation().css(),
+            disabled,
+            self.state.text(),
+            self.state.placeholder(),
+            self.state.request,
+            Event::change_js(&self.name, "'input;' + this.value"),
+            Event::change_js(&self.name, "'blur'"),
+            keydown,
+            Event::change_js(&self.name, "'toggle'"),
+        );
+        if self.state.opened() {
+            html.push_str(&format!(
+                r#"
+                <div class="datepicker-backdrop" onclick="{}"></div>
+                <div class="calendar datepicker-calendar">{}</div>
+                "#,
+                Event::change_js(&self.name, "'close'"),
+                eval_month(&self.name, &self.state.calendar, true),
+            ));
+        }
+        html.push_str(&self.state.validation().eval());
+        html.push_str("</div>");
+        format!("{}{}", style, html)
+    }
+
+    fn trigger(&mut self, event: &Event) {
+        match event {
+            Event::Update => self.on_update(),
+            Event::Change { source, value } => {
+                if source == &self.name && !self.state.disabled() {
+                    self.on_change(value);
+                } else if self.state.opened() {
+                    self.state.set_opened(false);
+                }
+            }
+            Event::Keypress { source, keys } => {
+                if source == &self.name && !self.state.disabled() {
+                    self.on_keys(keys);
+                } else if keys.contains(&Key::Escape) && self.state.opened() {
+                    self.state.set_opened(false);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn on_update(&mut self) {
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_update(&mut self.state);
+            }
+        }
+    }
+
+    fn on_change(&mut self, value: &str) {
+        let values = value.splitn(2, ';').collect::<Vec<&str>>();
+        match values.as_slice() {
+            ["toggle"] => self.state.set_opened(!self.state.opened()),
+            ["close"] => self.state.set_opened(false),
+            ["month", offset] => {
+                if let Ok(offset) = offset.parse::<i32>() {
+                    let month = self.state.calendar.month().add_months(offset);
+                    self.state.calendar.set_month(month);
+                }
+            }
+            ["select", date] => {
+                if let Some(date) = Date::parse(date) {
+                    self.on_select(date);
+                }
+            }
+            ["input", text] => {
+                if let Some(value) = self.state.parse(text) {
+                    self.state.set_value(value);
+                    self.on_value();
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+
+    fn field(&self) -> Option<&dyn Field> {
+        Some(self)
+    }
+
+    fn field_mut(&mut self) -> Option<&mut dyn Field> {
+        Some(self)
+    }
+}
+
+impl Field for DatePicker {
+    fn value(&self) -> FieldValue {
+        FieldValue::Text(self.state.text())
+    }
+
+    fn validation(&self) -> &Validation {
+        self.state.validation()
+    }
+
+    fn validate(&mut self) -> bool {
+        let value = self.value();
+        self.state.validation.validate(&value)
+    }
+}
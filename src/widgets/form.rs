@@ -0,0 +1,414 @@
+use std::collections::HashMap;
+
+use crate::utils::event::Event;
+use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
+use crate::utils::validation::FieldValue;
+use crate::widgets::widget::{find, find_as, find_as_mut, Widget};
+
+/// # The state of a Form
+///
+/// ## Fields
+///
+/// ```text
+/// child: Option<Box<dyn Widget>>
+/// valid: bool
+/// values: HashMap<String, FieldValue>
+/// stretched: bool
+/// style: String
+/// tooltip: Option<Tooltip>
+/// ```
+pub struct FormState {
+    child: Option<Box<dyn Widget>>,
+    valid: bool,
+    values: HashMap<String, FieldValue>,
+    stretched: bool,
+    style: String,
+    tooltip: Option<Tooltip>,
+}
+
+impl FormState {
+    /// Get the child
+    pub fn child(&self) -> Option<&dyn Widget> {
+        self.child.as_ref().map(|child| child.as_ref())
+    }
+
+    /// Get the valid flag
+    pub fn valid(&self) -> bool {
+        self.valid
+    }
+
+    /// Get the values of the fields by name
+    pub fn values(&self) -> &HashMap<String, FieldValue> {
+        &self.values
+    }
+
+    /// Get the value of the field having the given name
+    pub fn value(&self, name: &str) -> Option<&FieldValue> {
+        self.values.get(name)
+    }
+
+    /// Get the stretched flag
+    pub fn stretched(&self) -> bool {
+        self.stretched
+    }
+
+    /// Get the style
+    pub fn style(&self) -> &str {
+        &self.style
+    }
+
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
+    /// Set the child
+    pub fn set_child(&mut self, child: Box<dyn Widget>) {
+        self.child = Some(child);
+        self.collect();
+    }
+
+    /// Set the stretched flag
+    pub fn set_stretched(&mut self, stretched: bool) {
+        self.stretched = stretched;
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.style = style.to_string();
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
+
+    /// Check every field and display the errors found, return true if all
+    /// the values are valid
+    pub fn validate(&mut self) -> bool {
+        if let Some(child) = &mut self.child {
+            validate_fields(child.as_mut());
+        }
+        self.collect();
+        self.valid
+    }
+
+    /// Deserialize the values into a struct whose fields are named after
+    /// the fields of the form
+    #[cfg(feature = "serde")]
+    pub fn deserialize<T: serde::de::DeserializeOwned>(
+        &self,
+    ) -> Result<T, serde::de::value::Error> {
+        T::deserialize(serde::de::value::MapDeserializer::new(
+            self.values.clone().into_iter(),
+        ))
+    }
+
+    fn collect(&mut self) {
+        self.values.clear();
+        self.valid = true;
+        if let Some(child) = &self.child {
+            collect_fields(child.as_ref(), &mut self.values, &mut self.valid);
+        }
+    }
+}
+
+/// Collect the values of the fields of a widget tree
+fn collect_fields(
+    widget: &dyn Widget,
+    values: &mut HashMap<String, FieldValue>,
+    valid: &mut bool,
+) {
+    if let Some(field) = widget.field() {
+        let value = field.value();
+        if field.validation().check(&value).is_some() {
+            *valid = false;
+        }
+        values.insert(widget.name().to_string(), value);
+    }
+    for child in widget.children() {
+        collect_fields(child, values, valid);
+    }
+}
+
+/// Check the fields of a widget tree and display the errors found
+fn validate_fields(widget: &mut dyn Widget) {
+    if let Some(field) = widget.field_mut() {
+        field.validate();
+    }
+    for child in widget.children_mut() {
+        validate_fields(child);
+    }
+}
+
+/// # The listener of a Form
+pub trait FormListener {
+    /// Function triggered on update event
+    fn on_update(&self, state: &mut FormState);
+
+    /// Function triggered on change event of a widget of the form
+    fn on_change(&self, state: &FormState);
+}
+
+/// # A group of validated fields
+///
+/// The Form reports if all the fields it contains are valid and collects
+/// their values by name. A field is any widget having validators, like a
+/// TextInput, a Combo, a Range, a CheckBox or a Radio.
+///
+/// ## Fields
+///
+/// ```text
+/// name: String
+/// state: FormState
+/// listener: Option<Box<dyn FormListener>>
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// name: name.to_string()
+/// state:
+///     child: None
+///     valid: true
+///     values: HashMap::new()
+///     stretched: false
+///     style: "".to_string()
+///     tooltip: None
+/// listener: None
+/// ```
+///
+/// ## Style
+///
+/// ```text
+/// div.form[.invalid]
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use neutrino::utils::validation::Validator;
+/// use neutrino::widgets::form::{Form, FormListener, FormState};
+/// use neutrino::widgets::textinput::TextInput;
+///
+///
+/// struct Account {
+///     username: String,
+///     valid: bool,
+/// }
+///
+/// impl Account {
+///     fn new() -> Self {
+///         Self { username: "".to_string(), valid: false }
+///     }
+/// }
+///
+///
+/// struct MyFormListener {
+///     account: Rc<RefCell<Account>>,
+/// }
+///
+/// impl MyFormListener {
+///    pub fn new(account: Rc<RefCell<Account>>) -> Self {
+///        Self { account }
+///    }
+/// }
+///
+/// impl FormListener for MyFormListener {
+///     fn on_change(&self, state: &FormState) {
+///         let mut account = self.account.borrow_mut();
+///         account.valid = state.valid();
+///         if let Some(value) = state.value("my_username") {
+///             account.username = value.text();
+///         }
+///     }
+///
+///     fn on_update(&self, _state: &mut FormState) {}
+/// }
+///
+///
+/// fn main() {
+///     let account = Rc::new(RefCell::new(Account::new()));
+///
+///     let my_listener = MyFormListener::new(Rc::clone(&account));
+///
+///     let mut my_username = TextInput::new("my_username");
+///     my_username.add_validator(
+///         Validator::required("The username is required")
+///     );
+///
+///     let mut my_form = Form::new("my_form");
+///     my_form.set_child(Box::new(my_username));
+///     my_form.set_listener(Box::new(my_listener));
+/// }
+/// ```
+pub struct Form {
+    name: String,
+    state: FormState,
+    listener: Option<Box<dyn FormListener>>,
+}
+
+impl Form {
+    /// Create a Form
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            state: FormState {
+                child: None,
+                valid: true,
+                values: HashMap::new(),
+                stretched: false,
+                style: "".to_string(),
+                tooltip: None,
+            },
+            listener: None,
+        }
+    }
+
+    /// Set the child
+    pub fn set_child(&mut self, child: Box<dyn Widget>) {
+        self.state.set_child(child);
+    }
+
+    /// Set the stretched flag to true
+    pub fn set_stretched(&mut self) {
+        self.state.set_stretched(true);
+    }
+
+    /// Check every field and display the errors found, return true if all
+    /// the values are valid
+    pub fn validate(&mut self) -> bool {
+        self.state.validate()
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &FormState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut FormState {
+        &mut self.state
+    }
+
+    /// Set the listener
+    pub fn set_listener(&mut self, listener: Box<dyn FormListener>) {
+        self.listener = Some(listener);
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.state.set_style(style);
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
+
+    /// Find a widget by name in the form and downcast it to the type T
+    pub fn find<T: Widget + 'static>(&self, name: &str) -> Option<&T> {
+        find_as(self, name)
+    }
+
+    /// Find a mutable widget by name in the form and downcast it to the
+    /// type T
+    pub fn find_mut<T: Widget + 'static>(
+        &mut self,
+        name: &str,
+    ) -> Option<&mut T> {
+        find_as_mut(self, name)
+    }
+}
+
+impl Widget for Form {
+    fn eval(&self) -> String {
+        let stretched = if self.state.stretched() {
+            "stretched"
+        } else {
+            ""
+        };
+        let invalid = if self.state.valid() { "" } else { "invalid" };
+        let style = inline_style(&scss_to_css(&format!(
+            r##"#{}{{{}}}"##,
+            self.name,
+            self.state.style(),
+        )));
+        let html = format!(
+            r#"<div id="{}" class="form {} {}">{}</div>"#,
+            self.name,
+            stretched,
+            invalid,
+            match &self.state.child {
+                Some(child) => child.eval(),
+                None => "".to_string(),
+            },
+        );
+        format!("{}{}", style, html)
+    }
+
+    fn trigger(&mut self, event: &Event) {
+        if let Some(child) = &mut self.state.child {
+            child.trigger(event);
+        }
+        match event {
+            Event::Update => self.on_update(),
+            Event::Change { source, value } => {
+                let inside = match &self.state.child {
+                    Some(child) => find(child.as_ref(), source).is_some(),
+                    None => false,
+                };
+                if inside {
+                    self.on_change(value)
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn on_update(&mut self) {
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_update(&mut self.state);
+            }
+        }
+        self.state.collect();
+    }
+
+    fn on_change(&mut self, _value: &str) {
+        self.state.collect();
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_change(&self.state);
+            }
+        }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+
+    fn children(&self) -> Vec<&dyn Widget> {
+        match &self.state.child {
+            Some(child) => vec![child.as_ref()],
+            None => vec![],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Widget> {
+        match &mut self.state.child {
+            Some(child) => vec![child.as_mut()],
+            None => vec![],
+        }
+    }
+}
@@ -0,0 +1,536 @@
+use crate::utils::event::Event;
+use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
+use crate::widgets::container::Alignment;
+use crate::widgets::widget::{find_as, find_as_mut, Widget};
+
+/// # A cell of a Grid
+///
+/// ## Fields
+///
+/// ```text
+/// child: Box<dyn Widget>
+/// row: u32
+/// column: u32
+/// row_span: u32
+/// column_span: u32
+/// horizontal_alignment: Alignment
+/// vertical_alignment: Alignment
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// child: child
+/// row: row
+/// column: column
+/// row_span: 1
+/// column_span: 1
+/// horizontal_alignment: Alignment::None
+/// vertical_alignment: Alignment::None
+/// ```
+pub struct GridCell {
+    child: Box<dyn Widget>,
+    row: u32,
+    column: u32,
+    row_span: u32,
+    column_span: u32,
+    horizontal_alignment: Alignment,
+    vertical_alignment: Alignment,
+}
+
+impl GridCell {
+    /// Create a GridCell holding a child at the given row and column
+    pub fn new(child: Box<dyn Widget>, row: u32, column: u32) -> Self {
+        Self {
+            child,
+            row,
+            column,
+            row_span: 1,
+            column_span: 1,
+            horizontal_alignment: Alignment::None,
+            vertical_alignment: Alignment::None,
+        }
+    }
+
+    /// Get the child
+    pub fn child(&self) -> &dyn Widget {
+        self.child.as_ref()
+    }
+
+    /// Get the row
+    pub fn row(&self) -> u32 {
+        self.row
+    }
+
+    /// Get the column
+    pub fn column(&self) -> u32 {
+        self.column
+    }
+
+    /// Get the row span
+    pub fn row_span(&self) -> u32 {
+        self.row_span
+    }
+
+    /// Get the column span
+    pub fn column_span(&self) -> u32 {
+        self.column_span
+    }
+
+    /// Get the horizontal alignment
+    pub fn horizontal_alignment(&self) -> &Alignment {
+        &self.horizontal_alignment
+    }
+
+    /// Get the vertical alignment
+    pub fn vertical_alignment(&self) -> &Alignment {
+        &self.vertical_alignment
+    }
+
+    /// Set the position (row and column)
+    pub fn set_position(&mut self, row: u32, column: u32) {
+        self.row = row;
+        self.column = column;
+    }
+
+    /// Set the span (number of rows and columns covered by the cell)
+    pub fn set_span(&mut self, row_span: u32, column_span: u32) {
+        self.row_span = row_span.max(1);
+        self.column_span = column_span.max(1);
+    }
+
+    /// Set the horizontal alignment of the child inside of the cell.
+    /// Alignment::None (default) stretches the child.
+    pub fn set_horizontal_alignment(&mut self, alignment: Alignment) {
+        self.horizontal_alignment = alignment;
+    }
+
+    /// Set the vertical alignment of the child inside of the cell.
+    /// Alignment::None (default) stretches the child.
+    pub fn set_vertical_alignment(&mut self, alignment: Alignment) {
+        self.vertical_alignment = alignment;
+    }
+
+    /// Return the HTML representation of the cell
+    fn eval(&self) -> String {
+        format!(
+            r#"
+            <div class="grid-cell" style="grid-row: {} / span {};
+                grid-column: {} / span {}; justify-self: {};
+                align-self: {};"
+            >
+                {}
+            </div>
+            "#,
+            self.row + 1,
+            self.row_span,
+            self.column + 1,
+            self.column_span,
+            alignment_css(&self.horizontal_alignment),
+            alignment_css(&self.vertical_alignment),
+            self.child.eval(),
+        )
+    }
+}
+
+/// # The state of a Grid
+///
+/// ## Fields
+///
+/// ```text
+/// rows: Vec<Track>
+/// columns: Vec<Track>
+/// cells: Vec<GridCell>
+/// row_gap: u32
+/// column_gap: u32
+/// stretched: bool
+/// style: String
+/// tooltip: Option<Tooltip>
+/// ```
+pub struct GridState {
+    rows: Vec<Track>,
+    columns: Vec<Track>,
+    cells: Vec<GridCell>,
+    row_gap: u32,
+    column_gap: u32,
+    stretched: bool,
+    style: String,
+    tooltip: Option<Tooltip>,
+}
+
+impl GridState {
+    /// Get the rows
+    pub fn rows(&self) -> &Vec<Track> {
+        &self.rows
+    }
+
+    /// Get the columns
+    pub fn columns(&self) -> &Vec<Track> {
+        &self.columns
+    }
+
+    /// Get the cells
+    pub fn cells(&self) -> &Vec<GridCell> {
+        &self.cells
+    }
+
+    /// Get the row gap
+    pub fn row_gap(&self) -> u32 {
+        self.row_gap
+    }
+
+    /// Get the column gap
+    pub fn column_gap(&self) -> u32 {
+        self.column_gap
+    }
+
+    /// Get the stretched flag
+    pub fn stretched(&self) -> bool {
+        self.stretched
+    }
+
+    /// Get the style
+    pub fn style(&self) -> &str {
+        &self.style
+    }
+
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
+    /// Set the rows
+    pub fn set_rows(&mut self, rows: Vec<Track>) {
+        self.rows = rows;
+    }
+
+    /// Set the columns
+    pub fn set_columns(&mut self, columns: Vec<Track>) {
+        self.columns = columns;
+    }
+
+    /// Set the cells
+    pub fn set_cells(&mut self, cells: Vec<GridCell>) {
+        self.cells = cells;
+    }
+
+    /// Set the gaps between rows and between columns
+    pub fn set_gap(&mut self, row_gap: u32, column_gap: u32) {
+        self.row_gap = row_gap;
+        self.column_gap = column_gap;
+    }
+
+    /// Set the stretched flag
+    pub fn set_stretched(&mut self, stretched: bool) {
+        self.stretched = stretched;
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.style = style.to_string();
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
+
+    /// Add a cell
+    pub fn add(&mut self, cell: GridCell) {
+        self.cells.push(cell);
+    }
+
+    /// Remove the cell whose child has the given name
+    pub fn remove(&mut self, name: &str) -> Option<GridCell> {
+        match self.cells.iter().position(|cell| cell.child.name() == name) {
+            Some(index) => Some(self.cells.remove(index)),
+            None => None,
+        }
+    }
+}
+
+/// # The listener of a Grid
+pub trait GridListener {
+    /// Function triggered on update event
+    fn on_update(&self, state: &mut GridState);
+}
+
+/// # A container placing its children on rows and columns
+///
+/// ## Fields
+///
+/// ```text
+/// name: String
+/// state: GridState
+/// listener: Option<Box<dyn GridListener>>
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// name: name.to_string()
+/// state:
+///     rows: vec![]
+///     columns: vec![]
+///     cells: vec![]
+///     row_gap: 0
+///     column_gap: 0
+///     stretched: false
+///     style: "".to_string()
+///     tooltip: None
+/// listener: None
+/// ```
+///
+/// ## Style
+///
+/// ```text
+/// div.grid
+///     div.grid-cell
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use neutrino::widgets::container::Alignment;
+/// use neutrino::widgets::grid::{Grid, GridCell, Track};
+/// use neutrino::widgets::label::Label;
+/// use neutrino::widgets::textinput::TextInput;
+///
+///
+/// fn main() {
+///     let mut username_label = Label::new("username_label");
+///     username_label.set_text("Username");
+///
+///     let username_input = TextInput::new("username_input");
+///
+///     let mut username_cell = GridCell::new(Box::new(username_label), 0, 0);
+///     username_cell.set_vertical_alignment(Alignment::Center);
+///
+///     let mut my_grid = Grid::new("my_grid");
+///     my_grid.set_columns(vec![Track::Auto, Track::Fraction(1)]);
+///     my_grid.set_rows(vec![Track::Auto]);
+///     my_grid.set_gap(0, 12);
+///     my_grid.add(username_cell);
+///     my_grid.add(GridCell::new(Box::new(username_input), 0, 1));
+/// }
+/// ```
+pub struct Grid {
+    name: String,
+    state: GridState,
+    listener: Option<Box<dyn GridListener>>,
+}
+
+impl Grid {
+    /// Create a Grid
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            state: GridState {
+                rows: vec![],
+                columns: vec![],
+                cells: vec![],
+                row_gap: 0,
+                column_gap: 0,
+                stretched: false,
+                style: "".to_string(),
+                tooltip: None,
+            },
+            listener: None,
+        }
+    }
+
+    /// Set the rows
+    ///
+    /// The rows which are not defined are sized automatically.
+    pub fn set_rows(&mut self, rows: Vec<Track>) {
+        self.state.set_rows(rows);
+    }
+
+    /// Set the columns
+    ///
+    /// The columns which are not defined are sized automatically.
+    pub fn set_columns(&mut self, columns: Vec<Track>) {
+        self.state.set_columns(columns);
+    }
+
+    /// Set the gaps (in pixels) between rows and between columns
+    pub fn set_gap(&mut self, row_gap: u32, column_gap: u32) {
+        self.state.set_gap(row_gap, column_gap);
+    }
+
+    /// Set the stretched flag to true
+    pub fn set_stretched(&mut self) {
+        self.state.set_stretched(true);
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &GridState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut GridState {
+        &mut self.state
+    }
+
+    /// Set the listener
+    pub fn set_listener(&mut self, listener: Box<dyn GridListener>) {
+        self.listener = Some(listener);
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.state.set_style(style);
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
+
+    /// Add a cell
+    pub fn add(&mut self, cell: GridCell) {
+        self.state.add(cell);
+    }
+
+    /// Find a widget by name in the grid and downcast it to the type T
+    pub fn find<T: Widget + 'static>(&self, name: &str) -> Option<&T> {
+        find_as(self, name)
+    }
+
+    /// Find a mutable widget by name in the grid and downcast it to the type
+    /// T
+    pub fn find_mut<T: Widget + 'static>(
+        &mut self,
+        name: &str,
+    ) -> Option<&mut T> {
+        find_as_mut(self, name)
+    }
+}
+
+impl Widget for Grid {
+    fn eval(&self) -> String {
+        let stretched = if self.state.stretched() {
+            "stretched"
+        } else {
+            ""
+        };
+        let style = inline_style(&scss_to_css(&format!(
+            r##"#{}{{
+                grid-template-rows: {};
+                grid-template-columns: {};
+                row-gap: {}px;
+                column-gap: {}px;
+                {}
+            }}"##,
+            self.name,
+            tracks_css(self.state.rows()),
+            tracks_css(self.state.columns()),
+            self.state.row_gap(),
+            self.state.column_gap(),
+            self.state.style(),
+        )));
+        let mut html =
+            format!(r#"<div id="{}" class="grid {}">"#, self.name, stretched);
+        for cell in self.state.cells.iter() {
+            html.push_str(&cell.eval());
+        }
+        html.push_str("</div>");
+        format!("{}{}", style, html)
+    }
+
+    fn trigger(&mut self, event: &Event) {
+        match event {
+            Event::Update => self.on_update(),
+            Event::Change { source, value } => {
+                if source == &self.name {
+                    self.on_change(value)
+                }
+            }
+            _ => (),
+        }
+        for cell in self.state.cells.iter_mut() {
+            cell.child.trigger(event);
+        }
+    }
+
+    fn on_update(&mut self) {
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_update(&mut self.state);
+            }
+        }
+    }
+
+    fn on_change(&mut self, _value: &str) {}
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+
+    fn children(&self) -> Vec<&dyn Widget> {
+        self.state
+            .cells
+            .iter()
+            .map(|cell| cell.child.as_ref())
+            .collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Widget> {
+        self.state
+            .cells
+            .iter_mut()
+            .map(|cell| cell.child.as_mut() as &mut dyn Widget)
+            .collect()
+    }
+}
+
+/// # The size of a row or a column of a Grid
+pub enum Track {
+    /// A size in pixels
+    Fixed(u32),
+    /// A share of the remaining space
+    Fraction(u32),
+    /// A size fitting the content
+    Auto,
+}
+
+impl Track {
+    // Return the CSS size corresponding to the track
+    fn css(&self) -> String {
+        match &self {
+            Track::Fixed(size) => format!("{}px", size),
+            Track::Fraction(fraction) => format!("{}fr", fraction),
+            Track::Auto => "auto".to_string(),
+        }
+    }
+}
+
+// Return the CSS template corresponding to a list of tracks
+fn tracks_css(tracks: &[Track]) -> String {
+    if tracks.is_empty() {
+        "none".to_string()
+    } else {
+        tracks
+            .iter()
+            .map(|track| track.css())
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
+
+// Return the CSS self alignment corresponding to an alignment
+fn alignment_css(alignment: &Alignment) -> &str {
+    match alignment {
+        Alignment::None => "stretch",
+        Alignment::Center => "center",
+        Alignment::Start => "start",
+        Alignment::End => "end",
+    }
+}
@@ -2,6 +2,7 @@ use crate::utils::event::Event;
 use crate::utils::icon::Icon;
 use crate::utils::pixmap::Pixmap;
 use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
 use crate::widgets::widget::Widget;
 
 /// # The state of an Image
@@ -15,6 +16,7 @@ use crate::widgets::widget::Widget;
 /// keep_ratio_aspect: bool
 /// stretched: bool
 /// style: String
+/// tooltip: Option<Tooltip>
 /// ```
 pub struct ImageState {
     data: String,
@@ -23,6 +25,7 @@ pub struct ImageState {
     keep_ratio_aspect: bool,
     stretched: bool,
     style: String,
+    tooltip: Option<Tooltip>,
 }
 
 impl ImageState {
@@ -56,6 +59,11 @@ impl ImageState {
         &self.style
     }
 
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
     /// Set the base64 encoded image data
     pub fn set_data(&mut self, data: &str) {
         self.data = data.to_string();
@@ -85,6 +93,11 @@ impl ImageState {
     pub fn set_style(&mut self, style: &str) {
         self.style = style.to_string();
     }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
 }
 
 /// # The listener for an Image
@@ -117,6 +130,7 @@ pub trait ImageListener {
 ///     keep_ratio_aspect: false
 ///     stretched: false
 ///     style: "".to_string()
+///     tooltip: None
 /// listener: None
 /// ```
 ///
@@ -206,6 +220,7 @@ impl Image {
                 keep_ratio_aspect: false,
                 stretched: false,
                 style: "".to_string(),
+                tooltip: None,
             },
             listener: None,
         }
@@ -223,6 +238,7 @@ impl Image {
                 keep_ratio_aspect: false,
                 stretched: false,
                 style: "".to_string(),
+                tooltip: None,
             },
             listener: None,
         }
@@ -243,6 +259,16 @@ impl Image {
         self.state.set_stretched(true);
     }
 
+    /// Get the state
+    pub fn state(&self) -> &ImageState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut ImageState {
+        &mut self.state
+    }
+
     /// Set the listener
     pub fn set_listener(&mut self, listener: Box<dyn ImageListener>) {
         self.listener = Some(listener);
@@ -252,6 +278,11 @@ impl Image {
     pub fn set_style(&mut self, style: &str) {
         self.state.set_style(style);
     }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
 }
 
 impl Widget for Image {
@@ -309,4 +340,12 @@ impl Widget for Image {
     }
 
     fn on_change(&mut self, _value: &str) {}
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
 }
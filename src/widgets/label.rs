@@ -1,5 +1,6 @@
 use crate::utils::event::Event;
 use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
 use crate::widgets::widget::Widget;
 
 /// # The state of a Label
@@ -11,12 +12,14 @@ use crate::widgets::widget::Widget;
 /// stretched: bool
 /// unselectable: bool
 /// style: String
+/// tooltip: Option<Tooltip>
 /// ```
 pub struct LabelState {
     text: String,
     stretched: bool,
     unselectable: bool,
     style: String,
+    tooltip: Option<Tooltip>,
 }
 
 impl LabelState {
@@ -40,6 +43,11 @@ impl LabelState {
         &self.style
     }
 
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
     /// Set the text
     pub fn set_text(&mut self, text: &str) {
         self.text = text.to_string();
@@ -59,6 +67,11 @@ impl LabelState {
     pub fn set_style(&mut self, style: &str) {
         self.style = style.to_string();
     }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
 }
 
 /// # The listener of a Label
@@ -86,6 +99,7 @@ pub trait LabelListener {
 ///     stretched: false
 ///     unselectable: false
 ///     style: "".to_string()
+///     tooltip: None
 /// listener: None
 /// ```
 ///
@@ -164,6 +178,7 @@ impl Label {
                 stretched: false,
                 unselectable: false,
                 style: "".to_string(),
+                tooltip: None,
             },
             listener: None,
         }
@@ -184,6 +199,16 @@ impl Label {
         self.state.set_unselectable(true);
     }
 
+    /// Get the state
+    pub fn state(&self) -> &LabelState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut LabelState {
+        &mut self.state
+    }
+
     /// Set the listener
     pub fn set_listener(&mut self, listener: Box<dyn LabelListener>) {
         self.listener = Some(listener);
@@ -193,6 +218,11 @@ impl Label {
     pub fn set_style(&mut self, style: &str) {
         self.state.set_style(style);
     }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
 }
 
 impl Widget for Label {
@@ -244,4 +274,12 @@ impl Widget for Label {
     }
 
     fn on_change(&mut self, _value: &str) {}
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
 }
@@ -0,0 +1,683 @@
+use crate::utils::event::{Event, Key};
+use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
+use crate::widgets::widget::Widget;
+use std::collections::HashSet;
+
+/// Number of rows rendered above and below the visible ones
+const OVERSCAN: usize = 5;
+
+/// # The state of a ListView
+///
+/// ## Fields
+///
+/// ```text
+/// items: Vec<String>
+/// selected: Vec<usize>
+/// cursor: Option<usize>
+/// anchor: Option<usize>
+/// selection_mode: SelectionMode
+/// row_height: u32
+/// scroll_top: u32
+/// viewport_height: u32
+/// request: u32
+/// disabled: bool
+/// stretched: bool
+/// style: String
+/// tooltip: Option<Tooltip>
+/// ```
+pub struct ListViewState {
+    items: Vec<String>,
+    selected: Vec<usize>,
+    cursor: Option<usize>,
+    anchor: Option<usize>,
+    selection_mode: SelectionMode,
+    row_height: u32,
+    scroll_top: u32,
+    viewport_height: u32,
+    request: u32,
+    disabled: bool,
+    stretched: bool,
+    style: String,
+    tooltip: Option<Tooltip>,
+}
+
+impl ListViewState {
+    /// Get the items
+    pub fn items(&self) -> &Vec<String> {
+        &self.items
+    }
+
+    /// Get the indexes of the selected items, in ascending order
+    pub fn selected(&self) -> &Vec<usize> {
+        &self.selected
+    }
+
+    /// Get the index of the item having the keyboard focus
+    pub fn cursor(&self) -> Option<usize> {
+        self.cursor
+    }
+
+    /// Get the selection mode
+    pub fn selection_mode(&self) -> &SelectionMode {
+        &self.selection_mode
+    }
+
+    /// Get the row height
+    pub fn row_height(&self) -> u32 {
+        self.row_height
+    }
+
+    /// Get the vertical scroll offset
+    pub fn scroll_top(&self) -> u32 {
+        self.scroll_top
+    }
+
+    /// Get the disabled flag
+    pub fn disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Get the stretched flag
+    pub fn stretched(&self) -> bool {
+        self.stretched
+    }
+
+    /// Get the style
+    pub fn style(&self) -> &str {
+        &self.style
+    }
+
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
+    /// Set the items
+    ///
+    /// The selected indexes which are out of the new items are dropped.
+    pub fn set_items(&mut self, items: Vec<String>) {
+        let len = items.len();
+        self.items = items;
+        self.selected.retain(|&index| index < len);
+        self.cursor = self.cursor.filter(|&index| index < len);
+        self.anchor = self.anchor.filter(|&index| index < len);
+    }
+
+    /// Set the indexes of the selected items
+    pub fn set_selected(&mut self, selected: Vec<usize>) {
+        let len = self.items.len();
+        self.selected = selected;
+        self.selected.retain(|&index| index < len);
+        self.selected.sort();
+        self.selected.dedup();
+        if let SelectionMode::Single = self.selection_mode {
+            self.selected.truncate(1);
+        }
+    }
+
+    /// Set the index of the item having the keyboard focus
+    pub fn set_cursor(&mut self, cursor: Option<usize>) {
+        self.cursor = cursor.filter(|&index| index < self.items.len());
+    }
+
+    /// Set the selection mode
+    ///
+    /// Switching to single selection keeps only the item having the keyboard
+    /// focus if it is selected, the first selected item otherwise.
+    pub fn set_selection_mode(&mut self, selection_mode: SelectionMode) {
+        self.selection_mode = selection_mode;
+        if let SelectionMode::Single = self.selection_mode {
+            let kept = match self.cursor {
+                Some(cursor) if self.selected.contains(&cursor) => Some(cursor),
+                _ => self.selected.first().cloned(),
+            };
+            self.selected = kept.into_iter().collect();
+            self.anchor = kept;
+        }
+    }
+
+    /// Set the row height (in pixels)
+    pub fn set_row_height(&mut self, row_height: u32) {
+        self.row_height = row_height.max(1);
+    }
+
+    /// Scroll until the item at the given index is visible
+    pub fn scroll_to(&mut self, index: usize) {
+        let top = index as u32 * self.row_height;
+        let bottom = top + self.row_height;
+        if top < self.scroll_top {
+            self.scroll_top = top;
+            self.request += 1;
+        } else if bottom > self.scroll_top + self.viewport_height {
+            self.scroll_top = bottom.saturating_sub(self.viewport_height);
+            self.request += 1;
+        }
+    }
+
+    /// Set the disabled flag
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+
+    /// Set the stretched flag
+    pub fn set_stretched(&mut self, stretched: bool) {
+        self.stretched = stretched;
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.style = style.to_string();
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
+
+    /// Select the item at the given index
+    ///
+    /// In multiple selection mode, `toggle` adds or removes the item from the
+    /// selection and `extend` selects every item from the anchor to the item.
+    fn select(&mut self, index: usize, toggle: bool, extend: bool) {
+        match self.selection_mode {
+            SelectionMode::Multiple if extend => {
+                let anchor = self.anchor.unwrap_or(index);
+                self.selected =
+                    (anchor.min(index)..=anchor.max(index)).collect();
+            }
+            SelectionMode::Multiple if toggle => {
+                match self.selected.iter().position(|&i| i == index) {
+                    Some(position) => {
+                        self.selected.remove(position);
+                    }
+                    None => {
+                        self.selected.push(index);
+                        self.selected.sort();
+                    }
+                }
+                self.anchor = Some(index);
+            }
+            _ => {
+                self.selected = vec![index];
+                self.anchor = Some(index);
+            }
+        }
+        self.cursor = Some(index);
+    }
+
+    /// Get the range of the rows to render
+    fn visible_range(&self) -> (usize, usize) {
+        let first = (self.scroll_top / self.row_height) as usize;
+        let count = (self.viewport_height / self.row_height) as usize + 1;
+        (
+            first.saturating_sub(OVERSCAN),
+            (first + count + OVERSCAN).min(self.items.len()),
+        )
+    }
+}
+
+/// # The listener of a ListView
+pub trait ListViewListener {
+    /// Function triggered on update event
+    fn on_update(&self, state: &mut ListViewState);
+
+    /// Function triggered on change event
+    fn on_change(&self, state: &ListViewState);
+}
+
+/// # The template building the rows of a ListView
+///
+/// The widgets are built for the visible rows each time the ListView is
+/// rendered, so they are only used to display the items.
+pub trait ListViewTemplate {
+    /// Return the widget displaying the item at the given index
+    fn eval(&self, index: usize, item: &str) -> Box<dyn Widget>;
+}
+
+/// # A scrollable list of selectable items
+///
+/// Only the visible rows are rendered, so that lists containing a large
+/// number of items stay responsive. All the rows have the same height.
+///
+/// ## Fields
+///
+/// ```text
+/// name: String
+/// state: ListViewState
+/// template: Option<Box<dyn ListViewTemplate>>
+/// listener: Option<Box<dyn ListViewListener>>
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// name: name.to_string()
+/// state:
+///     items: vec![]
+///     selected: vec![]
+///     cursor: None
+///     anchor: None
+///     selection_mode: SelectionMode::Single
+///     row_height: 28
+///     scroll_top: 0
+///     viewport_height: 480
+///     request: 0
+///     disabled: false
+///     stretched: false
+///     style: "".to_string()
+///     tooltip: None
+/// template: None
+/// listener: None
+/// ```
+///
+/// ## Style
+///
+/// ```text
+/// div.listview[.disabled]
+///     div.listview-content
+///         div.listview-row[.selected][.cursor]
+///             div.listview-text
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use neutrino::widgets::label::Label;
+/// use neutrino::widgets::listview::{
+///     ListView,
+///     ListViewListener,
+///     ListViewState,
+///     ListViewTemplate,
+///     SelectionMode
+/// };
+/// use neutrino::widgets::widget::Widget;
+///
+///
+/// struct Files {
+///     selected: Vec<usize>,
+/// }
+///
+/// impl Files {
+///     fn new() -> Self {
+///         Self { selected: vec![] }
+///     }
+///
+///     fn set_selected(&mut self, selected: Vec<usize>) {
+///         self.selected = selected;
+///     }
+/// }
+///
+///
+/// struct MyListViewListener {
+///     files: Rc<RefCell<Files>>,
+/// }
+///
+/// impl MyListViewListener {
+///    pub fn new(files: Rc<RefCell<Files>>) -> Self {
+///        Self { files }
+///    }
+/// }
+///
+/// impl ListViewListener for MyListViewListener {
+///     fn on_change(&self, state: &ListViewState) {
+///         self.files.borrow_mut().set_selected(state.selected().clone());
+///     }
+///
+///     fn on_update(&self, _state: &mut ListViewState) {}
+/// }
+///
+///
+/// struct MyListViewTemplate;
+///
+/// impl ListViewTemplate for MyListViewTemplate {
+///     fn eval(&self, index: usize, item: &str) -> Box<dyn Widget> {
+///         let mut label = Label::new(&format!("file-{}", index));
+///         label.set_text(item);
+///         Box::new(label)
+///     }
+/// }
+///
+///
+/// fn main() {
+///     let files = Rc::new(RefCell::new(Files::new()));
+///
+///     let my_listener = MyListViewListener::new(Rc::clone(&files));
+///
+///     let mut my_listview = ListView::new("my_listview");
+///     my_listview.set_items(
+///         (0..100_000).map(|i| format!("File {}", i)).collect()
+///     );
+///     my_listview.set_selection_mode(SelectionMode::Multiple);
+///     my_listview.set_template(Box::new(MyListViewTemplate));
+///     my_listview.set_listener(Box::new(my_listener));
+/// }
+/// ```
+pub struct ListView {
+    name: String,
+    state: ListViewState,
+    template: Option<Box<dyn ListViewTemplate>>,
+    listener: Option<Box<dyn ListViewListener>>,
+}
+
+impl ListView {
+    /// Create a ListView
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            state: ListViewState {
+                items: vec![],
+                selected: vec![],
+                cursor: None,
+                anchor: None,
+                selection_mode: SelectionMode::Single,
+                row_height: 28,
+                scroll_top: 0,
+                viewport_height: 480,
+                request: 0,
+                disabled: false,
+                stretched: false,
+                style: "".to_string(),
+                tooltip: None,
+            },
+            template: None,
+            listener: None,
+        }
+    }
+
+    /// Set the items
+    pub fn set_items(&mut self, items: Vec<String>) {
+        self.state.set_items(items);
+    }
+
+    /// Set the indexes of the selected items
+    pub fn set_selected(&mut self, selected: Vec<usize>) {
+        self.state.set_selected(selected);
+    }
+
+    /// Set the selection mode
+    pub fn set_selection_mode(&mut self, selection_mode: SelectionMode) {
+        self.state.set_selection_mode(selection_mode);
+    }
+
+    /// Set the row height (in pixels)
+    pub fn set_row_height(&mut self, row_height: u32) {
+        self.state.set_row_height(row_height);
+    }
+
+    /// Set the disabled flag to true
+    pub fn set_disabled(&mut self) {
+        self.state.set_disabled(true);
+    }
+
+    /// Set the stretched flag to true
+    pub fn set_stretched(&mut self) {
+        self.state.set_stretched(true);
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &ListViewState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut ListViewState {
+        &mut self.state
+    }
+
+    /// Set the template
+    pub fn set_template(&mut self, template: Box<dyn ListViewTemplate>) {
+        self.template = Some(template);
+    }
+
+    /// Set the listener
+    pub fn set_listener(&mut self, listener: Box<dyn ListViewListener>) {
+        self.listener = Some(listener);
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.state.set_style(style);
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
+
+    /// Function triggered on keypress event
+    fn on_keys(&mut self, keys: &HashSet<Key>) {
+        let len = self.state.items.len();
+        if len == 0 {
+            return;
+        }
+        let page = (self.state.viewport_height / self.state.row_height).max(1)
+            as usize;
+        let cursor = self.state.cursor;
+        let target = if keys.contains(&Key::Down) {
+            Some(cursor.map_or(0, |c| (c + 1).min(len - 1)))
+        } else if keys.contains(&Key::Up) {
+            Some(cursor.map_or(0, |c| c.saturating_sub(1)))
+        } else if keys.contains(&Key::PageDown) {
+            Some(cursor.map_or(0, |c| (c + page).min(len - 1)))
+        } else if keys.contains(&Key::PageUp) {
+            Some(cursor.map_or(0, |c| c.saturating_sub(page)))
+        } else if keys.contains(&Key::Home) {
+            Some(0)
+        } else if keys.contains(&Key::End) {
+            Some(len - 1)
+        } else {
+            None
+        };
+        match (target, &self.state.selection_mode) {
+            (Some(index), SelectionMode::Multiple)
+                if keys.contains(&Key::Control) =>
+            {
+                self.state.cursor = Some(index);
+            }
+            (Some(index), _) => {
+                self.state.select(index, false, keys.contains(&Key::Shift));
+            }
+            (None, _) => match cursor {
+                Some(index) if keys.contains(&Key::Space) => {
+                    self.state.select(index, true, false);
+                }
+                _ => return,
+            },
+        }
+        if let Some(index) = self.state.cursor {
+            self.state.scroll_to(index);
+        }
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_change(&self.state);
+            }
+        }
+    }
+
+    /// Return the HTML representation of the row at the given index
+    fn eval_row(&self, index: usize) -> String {
+        let item = &self.state.items[index];
+        let selected = if self.state.selected.contains(&index) {
+            "selected"
+        } else {
+            ""
+        };
+        let cursor = if self.state.cursor == Some(index) {
+            "cursor"
+        } else {
+            ""
+        };
+        format!(
+            r#"
+            <div class="listview-row {} {}" onclick="{}"
+                style="top: {}px; height: {}px;"
+            >
+                {}
+            </div>
+            "#,
+            selected,
+            cursor,
+            Event::change_js(
+                &self.name,
+                &format!(
+                    "'click;{};' + event.ctrlKey + ';' + event.shiftKey",
+                    index
+                )
+            ),
+            index as u32 * self.state.row_height,
+            self.state.row_height,
+            match &self.template {
+                Some(template) => template.eval(index, item).eval(),
+                None => format!(r#"<div class="listview-text">{}</div>"#, item),
+            },
+        )
+    }
+}
+
+impl Widget for ListView {
+    fn eval(&self) -> String {
+        let stretched = if self.state.stretched() {
+            "stretched"
+        } else {
+            ""
+        };
+        let disabled = if self.state.disabled() {
+            "disabled"
+        } else {
+            ""
+        };
+        let style = inline_style(&scss_to_css(&format!(
+            r##"#{}{{{}}}"##,
+            self.name,
+            self.state.style(),
+        )));
+        let mut html = format!(
+            r#"
+            <div id="{}" class="listview {} {}" tabindex="0"
+                data-x="0" data-y="{}" data-request="{}" data-viewport="{}"
+                onscroll="scrolled(this, 10)" onkeydown="navigate(event); {}"
+            >
+                <div class="listview-content" style="height: {}px;">
+            "#,
+            self.name,
+            disabled,
+            stretched,
+            self.state.scroll_top(),
+            self.state.request,
+            self.state.viewport_height,
+            Event::keypress_js(&self.name, "down"),
+            self.state.items.len() as u32 * self.state.row_height,
+        );
+        let (first, last) = self.state.visible_range();
+        for index in first..last {
+            html.push_str(&self.eval_row(index));
+        }
+        html.push_str("</div></div>");
+        format!("{}{}", style, html)
+    }
+
+    fn trigger(&mut self, event: &Event) {
+        match event {
+            Event::Update => self.on_update(),
+            Event::Change { source, value } => {
+                if source == &self.name && !self.state.disabled() {
+                    self.on_change(value);
+                }
+            }
+            Event::Keypress { source, keys } => {
+                if source == &self.name && !self.state.disabled() {
+                    self.on_keys(keys);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn on_update(&mut self) {
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_update(&mut self.state);
+            }
+        }
+    }
+
+    fn on_change(&mut self, value: &str) {
+        let values = value.split(';').collect::<Vec<&str>>();
+        match values.as_slice() {
+            ["scroll", _, top, _, height] => {
+                self.state.scroll_top =
+                    top.parse::<f64>().unwrap_or(0.0) as u32;
+                self.state.viewport_height =
+                    height.parse::<f64>().unwrap_or(0.0) as u32;
+            }
+            ["click", index, control, shift] => {
+                if let Ok(index) = index.parse::<usize>() {
+                    if index < self.state.items.len() {
+                        self.state.select(
+                            index,
+                            *control == "true",
+                            *shift == "true",
+                        );
+                        match &self.listener {
+                            None => (),
+                            Some(listener) => {
+                                listener.on_change(&self.state);
+                            }
+                        }
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+}
+
+/// # The selection mode of a list
+pub enum SelectionMode {
+    Single,
+    Multiple,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn listview() -> ListView {
+        let mut listview = ListView::new("listview");
+        listview.set_items((0..4).map(|i| i.to_string()).collect());
+        listview.set_selection_mode(SelectionMode::Multiple);
+        listview
+    }
+
+    #[test]
+    fn single_selection_keeps_the_cursor_item() {
+        let mut listview = listview();
+        listview.state.select(1, false, false);
+        listview.state.select(3, true, false);
+        listview.state.select(2, true, false);
+        listview.set_selection_mode(SelectionMode::Single);
+        assert_eq!(listview.state().selected(), &vec![2]);
+    }
+
+    #[test]
+    fn single_selection_keeps_the_first_selected_item() {
+        let mut listview = listview();
+        listview.set_selected(vec![3, 1]);
+        listview.set_selection_mode(SelectionMode::Single);
+        assert_eq!(listview.state().selected(), &vec![1]);
+    }
+}
@@ -1,5 +1,7 @@
 use crate::utils::event::Event;
 use crate::utils::event::Key;
+use crate::utils::icon::Icon;
+use crate::utils::pixmap::Pixmap;
 
 /// # The state of a MenuBar
 ///
@@ -438,12 +440,14 @@ impl MenuItem {
             if selected_index == index as u32 {
                 s.push_str(r#"<div class="menufunctions">"#);
                 let functions_number = self.functions.len();
+                let iconic = self.functions.iter().any(|f| f.icon.is_some());
                 for (i, function) in self.functions.iter().enumerate() {
                     s.push_str(&function.eval(
                         i,
                         i == 0,
                         i == functions_number - 1,
                         hovered_function,
+                        iconic,
                     ));
                 }
                 s.push_str(r#"</div>"#);
@@ -456,11 +460,15 @@ impl MenuItem {
 
 /// # A function of a MenuItem
 ///
+/// A monochrome SVG icon is inlined and drawn with the text colour.
+///
 /// ## Fields
 ///
 /// ```text
 /// name: String
 /// shortcut: Option<String>
+/// icon: Option<Pixmap>
+/// monochrome: bool
 /// ```
 ///
 /// ## Default values
@@ -468,10 +476,14 @@ impl MenuItem {
 /// ```text
 /// name: name.to_string()
 /// shortcut: None
+/// icon: None
+/// monochrome: false
 /// ```
 pub struct MenuFunction {
     name: String,
     shortcut: Option<String>,
+    icon: Option<Pixmap>,
+    monochrome: bool,
 }
 
 impl MenuFunction {
@@ -480,6 +492,8 @@ impl MenuFunction {
         Self {
             name: name.to_string(),
             shortcut: None,
+            icon: None,
+            monochrome: false,
         }
     }
 
@@ -488,6 +502,21 @@ impl MenuFunction {
         self.shortcut = Some(shortcut.to_string());
     }
 
+    /// Set the icon
+    pub fn set_icon(&mut self, icon: Box<dyn Icon>) {
+        let mut pixmap = Pixmap::from_icon(icon);
+        pixmap.set_monochrome(self.monochrome);
+        self.icon = Some(pixmap);
+    }
+
+    /// Set the monochrome flag to true
+    pub fn set_monochrome(&mut self) {
+        self.monochrome = true;
+        if let Some(icon) = &mut self.icon {
+            icon.set_monochrome(true);
+        }
+    }
+
     /// Return the HTML representation of the widget
     fn eval(
         &self,
@@ -495,7 +524,18 @@ impl MenuFunction {
         first: bool,
         last: bool,
         hovered_function: Option<u32>,
+        iconic: bool,
     ) -> String {
+        let icon = match (&self.icon, iconic) {
+            (Some(icon), _) => format!(
+                r#"<span class="menufunction-icon">{}</span>"#,
+                icon.eval_icon()
+            ),
+            (None, true) => {
+                r#"<span class="menufunction-icon"></span>"#.to_string()
+            }
+            (None, false) => "".to_string(),
+        };
         let hovered = match hovered_function {
             Some(hovered_index) => {
                 if hovered_index == index as u32 {
@@ -512,6 +552,7 @@ impl MenuFunction {
                 onclick="{}" 
                 onmouseover="{}"
             >
+                {}
                 <span class="title">{}</span>
                 <span class="shortcut">{}</span>
             </div>
@@ -521,6 +562,7 @@ impl MenuFunction {
             hovered,
             Event::change_js("menufunction", &format!("'click;{}'", index)),
             Event::change_js("menufunction", &format!("'over;{}'", index)),
+            icon,
             self.name,
             match &self.shortcut {
                 None => "",
@@ -1,13 +1,33 @@
 pub mod button;
+pub mod calendar;
+pub mod canvas;
+pub mod chart;
 pub mod checkbox;
+pub mod colorpicker;
 pub mod combo;
 pub mod container;
+pub mod datepicker;
+pub mod form;
+pub mod grid;
 pub mod image;
 pub mod label;
+pub mod listview;
 pub mod menubar;
 pub mod progressbar;
 pub mod radio;
+pub mod radiobutton;
+pub mod radiogroup;
 pub mod range;
+pub mod scrollarea;
+pub mod spinbox;
+pub mod splitter;
+pub mod statusbar;
+pub mod svgimage;
+pub mod table;
 pub mod tabs;
+pub mod textarea;
 pub mod textinput;
+pub mod timepicker;
+pub mod toolbar;
+pub mod treeview;
 pub mod widget;
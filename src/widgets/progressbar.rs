@@ -1,7 +1,27 @@
 use crate::utils::event::Event;
 use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
 use crate::widgets::widget::Widget;
 
+/// # The status of a ProgressBar, giving its colour
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProgressStatus {
+    Normal,
+    Warning,
+    Error,
+}
+
+impl ProgressStatus {
+    /// Get the CSS class of the status
+    pub fn css(&self) -> &str {
+        match self {
+            ProgressStatus::Normal => "",
+            ProgressStatus::Warning => "warning",
+            ProgressStatus::Error => "error",
+        }
+    }
+}
+
 /// # The state of a ProgressBar
 ///
 /// ## Fields
@@ -10,15 +30,25 @@ use crate::widgets::widget::Widget;
 /// min: i32
 /// max: i32
 /// value: i32
+/// indeterminate: bool
+/// text_format: String
+/// status: ProgressStatus
+/// circular: bool
 /// stretched: bool
 /// style: String
+/// tooltip: Option<Tooltip>
 /// ```
 pub struct ProgressBarState {
     min: i32,
     max: i32,
     value: i32,
+    indeterminate: bool,
+    text_format: String,
+    status: ProgressStatus,
+    circular: bool,
     stretched: bool,
     style: String,
+    tooltip: Option<Tooltip>,
 }
 
 impl ProgressBarState {
@@ -37,6 +67,47 @@ impl ProgressBarState {
         self.value
     }
 
+    /// Get the value as a percentage of the range between the min and the
+    /// max
+    pub fn percent(&self) -> f64 {
+        if self.max > self.min {
+            f64::from(self.value - self.min) / f64::from(self.max - self.min)
+                * 100.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Get the indeterminate flag
+    pub fn indeterminate(&self) -> bool {
+        self.indeterminate
+    }
+
+    /// Get the text format
+    pub fn text_format(&self) -> &str {
+        &self.text_format
+    }
+
+    /// Get the text displayed, made of the text format where `{percent}`,
+    /// `{value}`, `{min}` and `{max}` are replaced
+    pub fn text(&self) -> String {
+        self.text_format
+            .replace("{percent}", &format!("{:.0}", self.percent()))
+            .replace("{value}", &self.value.to_string())
+            .replace("{min}", &self.min.to_string())
+            .replace("{max}", &self.max.to_string())
+    }
+
+    /// Get the status
+    pub fn status(&self) -> ProgressStatus {
+        self.status
+    }
+
+    /// Get the circular flag
+    pub fn circular(&self) -> bool {
+        self.circular
+    }
+
     /// Get the stretched flag
     pub fn stretched(&self) -> bool {
         self.stretched
@@ -47,6 +118,11 @@ impl ProgressBarState {
         &self.style
     }
 
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
     /// Set the min
     pub fn set_min(&mut self, min: i32) {
         self.min = min;
@@ -68,6 +144,26 @@ impl ProgressBarState {
         };
     }
 
+    /// Set the indeterminate flag
+    pub fn set_indeterminate(&mut self, indeterminate: bool) {
+        self.indeterminate = indeterminate;
+    }
+
+    /// Set the text format
+    pub fn set_text_format(&mut self, text_format: &str) {
+        self.text_format = text_format.to_string();
+    }
+
+    /// Set the status
+    pub fn set_status(&mut self, status: ProgressStatus) {
+        self.status = status;
+    }
+
+    /// Set the circular flag
+    pub fn set_circular(&mut self, circular: bool) {
+        self.circular = circular;
+    }
+
     /// Set the stretched flqg
     pub fn set_stretched(&mut self, stretched: bool) {
         self.stretched = stretched;
@@ -77,6 +173,11 @@ impl ProgressBarState {
     pub fn set_style(&mut self, style: &str) {
         self.style = style.to_string();
     }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
 }
 
 /// # The listener of a ProgressBar
@@ -87,6 +188,10 @@ pub trait ProgressBarListener {
 
 /// # A progress bar
 ///
+/// An indeterminate progress bar shows an animation instead of the value,
+/// for the operations of unknown length. A circular progress bar is drawn
+/// as a ring.
+///
 /// ## Fields
 ///
 /// ```text
@@ -103,17 +208,26 @@ pub trait ProgressBarListener {
 ///     min: 0
 ///     max: 100
 ///     value: 0
+///     indeterminate: false
+///     text_format: "".to_string()
+///     status: ProgressStatus::Normal
+///     circular: false
 ///     stretched: false
 ///     style: "".to_string()
+///     tooltip: None
 /// listener: None
 /// ```
 ///
 /// ## Style
 ///
 /// ```text
-/// div.progressbar
+/// div.progressbar[.indeterminate][.circular][.warning][.error]
 ///     div.background
 ///     div.foreground
+///     svg
+///         circle.track
+///         circle.arc
+///     div.progressbar-text
 /// ```
 ///
 /// ## Example
@@ -169,6 +283,7 @@ pub trait ProgressBarListener {
 ///     let my_listener = MyProgressBarListener::new(Rc::clone(&counter));
 ///
 ///     let mut my_progressbar = ProgressBar::new("my_progressbar");
+///     my_progressbar.set_text_format("{percent}%");
 ///     my_progressbar.set_listener(Box::new(my_listener));
 /// }
 /// ```
@@ -187,8 +302,13 @@ impl ProgressBar {
                 min: 0,
                 max: 100,
                 value: 0,
+                indeterminate: false,
+                text_format: "".to_string(),
+                status: ProgressStatus::Normal,
+                circular: false,
                 stretched: false,
                 style: "".to_string(),
+                tooltip: None,
             },
             listener: None,
         }
@@ -209,11 +329,42 @@ impl ProgressBar {
         self.state.set_value(value);
     }
 
+    /// Set the indeterminate flag to true
+    pub fn set_indeterminate(&mut self) {
+        self.state.set_indeterminate(true);
+    }
+
+    /// Set the text format, where `{percent}`, `{value}`, `{min}` and
+    /// `{max}` are replaced
+    pub fn set_text_format(&mut self, text_format: &str) {
+        self.state.set_text_format(text_format);
+    }
+
+    /// Set the status
+    pub fn set_status(&mut self, status: ProgressStatus) {
+        self.state.set_status(status);
+    }
+
+    /// Set the circular flag to true
+    pub fn set_circular(&mut self) {
+        self.state.set_circular(true);
+    }
+
     // Set the stretched flag to true
     pub fn set_stretched(&mut self) {
         self.state.set_stretched(true);
     }
 
+    /// Get the state
+    pub fn state(&self) -> &ProgressBarState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut ProgressBarState {
+        &mut self.state
+    }
+
     /// Set the listener
     pub fn set_listener(&mut self, listener: Box<dyn ProgressBarListener>) {
         self.listener = Some(listener);
@@ -223,6 +374,11 @@ impl ProgressBar {
     pub fn set_style(&mut self, style: &str) {
         self.state.set_style(style);
     }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
 }
 
 impl Widget for ProgressBar {
@@ -237,18 +393,64 @@ impl Widget for ProgressBar {
             self.name,
             self.state.style(),
         )));
-        let html = format!(
-            r#"
-            <div id="{}" class="progressbar {}">
+        let indeterminate = if self.state.indeterminate() {
+            "indeterminate"
+        } else {
+            ""
+        };
+        let circular = if self.state.circular() {
+            "circular"
+        } else {
+            ""
+        };
+        let percent = if self.state.indeterminate() {
+            25.0
+        } else {
+            self.state.percent()
+        };
+        let bar = if self.state.circular() {
+            format!(
+                r#"
+                <svg viewBox="0 0 36 36">
+                    <circle class="track" cx="18" cy="18" r="16"></circle>
+                    <circle class="arc" cx="18" cy="18" r="16"
+                        pathLength="100" stroke-dasharray="{} 100"
+                        transform="rotate(-90 18 18)"></circle>
+                </svg>
+                "#,
+                percent,
+            )
+        } else {
+            format!(
+                r#"
                 <div class="background"></div>
                 <div class="foreground" style="width: {}%;"></div>
+                "#,
+                percent,
+            )
+        };
+        let text = if self.state.text_format().is_empty() {
+            "".to_string()
+        } else {
+            format!(
+                r#"<div class="progressbar-text">{}</div>"#,
+                self.state.text()
+            )
+        };
+        let html = format!(
+            r#"
+            <div id="{}" class="progressbar {} {} {} {}">
+                {}
+                {}
             </div>
             "#, 
             self.name,
             stretched,
-            f64::from(self.state.value() - self.state.min()) /
-            f64::from(self.state.max() - self.state.min()) *
-            100.0,
+            indeterminate,
+            circular,
+            self.state.status().css(),
+            bar,
+            text,
         );
         format!("{}{}", style, html)
     }
@@ -275,4 +477,12 @@ impl Widget for ProgressBar {
     }
 
     fn on_change(&mut self, _value: &str) {}
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
 }
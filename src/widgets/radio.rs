@@ -1,5 +1,7 @@
 use crate::utils::event::Event;
 use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
+use crate::utils::validation::{Field, FieldValue, Validation, Validator};
 use crate::widgets::widget::Widget;
 
 /// # The state of a Radio
@@ -9,16 +11,20 @@ use crate::widgets::widget::Widget;
 /// ```text
 /// choices: Vec<String>
 /// selected: u32
+/// validation: Validation
 /// disabled: bool
 /// stretched: bool
 /// style: String
+/// tooltip: Option<Tooltip>
 /// ```
 pub struct RadioState {
     choices: Vec<String>,
     selected: u32,
+    validation: Validation,
     disabled: bool,
     stretched: bool,
     style: String,
+    tooltip: Option<Tooltip>,
 }
 
 impl RadioState {
@@ -32,6 +38,11 @@ impl RadioState {
         self.selected
     }
 
+    /// Get the validation
+    pub fn validation(&self) -> &Validation {
+        &self.validation
+    }
+
     /// Get the disabled flag
     pub fn disabled(&self) -> bool {
         self.disabled
@@ -47,6 +58,11 @@ impl RadioState {
         &self.style
     }
 
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
     /// Set the choices
     pub fn set_choices(&mut self, choices: Vec<&str>) {
         self.choices = choices
@@ -74,6 +90,11 @@ impl RadioState {
     pub fn set_style(&mut self, style: &str) {
         self.style = style.to_string();
     }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
 }
 
 /// # The listener of a Radio
@@ -87,7 +108,8 @@ pub trait RadioListener {
 
 /// # A list of radio buttons
 ///
-/// Only one can be selected at a time.
+/// Only one can be selected at a time. The buttons are displayed one below
+/// the other, a RadioGroup lets RadioButtons be placed anywhere in a layout.
 ///
 /// ## Fields
 ///
@@ -104,19 +126,22 @@ pub trait RadioListener {
 /// state:
 ///     choices: vec!["Choice 1".to_string(), "Choice 2".to_string()],
 ///     selected: 0
+///     validation: Validation::new()
 ///     disabled: false
 ///     stretched: false
 ///     style: "".to_string()
+///     tooltip: None
 /// listener: None
 /// ```
 ///
 /// ## Style
 ///
 /// ```text
-/// div.radio[.disabled][.selected]
+/// div.radio[.disabled][.selected][.invalid]
 ///     label
 ///     div.radio-outer
 ///         div.radio-inner
+/// div.validation-error
 /// ```
 ///
 /// ## Example
@@ -207,9 +232,11 @@ impl Radio {
                     "Choice 2".to_string()
                 ],
                 selected: 0,
+                validation: Validation::new(),
                 disabled: false,
                 stretched: false,
                 style: "".to_string(),
+                tooltip: None,
             },
             listener: None,
         }
@@ -235,6 +262,21 @@ impl Radio {
         self.state.set_stretched(true);
     }
 
+    /// Add a validator
+    pub fn add_validator(&mut self, validator: Validator) {
+        self.state.validation.add(validator);
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &RadioState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut RadioState {
+        &mut self.state
+    }
+
     /// Set the listener
     pub fn set_listener(&mut self, listener: Box<dyn RadioListener>) {
         self.listener = Some(listener);
@@ -244,6 +286,11 @@ impl Radio {
     pub fn set_style(&mut self, style: &str) {
         self.state.set_style(style);
     }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
 }
 
 impl Widget for Radio {
@@ -273,7 +320,7 @@ impl Widget for Radio {
             html.push_str(
                 &format!(
                     r#"
-                    <div id="{}" class="radio {} {} {}" onclick="{}">
+                    <div id="{}" class="radio {} {} {} {}" onclick="{}">
                         <div class="radio-outer">
                             <div class="radio-inner"></div>
                         </div>
@@ -284,11 +331,13 @@ impl Widget for Radio {
                     stretched,
                     disabled,
                     selected,
+                    self.state.validation().css(),
                     Event::change_js(&self.name, &format!("'{}'", i)), 
                     choice
                 )
             );
         }
+        html.push_str(&self.state.validation().eval());
         format!("{}{}", style, html)
     }
 
@@ -315,6 +364,7 @@ impl Widget for Radio {
 
     fn on_change(&mut self, value: &str) {
         self.state.set_selected(value.parse::<u32>().unwrap());
+        self.validate();
         match &self.listener {
             None => (),
             Some(listener) => {
@@ -322,4 +372,41 @@ impl Widget for Radio {
             }
         }
     }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+
+    fn field(&self) -> Option<&dyn Field> {
+        Some(self)
+    }
+
+    fn field_mut(&mut self) -> Option<&mut dyn Field> {
+        Some(self)
+    }
+}
+
+impl Field for Radio {
+    fn value(&self) -> FieldValue {
+        FieldValue::Text(
+            self.state
+                .choices()
+                .get(self.state.selected() as usize)
+                .cloned()
+                .unwrap_or_default(),
+        )
+    }
+
+    fn validation(&self) -> &Validation {
+        self.state.validation()
+    }
+
+    fn validate(&mut self) -> bool {
+        let value = self.value();
+        self.state.validation.validate(&value)
+    }
 }
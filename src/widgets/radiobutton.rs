@@ -0,0 +1,388 @@
+use crate::utils::event::Event;
+use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
+use crate::widgets::widget::Widget;
+
+/// # The state of a RadioButton
+///
+/// ## Fields
+///
+/// ```text
+/// group: String
+/// value: String
+/// text: String
+/// description: String
+/// icon: String
+/// checked: bool
+/// disabled: bool
+/// stretched: bool
+/// style: String
+/// tooltip: Option<Tooltip>
+/// ```
+pub struct RadioButtonState {
+    group: String,
+    value: String,
+    text: String,
+    description: String,
+    icon: String,
+    checked: bool,
+    disabled: bool,
+    stretched: bool,
+    style: String,
+    tooltip: Option<Tooltip>,
+}
+
+impl RadioButtonState {
+    /// Get the name of the group
+    pub fn group(&self) -> &str {
+        &self.group
+    }
+
+    /// Get the value
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Get the text
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Get the description
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Get the icon
+    pub fn icon(&self) -> &str {
+        &self.icon
+    }
+
+    /// Get the checked flag
+    pub fn checked(&self) -> bool {
+        self.checked
+    }
+
+    /// Get the disabled flag
+    pub fn disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Get the stretched flag
+    pub fn stretched(&self) -> bool {
+        self.stretched
+    }
+
+    /// Get the style
+    pub fn style(&self) -> &str {
+        &self.style
+    }
+
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
+    /// Set the name of the group
+    pub fn set_group(&mut self, group: &str) {
+        self.group = group.to_string();
+    }
+
+    /// Set the value
+    pub fn set_value(&mut self, value: &str) {
+        self.value = value.to_string();
+    }
+
+    /// Set the text
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.to_string();
+    }
+
+    /// Set the description
+    pub fn set_description(&mut self, description: &str) {
+        self.description = description.to_string();
+    }
+
+    /// Set the icon
+    pub fn set_icon(&mut self, icon: &str) {
+        self.icon = icon.to_string();
+    }
+
+    /// Set the checked flag
+    pub fn set_checked(&mut self, checked: bool) {
+        self.checked = checked;
+    }
+
+    /// Set the disabled flag
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+
+    /// Set the stretched flag
+    pub fn set_stretched(&mut self, stretched: bool) {
+        self.stretched = stretched;
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.style = style.to_string();
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
+}
+
+/// # The listener of a RadioButton
+pub trait RadioButtonListener {
+    /// Function triggered on update event
+    fn on_update(&self, state: &mut RadioButtonState);
+}
+
+/// # A radio button belonging to a RadioGroup
+///
+/// The button can be placed anywhere in the child of the RadioGroup having
+/// the name of its group, which checks it when its value is selected. The
+/// arrow keys move the selection to the previous or next enabled button of
+/// the same group.
+///
+/// ## Fields
+///
+/// ```text
+/// name: String
+/// state: RadioButtonState
+/// listener: Option<Box<dyn RadioButtonListener>>
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// name: name.to_string()
+/// state:
+///     group: "".to_string()
+///     value: name.to_string()
+///     text: "Radio Button".to_string()
+///     description: "".to_string()
+///     icon: "".to_string()
+///     checked: false
+///     disabled: false
+///     stretched: false
+///     style: "".to_string()
+///     tooltip: None
+/// listener: None
+/// ```
+///
+/// ## Style
+///
+/// ```text
+/// div.radio.radio-button[.selected][.disabled]
+///     div.radio-outer
+///         div.radio-inner
+///     div.radio-button-icon
+///     div.radio-button-text
+///         label
+///         div.radio-button-description
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use neutrino::widgets::radiobutton::RadioButton;
+///
+///
+/// fn main() {
+///     let mut my_radiobutton = RadioButton::new("my_radiobutton");
+///     my_radiobutton.set_group("my_radiogroup");
+///     my_radiobutton.set_value("express");
+///     my_radiobutton.set_text("Express delivery");
+///     my_radiobutton.set_description("Delivered tomorrow");
+/// }
+/// ```
+pub struct RadioButton {
+    name: String,
+    state: RadioButtonState,
+    listener: Option<Box<dyn RadioButtonListener>>,
+}
+
+impl RadioButton {
+    /// Create a RadioButton
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            state: RadioButtonState {
+                group: "".to_string(),
+                value: name.to_string(),
+                text: "Radio Button".to_string(),
+                description: "".to_string(),
+                icon: "".to_string(),
+                checked: false,
+                disabled: false,
+                stretched: false,
+                style: "".to_string(),
+                tooltip: None,
+            },
+            listener: None,
+        }
+    }
+
+    /// Set the name of the group
+    pub fn set_group(&mut self, group: &str) {
+        self.state.set_group(group);
+    }
+
+    /// Set the value
+    pub fn set_value(&mut self, value: &str) {
+        self.state.set_value(value);
+    }
+
+    /// Set the text
+    pub fn set_text(&mut self, text: &str) {
+        self.state.set_text(text);
+    }
+
+    /// Set the description
+    pub fn set_description(&mut self, description: &str) {
+        self.state.set_description(description);
+    }
+
+    /// Set the icon
+    pub fn set_icon(&mut self, icon: &str) {
+        self.state.set_icon(icon);
+    }
+
+    /// Set the disabled flag to true
+    pub fn set_disabled(&mut self) {
+        self.state.set_disabled(true);
+    }
+
+    /// Set the stretched flag to true
+    pub fn set_stretched(&mut self) {
+        self.state.set_stretched(true);
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &RadioButtonState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut RadioButtonState {
+        &mut self.state
+    }
+
+    /// Set the listener
+    pub fn set_listener(&mut self, listener: Box<dyn RadioButtonListener>) {
+        self.listener = Some(listener);
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.state.set_style(style);
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
+}
+
+impl Widget for RadioButton {
+    fn eval(&self) -> String {
+        let stretched = if self.state.stretched() {
+            "stretched"
+        } else {
+            ""
+        };
+        let disabled = if self.state.disabled() {
+            "disabled"
+        } else {
+            ""
+        };
+        let selected = if self.state.checked() { "selected" } else { "" };
+        let (tabindex, onclick) = if self.state.disabled() {
+            ("-1".to_string(), "".to_string())
+        } else {
+            (
+                "0".to_string(),
+                Event::change_js(
+                    self.state.group(),
+                    &format!("'{}'", self.state.value()),
+                ),
+            )
+        };
+        let icon = if self.state.icon().is_empty() {
+            "".to_string()
+        } else {
+            format!(
+                r#"<div class="radio-button-icon">{}</div>"#,
+                self.state.icon()
+            )
+        };
+        let description = if self.state.description().is_empty() {
+            "".to_string()
+        } else {
+            format!(
+                r#"<div class="radio-button-description">{}</div>"#,
+                self.state.description()
+            )
+        };
+        let style = inline_style(&scss_to_css(&format!(
+            r##"#{}{{{}}}"##,
+            self.name,
+            self.state.style(),
+        )));
+        let html = format!(
+            r#"
+            <div id="{}" class="radio radio-button {} {} {}" data-group="{}"
+                tabindex="{}" onclick="{}" onkeydown="cycle(event)"
+            >
+                <div class="radio-outer">
+                    <div class="radio-inner"></div>
+                </div>
+                {}
+                <div class="radio-button-text">
+                    <label>{}</label>
+                    {}
+                </div>
+            </div>
+            "#,
+            self.name,
+            stretched,
+            disabled,
+            selected,
+            self.state.group(),
+            tabindex,
+            onclick,
+            icon,
+            self.state.text(),
+            description,
+        );
+        format!("{}{}", style, html)
+    }
+
+    fn trigger(&mut self, event: &Event) {
+        if let Event::Update = event {
+            self.on_update()
+        }
+    }
+
+    fn on_update(&mut self) {
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_update(&mut self.state);
+            }
+        }
+    }
+
+    fn on_change(&mut self, _value: &str) {}
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+}
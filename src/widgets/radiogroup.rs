@@ -0,0 +1,455 @@
+use crate::utils::event::Event;
+use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
+use crate::utils::validation::{Field, FieldValue, Validation, Validator};
+use crate::widgets::radiobutton::RadioButton;
+use crate::widgets::widget::{find_as, find_as_mut, Widget};
+
+/// # The state of a RadioGroup
+///
+/// ## Fields
+///
+/// ```text
+/// child: Option<Box<dyn Widget>>
+/// selected: Option<String>
+/// validation: Validation
+/// stretched: bool
+/// style: String
+/// tooltip: Option<Tooltip>
+/// ```
+pub struct RadioGroupState {
+    child: Option<Box<dyn Widget>>,
+    selected: Option<String>,
+    validation: Validation,
+    stretched: bool,
+    style: String,
+    tooltip: Option<Tooltip>,
+}
+
+impl RadioGroupState {
+    /// Get the child
+    pub fn child(&self) -> Option<&dyn Widget> {
+        self.child.as_ref().map(|child| child.as_ref())
+    }
+
+    /// Get the value of the selected button
+    pub fn selected(&self) -> Option<&str> {
+        self.selected.as_ref().map(String::as_ref)
+    }
+
+    /// Get the validation
+    pub fn validation(&self) -> &Validation {
+        &self.validation
+    }
+
+    /// Get the stretched flag
+    pub fn stretched(&self) -> bool {
+        self.stretched
+    }
+
+    /// Get the style
+    pub fn style(&self) -> &str {
+        &self.style
+    }
+
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
+    /// Set the child
+    pub fn set_child(&mut self, child: Box<dyn Widget>) {
+        self.child = Some(child);
+    }
+
+    /// Set the value of the selected button, None selecting no button
+    pub fn set_selected(&mut self, selected: Option<&str>) {
+        self.selected = selected.map(|value| value.to_string());
+    }
+
+    /// Set the stretched flag
+    pub fn set_stretched(&mut self, stretched: bool) {
+        self.stretched = stretched;
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.style = style.to_string();
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
+}
+
+/// Find the button of a group having the given value in a widget tree
+fn find_button<'a>(
+    widget: &'a dyn Widget,
+    group: &str,
+    value: &str,
+) -> Option<&'a RadioButton> {
+    if let Some(button) = widget.as_any().downcast_ref::<RadioButton>() {
+        let state = button.state();
+        if state.group() == group && state.value() == value {
+            return Some(button);
+        }
+    }
+    for child in widget.children() {
+        if let Some(button) = find_button(child, group, value) {
+            return Some(button);
+        }
+    }
+    None
+}
+
+/// Check the buttons of a group in a widget tree according to the selected
+/// value
+fn check_buttons(widget: &mut dyn Widget, group: &str, selected: Option<&str>) {
+    if let Some(button) = widget.as_any_mut().downcast_mut::<RadioButton>() {
+        if button.state().group() == group {
+            let checked = selected == Some(button.state().value());
+            button.state_mut().set_checked(checked);
+        }
+    }
+    for child in widget.children_mut() {
+        check_buttons(child, group, selected);
+    }
+}
+
+/// # The listener of a RadioGroup
+pub trait RadioGroupListener {
+    /// Function triggered on update event
+    fn on_update(&self, state: &mut RadioGroupState);
+
+    /// Function triggered on change event
+    fn on_change(&self, state: &RadioGroupState);
+}
+
+/// # A group of radio buttons placed anywhere in a layout
+///
+/// The RadioGroup displays its child and keeps the selection of the
+/// RadioButtons it contains whose group is the name of the RadioGroup. Only
+/// one of them can be selected at a time and none is selected at first. A
+/// disabled button cannot be selected.
+///
+/// ## Fields
+///
+/// ```text
+/// name: String
+/// state: RadioGroupState
+/// listener: Option<Box<dyn RadioGroupListener>>
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// name: name.to_string()
+/// state:
+///     child: None
+///     selected: None
+///     validation: Validation::new()
+///     stretched: false
+///     style: "".to_string()
+///     tooltip: None
+/// listener: None
+/// ```
+///
+/// ## Style
+///
+/// ```text
+/// div.radio-group[.invalid]
+/// div.validation-error
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use neutrino::widgets::container::{Container, Direction};
+/// use neutrino::widgets::radiobutton::RadioButton;
+/// use neutrino::widgets::radiogroup::{
+///     RadioGroup,
+///     RadioGroupListener,
+///     RadioGroupState
+/// };
+///
+///
+/// struct Delivery {
+///     mode: Option<String>,
+/// }
+///
+/// impl Delivery {
+///     fn new() -> Self {
+///         Self { mode: None }
+///     }
+///
+///     fn mode(&self) -> Option<&str> {
+///         self.mode.as_ref().map(String::as_ref)
+///     }
+///
+///     fn set_mode(&mut self, mode: Option<&str>) {
+///         self.mode = mode.map(|mode| mode.to_string());
+///     }
+/// }
+///
+///
+/// struct MyRadioGroupListener {
+///     delivery: Rc<RefCell<Delivery>>,
+/// }
+///
+/// impl MyRadioGroupListener {
+///    pub fn new(delivery: Rc<RefCell<Delivery>>) -> Self {
+///        Self { delivery }
+///    }
+/// }
+///
+/// impl RadioGroupListener for MyRadioGroupListener {
+///     fn on_change(&self, state: &RadioGroupState) {
+///         self.delivery.borrow_mut().set_mode(state.selected());
+///     }
+///
+///     fn on_update(&self, state: &mut RadioGroupState) {
+///         state.set_selected(self.delivery.borrow().mode());
+///     }
+/// }
+///
+///
+/// fn main() {
+///     let delivery = Rc::new(RefCell::new(Delivery::new()));
+///
+///     let my_listener = MyRadioGroupListener::new(Rc::clone(&delivery));
+///
+///     let mut my_standard = RadioButton::new("my_standard");
+///     my_standard.set_group("my_radiogroup");
+///     my_standard.set_text("Standard");
+///
+///     let mut my_express = RadioButton::new("my_express");
+///     my_express.set_group("my_radiogroup");
+///     my_express.set_text("Express");
+///     my_express.set_description("Delivered tomorrow");
+///
+///     let mut my_container = Container::new("my_container");
+///     my_container.set_direction(Direction::Horizontal);
+///     my_container.add(Box::new(my_standard));
+///     my_container.add(Box::new(my_express));
+///
+///     let mut my_radiogroup = RadioGroup::new("my_radiogroup");
+///     my_radiogroup.set_child(Box::new(my_container));
+///     my_radiogroup.set_listener(Box::new(my_listener));
+/// }
+/// ```
+pub struct RadioGroup {
+    name: String,
+    state: RadioGroupState,
+    listener: Option<Box<dyn RadioGroupListener>>,
+}
+
+impl RadioGroup {
+    /// Create a RadioGroup
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            state: RadioGroupState {
+                child: None,
+                selected: None,
+                validation: Validation::new(),
+                stretched: false,
+                style: "".to_string(),
+                tooltip: None,
+            },
+            listener: None,
+        }
+    }
+
+    /// Set the child
+    pub fn set_child(&mut self, child: Box<dyn Widget>) {
+        self.state.set_child(child);
+        self.check();
+    }
+
+    /// Set the value of the selected button
+    pub fn set_selected(&mut self, selected: &str) {
+        self.state.set_selected(Some(selected));
+        self.check();
+    }
+
+    /// Set the stretched flag to true
+    pub fn set_stretched(&mut self) {
+        self.state.set_stretched(true);
+    }
+
+    /// Add a validator
+    pub fn add_validator(&mut self, validator: Validator) {
+        self.state.validation.add(validator);
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &RadioGroupState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut RadioGroupState {
+        &mut self.state
+    }
+
+    /// Set the listener
+    pub fn set_listener(&mut self, listener: Box<dyn RadioGroupListener>) {
+        self.listener = Some(listener);
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.state.set_style(style);
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
+
+    /// Find a widget by name in the group and downcast it to the type T
+    pub fn find<T: Widget + 'static>(&self, name: &str) -> Option<&T> {
+        find_as(self, name)
+    }
+
+    /// Find a mutable widget by name in the group and downcast it to the
+    /// type T
+    pub fn find_mut<T: Widget + 'static>(
+        &mut self,
+        name: &str,
+    ) -> Option<&mut T> {
+        find_as_mut(self, name)
+    }
+
+    /// Check the buttons of the group according to the selected value
+    fn check(&mut self) {
+        if let Some(child) = &mut self.state.child {
+            check_buttons(
+                child.as_mut(),
+                &self.name,
+                self.state.selected.as_ref().map(String::as_ref),
+            );
+        }
+    }
+}
+
+impl Widget for RadioGroup {
+    fn eval(&self) -> String {
+        let stretched = if self.state.stretched() {
+            "stretched"
+        } else {
+            ""
+        };
+        let style = inline_style(&scss_to_css(&format!(
+            r##"#{}{{{}}}"##,
+            self.name,
+            self.state.style(),
+        )));
+        let html = format!(
+            r#"<div id="{}" class="radio-group {} {}">{}</div>{}"#,
+            self.name,
+            stretched,
+            self.state.validation().css(),
+            match &self.state.child {
+                Some(child) => child.eval(),
+                None => "".to_string(),
+            },
+            self.state.validation().eval(),
+        );
+        format!("{}{}", style, html)
+    }
+
+    fn trigger(&mut self, event: &Event) {
+        if let Some(child) = &mut self.state.child {
+            child.trigger(event);
+        }
+        match event {
+            Event::Update => self.on_update(),
+            Event::Change { source, value } => {
+                if source == &self.name {
+                    self.on_change(value)
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn on_update(&mut self) {
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_update(&mut self.state);
+            }
+        }
+        self.check();
+    }
+
+    fn on_change(&mut self, value: &str) {
+        let button = match &self.state.child {
+            Some(child) => find_button(child.as_ref(), &self.name, value),
+            None => None,
+        };
+        match button {
+            Some(button) if !button.state().disabled() => (),
+            _ => return,
+        }
+        self.state.set_selected(Some(value));
+        self.check();
+        self.validate();
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_change(&self.state);
+            }
+        }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+
+    fn children(&self) -> Vec<&dyn Widget> {
+        match &self.state.child {
+            Some(child) => vec![child.as_ref()],
+            None => vec![],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Widget> {
+        match &mut self.state.child {
+            Some(child) => vec![child.as_mut()],
+            None => vec![],
+        }
+    }
+
+    fn field(&self) -> Option<&dyn Field> {
+        Some(self)
+    }
+
+    fn field_mut(&mut self) -> Option<&mut dyn Field> {
+        Some(self)
+    }
+}
+
+impl Field for RadioGroup {
+    fn value(&self) -> FieldValue {
+        FieldValue::Text(self.state.selected().unwrap_or("").to_string())
+    }
+
+    fn validation(&self) -> &Validation {
+        self.state.validation()
+    }
+
+    fn validate(&mut self) -> bool {
+        let value = self.value();
+        self.state.validation.validate(&value)
+    }
+}
@@ -1,5 +1,7 @@
 use crate::utils::event::Event;
 use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
+use crate::utils::validation::{Field, FieldValue, Validation, Validator};
 use crate::widgets::widget::Widget;
 
 /// # The state of a Range
@@ -7,38 +9,87 @@ use crate::widgets::widget::Widget;
 ///  ## Fields
 ///
 /// ```text
-/// min: i32
-/// max: i32
-/// value: i32
+/// min: f64
+/// max: f64
+/// value: f64
+/// high: f64
+/// step: f64
+/// ticks: Vec<(f64, String)>
+/// vertical: bool
+/// dual: bool
+/// validation: Validation
 /// disabled: bool
 /// stretched: bool
 /// style: String
+/// tooltip: Option<Tooltip>
 /// ```
 pub struct RangeState {
-    min: i32,
-    max: i32,
-    value: i32,
+    min: f64,
+    max: f64,
+    value: f64,
+    high: f64,
+    step: f64,
+    ticks: Vec<(f64, String)>,
+    vertical: bool,
+    dual: bool,
+    validation: Validation,
     disabled: bool,
     stretched: bool,
     style: String,
+    tooltip: Option<Tooltip>,
 }
 
 impl RangeState {
     /// Get the min
-    pub fn min(&self) -> i32 {
+    pub fn min(&self) -> f64 {
         self.min
     }
 
     /// Get the max
-    pub fn max(&self) -> i32 {
+    pub fn max(&self) -> f64 {
         self.max
     }
 
-    /// Get the value
-    pub fn value(&self) -> i32 {
+    /// Get the value, which is the low value in dual mode
+    pub fn value(&self) -> f64 {
         self.value
     }
 
+    /// Get the high value of the dual mode
+    pub fn high(&self) -> f64 {
+        self.high
+    }
+
+    /// Get the low and the high values of the dual mode
+    pub fn selection(&self) -> (f64, f64) {
+        (self.value, self.high)
+    }
+
+    /// Get the step
+    pub fn step(&self) -> f64 {
+        self.step
+    }
+
+    /// Get the ticks, made of a value and a label
+    pub fn ticks(&self) -> &Vec<(f64, String)> {
+        &self.ticks
+    }
+
+    /// Get the vertical flag
+    pub fn vertical(&self) -> bool {
+        self.vertical
+    }
+
+    /// Get the dual flag
+    pub fn dual(&self) -> bool {
+        self.dual
+    }
+
+    /// Get the validation
+    pub fn validation(&self) -> &Validation {
+        &self.validation
+    }
+
     /// Get the disabled flag
     pub fn disabled(&self) -> bool {
         self.disabled
@@ -54,19 +105,73 @@ impl RangeState {
         &self.style
     }
 
-    /// Set the min
-    pub fn set_min(&mut self, min: i32) {
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
+    /// Set the min, the values being kept above it
+    pub fn set_min(&mut self, min: f64) {
         self.min = min;
+        self.set_selection(self.value, self.high);
     }
 
-    /// Set the max
-    pub fn set_max(&mut self, max: i32) {
+    /// Set the max, the values being kept below it
+    pub fn set_max(&mut self, max: f64) {
         self.max = max;
+        self.set_selection(self.value, self.high);
+    }
+
+    /// Set the value, which is the low value in dual mode
+    ///
+    /// The value is kept between the min and the max, and below the high
+    /// value in dual mode.
+    pub fn set_value(&mut self, value: f64) {
+        self.value = self.clamp(value);
+        if self.dual && self.value > self.high {
+            self.value = self.high;
+        }
+    }
+
+    /// Set the high value of the dual mode
+    ///
+    /// The value is kept between the min and the max, and above the low
+    /// value.
+    pub fn set_high(&mut self, high: f64) {
+        self.high = self.clamp(high).max(self.value);
+    }
+
+    /// Set the low and the high values of the dual mode
+    ///
+    /// The values are kept between the min and the max, and the low value is
+    /// kept below the high value in dual mode.
+    pub fn set_selection(&mut self, low: f64, high: f64) {
+        self.value = self.clamp(low);
+        self.high = self.clamp(high);
+        if self.dual && self.value > self.high {
+            self.value = self.high;
+        }
+    }
+
+    /// Set the step
+    pub fn set_step(&mut self, step: f64) {
+        self.step = step;
+    }
+
+    /// Set the ticks, made of a value and a label
+    pub fn set_ticks(&mut self, ticks: Vec<(f64, String)>) {
+        self.ticks = ticks;
     }
 
-    /// Set the value
-    pub fn set_value(&mut self, value: i32) {
-        self.value = value;
+    /// Set the vertical flag
+    pub fn set_vertical(&mut self, vertical: bool) {
+        self.vertical = vertical;
+    }
+
+    /// Set the dual flag, the low value being kept below the high value
+    pub fn set_dual(&mut self, dual: bool) {
+        self.dual = dual;
+        self.set_selection(self.value, self.high);
     }
 
     /// Set the disabled flag
@@ -83,6 +188,25 @@ impl RangeState {
     pub fn set_style(&mut self, style: &str) {
         self.style = style.to_string();
     }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
+
+    /// Keep a value between the min and the max
+    fn clamp(&self, value: f64) -> f64 {
+        value.max(self.min).min(self.max)
+    }
+
+    /// Get the position of a value between the min and the max, in percent
+    fn percent(&self, value: f64) -> f64 {
+        if self.max > self.min {
+            (value - self.min) / (self.max - self.min) * 100.0
+        } else {
+            0.0
+        }
+    }
 }
 
 /// # The listener of a Range
@@ -91,11 +215,17 @@ pub trait RangeListener {
     fn on_update(&self, state: &mut RangeState);
 
     /// Function triggered on change event
+    ///
+    /// In dual mode, the selection of the state holds the low and the high
+    /// values.
     fn on_change(&self, state: &RangeState);
 }
 
 /// # A progress bar with a handle
 ///
+/// In dual mode, a second handle selects a range of values between the
+/// value and the high value.
+///
 /// ## Fields
 ///
 /// ```text
@@ -109,24 +239,37 @@ pub trait RangeListener {
 /// ```text
 /// name: name.to_string()
 /// state:
-///     min: 0
-///     max: 100
-///     value: 0
+///     min: 0.0
+///     max: 100.0
+///     value: 0.0
+///     high: 100.0
+///     step: 1.0
+///     ticks: vec![]
+///     vertical: false
+///     dual: false
+///     validation: Validation::new()
 ///     disabled: false
 ///     stretched: false
 ///     style: "".to_string()
+///     tooltip: None
 /// listener: None
 /// ```
 ///
 /// ## Style
 ///
 /// ```text
-/// div.range[.disabled]
-///     div.inner-range
-///         ::-webkit-slider-runnable-track
-///         ::-webkit-slider-thumb
-///         ::-ms-track
-///         ::-ms-thumb
+/// div.range[.disabled][.invalid][.vertical][.dual][.with-ticks]
+///     div.range-slider
+///         input.inner-range[.range-high]
+///             ::-webkit-slider-runnable-track
+///             ::-webkit-slider-thumb
+///             ::-ms-track
+///             ::-ms-thumb
+///         div.range-ticks
+///             div.range-tick
+///                 div.range-tick-mark
+///                 div.range-tick-label
+///     div.validation-error
 /// ```
 ///
 /// ## Example
@@ -141,19 +284,19 @@ pub trait RangeListener {
 ///
 ///
 /// struct Counter {
-///     value: i32,
+///     value: f64,
 /// }
 ///
 /// impl Counter {
 ///     fn new() -> Self {
-///         Self { value: 0 }
+///         Self { value: 0.0 }
 ///     }
 ///
-///     fn value(&self) -> i32 {
+///     fn value(&self) -> f64 {
 ///         self.value
 ///     }
 ///
-///     fn set_value(&mut self, value: i32) {
+///     fn set_value(&mut self, value: f64) {
 ///         self.value = value;
 ///     }
 /// }
@@ -186,6 +329,9 @@ pub trait RangeListener {
 ///     let my_listener = MyRangeListener::new(Rc::clone(&counter));
 ///
 ///     let mut my_range = Range::new("my_range");
+///     my_range.set_step(0.5);
+///     my_range.add_tick(0.0, "Min");
+///     my_range.add_tick(100.0, "Max");
 ///     my_range.set_listener(Box::new(my_listener));
 /// }
 /// ```
@@ -201,32 +347,64 @@ impl Range {
         Self {
             name: name.to_string(),
             state: RangeState {
-                min: 0,
-                max: 100,
-                value: 0,
+                min: 0.0,
+                max: 100.0,
+                value: 0.0,
+                high: 100.0,
+                step: 1.0,
+                ticks: vec![],
+                vertical: false,
+                dual: false,
+                validation: Validation::new(),
                 disabled: false,
                 stretched: false,
                 style: "".to_string(),
+                tooltip: None,
             },
             listener: None,
         }
     }
 
-    /// Set the min
-    pub fn set_min(&mut self, min: i32) {
+    /// Set the min, the values being kept above it
+    pub fn set_min(&mut self, min: f64) {
         self.state.set_min(min);
     }
 
-    /// Set the max
-    pub fn set_max(&mut self, max: i32) {
+    /// Set the max, the values being kept below it
+    pub fn set_max(&mut self, max: f64) {
         self.state.set_max(max);
     }
 
-    /// Set the value
-    pub fn set_value(&mut self, value: i32) {
+    /// Set the value, kept between the min and the max
+    pub fn set_value(&mut self, value: f64) {
         self.state.set_value(value);
     }
 
+    /// Set the low and the high values of the dual mode
+    pub fn set_selection(&mut self, low: f64, high: f64) {
+        self.state.set_selection(low, high);
+    }
+
+    /// Set the step
+    pub fn set_step(&mut self, step: f64) {
+        self.state.set_step(step);
+    }
+
+    /// Add a tick mark with a label at the given value
+    pub fn add_tick(&mut self, value: f64, label: &str) {
+        self.state.ticks.push((value, label.to_string()));
+    }
+
+    /// Set the vertical flag to true
+    pub fn set_vertical(&mut self) {
+        self.state.set_vertical(true);
+    }
+
+    /// Set the dual flag to true
+    pub fn set_dual(&mut self) {
+        self.state.set_dual(true);
+    }
+
     /// Set the disabled flag to true
     pub fn set_disabled(&mut self) {
         self.state.set_disabled(true);
@@ -237,6 +415,21 @@ impl Range {
         self.state.set_stretched(true);
     }
 
+    /// Add a validator
+    pub fn add_validator(&mut self, validator: Validator) {
+        self.state.validation.add(validator);
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &RangeState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut RangeState {
+        &mut self.state
+    }
+
     /// Set the listener
     pub fn set_listener(&mut self, listener: Box<dyn RangeListener>) {
         self.listener = Some(listener);
@@ -246,6 +439,65 @@ impl Range {
     pub fn set_style(&mut self, style: &str) {
         self.state.set_style(style);
     }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
+
+    /// Return the HTML representation of a handle
+    fn eval_handle(&self, class: &str, kind: &str, value: f64) -> String {
+        let disabled = if self.state.disabled() {
+            "disabled"
+        } else {
+            ""
+        };
+        let change =
+            Event::change_js(&self.name, &format!("'{};' + value", kind));
+        format!(
+            r#"
+            <input {} onchange="{}" oninput="{}" type="range" min="{}"
+                max="{}" step="{}" value="{}" class="inner-range {}"
+            >
+            "#,
+            disabled,
+            change,
+            change,
+            self.state.min(),
+            self.state.max(),
+            self.state.step(),
+            value,
+            class,
+        )
+    }
+
+    /// Return the HTML representation of the ticks
+    fn eval_ticks(&self) -> String {
+        if self.state.ticks().is_empty() {
+            return "".to_string();
+        }
+        let side = if self.state.vertical() {
+            "bottom"
+        } else {
+            "left"
+        };
+        let mut html = r#"<div class="range-ticks">"#.to_string();
+        for (value, label) in self.state.ticks() {
+            html.push_str(&format!(
+                r#"
+                <div class="range-tick" style="{}: {}%;">
+                    <div class="range-tick-mark"></div>
+                    <div class="range-tick-label">{}</div>
+                </div>
+                "#,
+                side,
+                self.state.percent(*value),
+                label,
+            ));
+        }
+        html.push_str("</div>");
+        html
+    }
 }
 
 impl Widget for Range {
@@ -260,6 +512,22 @@ impl Widget for Range {
         } else {
             ""
         };
+        let vertical = if self.state.vertical() {
+            "vertical"
+        } else {
+            ""
+        };
+        let dual = if self.state.dual() { "dual" } else { "" };
+        let ticks = if self.state.ticks().is_empty() {
+            ""
+        } else {
+            "with-ticks"
+        };
+        let high = if self.state.dual() {
+            self.eval_handle("range-high", "high", self.state.high())
+        } else {
+            "".to_string()
+        };
         let style = inline_style(&scss_to_css(&format!(
             r##"#{}{{{}}}"##,
             self.name,
@@ -267,21 +535,26 @@ impl Widget for Range {
         )));
         let html = format!(
             r#"
-            <div id="{}" class="range {} {}">
-                <input {} onchange="{}" oninput="{}" type="range" 
-                    min="{}" max="{}" value="{}" class="inner-range"
-                >
+            <div id="{}" class="range {} {} {} {} {} {}">
+                <div class="range-slider">
+                    {}
+                    {}
+                    {}
+                </div>
+                {}
             </div>
             "#, 
             self.name,
             disabled,
             stretched,
-            disabled,
-            Event::change_js(&self.name, "value"),
-            Event::change_js(&self.name, "value"),
-            self.state.min(),
-            self.state.max(),
-            self.state.value(),
+            self.state.validation().css(),
+            vertical,
+            dual,
+            ticks,
+            self.eval_handle("", "low", self.state.value()),
+            high,
+            self.eval_ticks(),
+            self.state.validation().eval(),
         );
         format!("{}{}", style, html)
     }
@@ -308,7 +581,23 @@ impl Widget for Range {
     }
 
     fn on_change(&mut self, value: &str) {
-        self.state.set_value(value.parse::<i32>().unwrap());
+        let values = value.split(';').collect::<Vec<&str>>();
+        let (kind, value) = match values.as_slice() {
+            [kind, value] => match value.parse::<f64>() {
+                Ok(value) => (*kind, value),
+                Err(_) => return,
+            },
+            _ => return,
+        };
+        match kind {
+            "low" if self.state.dual() => {
+                self.state.value = value.min(self.state.high);
+            }
+            "low" => self.state.value = value,
+            "high" => self.state.high = value.max(self.state.value),
+            _ => return,
+        }
+        self.validate();
         match &self.listener {
             None => (),
             Some(listener) => {
@@ -316,4 +605,69 @@ impl Widget for Range {
             }
         }
     }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+
+    fn field(&self) -> Option<&dyn Field> {
+        Some(self)
+    }
+
+    fn field_mut(&mut self) -> Option<&mut dyn Field> {
+        Some(self)
+    }
+}
+
+impl Field for Range {
+    fn value(&self) -> FieldValue {
+        FieldValue::Number(self.state.value())
+    }
+
+    fn validation(&self) -> &Validation {
+        self.state.validation()
+    }
+
+    fn validate(&mut self) -> bool {
+        let value = self.value();
+        self.state.validation.validate(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn setters_keep_the_values_between_the_min_and_the_max() {
+        let mut range = Range::new("range");
+        range.set_value(150.0);
+        assert_eq!(range.state.value(), 100.0);
+        range.set_value(-5.0);
+        assert_eq!(range.state.value(), 0.0);
+        range.set_value(50.0);
+        range.set_min(60.0);
+        assert_eq!(range.state.value(), 60.0);
+        range.set_max(80.0);
+        assert_eq!(range.state.selection(), (60.0, 80.0));
+    }
+
+    #[test]
+    fn setters_keep_the_low_value_below_the_high_value_in_dual_mode() {
+        let mut range = Range::new("range");
+        range.set_selection(70.0, 30.0);
+        range.set_dual();
+        assert_eq!(range.state.selection(), (30.0, 30.0));
+        range.set_selection(40.0, 120.0);
+        assert_eq!(range.state.selection(), (40.0, 100.0));
+        range.state.set_value(90.0);
+        range.state.set_high(20.0);
+        assert_eq!(range.state.selection(), (90.0, 90.0));
+        range.state.set_value(95.0);
+        assert_eq!(range.state.value(), 90.0);
+    }
 }
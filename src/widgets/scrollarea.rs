@@ -0,0 +1,423 @@
+use crate::utils::event::Event;
+use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
+use crate::widgets::widget::{find_as, find_as_mut, Widget};
+
+/// # The state of a ScrollArea
+///
+/// ## Fields
+///
+/// ```text
+/// child: Option<Box<dyn Widget>>
+/// horizontal: bool
+/// vertical: bool
+/// scroll_x: u32
+/// scroll_y: u32
+/// target: Option<String>
+/// request: u32
+/// stretched: bool
+/// style: String
+/// tooltip: Option<Tooltip>
+/// ```
+pub struct ScrollAreaState {
+    child: Option<Box<dyn Widget>>,
+    horizontal: bool,
+    vertical: bool,
+    scroll_x: u32,
+    scroll_y: u32,
+    target: Option<String>,
+    request: u32,
+    stretched: bool,
+    style: String,
+    tooltip: Option<Tooltip>,
+}
+
+impl ScrollAreaState {
+    /// Get the child
+    pub fn child(&self) -> Option<&dyn Widget> {
+        self.child.as_ref().map(|child| child.as_ref())
+    }
+
+    /// Get the horizontal scrolling flag
+    pub fn horizontal(&self) -> bool {
+        self.horizontal
+    }
+
+    /// Get the vertical scrolling flag
+    pub fn vertical(&self) -> bool {
+        self.vertical
+    }
+
+    /// Get the horizontal scroll offset
+    pub fn scroll_x(&self) -> u32 {
+        self.scroll_x
+    }
+
+    /// Get the vertical scroll offset
+    pub fn scroll_y(&self) -> u32 {
+        self.scroll_y
+    }
+
+    /// Get the name of the widget to scroll to
+    pub fn target(&self) -> Option<&str> {
+        self.target.as_ref().map(String::as_ref)
+    }
+
+    /// Get the stretched flag
+    pub fn stretched(&self) -> bool {
+        self.stretched
+    }
+
+    /// Get the style
+    pub fn style(&self) -> &str {
+        &self.style
+    }
+
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
+    /// Set the child
+    pub fn set_child(&mut self, child: Box<dyn Widget>) {
+        self.child = Some(child);
+    }
+
+    /// Set the horizontal scrolling flag
+    pub fn set_horizontal(&mut self, horizontal: bool) {
+        self.horizontal = horizontal;
+    }
+
+    /// Set the vertical scrolling flag
+    pub fn set_vertical(&mut self, vertical: bool) {
+        self.vertical = vertical;
+    }
+
+    /// Scroll to the given offsets
+    pub fn scroll_to(&mut self, x: u32, y: u32) {
+        self.scroll_x = x;
+        self.scroll_y = y;
+        self.target = None;
+        self.request += 1;
+    }
+
+    /// Scroll until the widget having the given name is visible
+    pub fn scroll_to_child(&mut self, name: &str) {
+        self.target = Some(name.to_string());
+        self.request += 1;
+    }
+
+    /// Set the stretched flag
+    pub fn set_stretched(&mut self, stretched: bool) {
+        self.stretched = stretched;
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.style = style.to_string();
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
+}
+
+/// # The listener of a ScrollArea
+pub trait ScrollAreaListener {
+    /// Function triggered on update event
+    fn on_update(&self, state: &mut ScrollAreaState);
+
+    /// Function triggered on change event
+    fn on_change(&self, state: &ScrollAreaState);
+}
+
+/// # A scrollable area displaying a child
+///
+/// The scroll position is kept when the widget tree is rendered again.
+///
+/// ## Fields
+///
+/// ```text
+/// name: String
+/// state: ScrollAreaState
+/// listener: Option<Box<dyn ScrollAreaListener>>
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// name: name.to_string()
+/// state:
+///     child: None
+///     horizontal: false
+///     vertical: true
+///     scroll_x: 0
+///     scroll_y: 0
+///     target: None
+///     request: 0
+///     stretched: false
+///     style: "".to_string()
+///     tooltip: None
+/// listener: None
+/// ```
+///
+/// ## Style
+///
+/// ```text
+/// div.scrollarea[.scroll-horizontal][.scroll-vertical]
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use neutrino::widgets::label::Label;
+/// use neutrino::widgets::scrollarea::{
+///     ScrollArea,
+///     ScrollAreaListener,
+///     ScrollAreaState
+/// };
+///
+///
+/// struct Document {
+///     position: u32,
+/// }
+///
+/// impl Document {
+///     fn new() -> Self {
+///         Self { position: 0 }
+///     }
+///
+///     fn set_position(&mut self, position: u32) {
+///         self.position = position;
+///     }
+/// }
+///
+///
+/// struct MyScrollAreaListener {
+///     document: Rc<RefCell<Document>>,
+/// }
+///
+/// impl MyScrollAreaListener {
+///    pub fn new(document: Rc<RefCell<Document>>) -> Self {
+///        Self { document }
+///    }
+/// }
+///
+/// impl ScrollAreaListener for MyScrollAreaListener {
+///     fn on_change(&self, state: &ScrollAreaState) {
+///         self.document.borrow_mut().set_position(state.scroll_y());
+///     }
+///
+///     fn on_update(&self, _state: &mut ScrollAreaState) {}
+/// }
+///
+///
+/// fn main() {
+///     let document = Rc::new(RefCell::new(Document::new()));
+///
+///     let my_listener = MyScrollAreaListener::new(Rc::clone(&document));
+///
+///     let mut my_label = Label::new("my_label");
+///     my_label.set_text("A very long text");
+///
+///     let mut my_scrollarea = ScrollArea::new("my_scrollarea");
+///     my_scrollarea.set_child(Box::new(my_label));
+///     my_scrollarea.set_scrolling(true, true);
+///     my_scrollarea.set_listener(Box::new(my_listener));
+/// }
+/// ```
+pub struct ScrollArea {
+    name: String,
+    state: ScrollAreaState,
+    listener: Option<Box<dyn ScrollAreaListener>>,
+}
+
+impl ScrollArea {
+    /// Create a ScrollArea
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            state: ScrollAreaState {
+                child: None,
+                horizontal: false,
+                vertical: true,
+                scroll_x: 0,
+                scroll_y: 0,
+                target: None,
+                request: 0,
+                stretched: false,
+                style: "".to_string(),
+                tooltip: None,
+            },
+            listener: None,
+        }
+    }
+
+    /// Set the child
+    pub fn set_child(&mut self, child: Box<dyn Widget>) {
+        self.state.set_child(child);
+    }
+
+    /// Set the horizontal and vertical scrolling flags
+    pub fn set_scrolling(&mut self, horizontal: bool, vertical: bool) {
+        self.state.set_horizontal(horizontal);
+        self.state.set_vertical(vertical);
+    }
+
+    /// Set the stretched flag to true
+    pub fn set_stretched(&mut self) {
+        self.state.set_stretched(true);
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &ScrollAreaState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut ScrollAreaState {
+        &mut self.state
+    }
+
+    /// Set the listener
+    pub fn set_listener(&mut self, listener: Box<dyn ScrollAreaListener>) {
+        self.listener = Some(listener);
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.state.set_style(style);
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
+
+    /// Find a widget by name in the scroll area and downcast it to the type T
+    pub fn find<T: Widget + 'static>(&self, name: &str) -> Option<&T> {
+        find_as(self, name)
+    }
+
+    /// Find a mutable widget by name in the scroll area and downcast it to
+    /// the type T
+    pub fn find_mut<T: Widget + 'static>(
+        &mut self,
+        name: &str,
+    ) -> Option<&mut T> {
+        find_as_mut(self, name)
+    }
+}
+
+impl Widget for ScrollArea {
+    fn eval(&self) -> String {
+        let stretched = if self.state.stretched() {
+            "stretched"
+        } else {
+            ""
+        };
+        let horizontal = if self.state.horizontal() {
+            "scroll-horizontal"
+        } else {
+            ""
+        };
+        let vertical = if self.state.vertical() {
+            "scroll-vertical"
+        } else {
+            ""
+        };
+        let style = inline_style(&scss_to_css(&format!(
+            r##"#{}{{{}}}"##,
+            self.name,
+            self.state.style(),
+        )));
+        let html = format!(
+            r#"
+            <div id="{}" class="scrollarea {} {} {}" data-x="{}" data-y="{}"
+                data-target="{}" data-request="{}"
+                onscroll="scrolled(this, 100)"
+            >
+                {}
+            </div>
+            "#,
+            self.name,
+            horizontal,
+            vertical,
+            stretched,
+            self.state.scroll_x(),
+            self.state.scroll_y(),
+            self.state.target().unwrap_or(""),
+            self.state.request,
+            match &self.state.child {
+                Some(child) => child.eval(),
+                None => "".to_string(),
+            },
+        );
+        format!("{}{}", style, html)
+    }
+
+    fn trigger(&mut self, event: &Event) {
+        match event {
+            Event::Update => self.on_update(),
+            Event::Change { source, value } => {
+                if source == &self.name {
+                    self.on_change(value)
+                }
+            }
+            _ => (),
+        }
+        if let Some(child) = &mut self.state.child {
+            child.trigger(event);
+        }
+    }
+
+    fn on_update(&mut self) {
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_update(&mut self.state);
+            }
+        }
+    }
+
+    fn on_change(&mut self, value: &str) {
+        let values = value.split(';').collect::<Vec<&str>>();
+        if let [_, x, y, ..] = values.as_slice() {
+            self.state.scroll_x = x.parse::<f64>().unwrap_or(0.0) as u32;
+            self.state.scroll_y = y.parse::<f64>().unwrap_or(0.0) as u32;
+            self.state.target = None;
+        }
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_change(&self.state);
+            }
+        }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+
+    fn children(&self) -> Vec<&dyn Widget> {
+        match &self.state.child {
+            Some(child) => vec![child.as_ref()],
+            None => vec![],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Widget> {
+        match &mut self.state.child {
+            Some(child) => vec![child.as_mut()],
+            None => vec![],
+        }
+    }
+}
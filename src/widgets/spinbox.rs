@@ -0,0 +1,719 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::utils::event::{Event, Key};
+use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
+use crate::utils::validation::{Field, FieldValue, Validation, Validator};
+use crate::widgets::widget::Widget;
+
+/// # Trait that any of the types held by a SpinBox have to implement
+///
+/// It is implemented for the integer and floating point primitive types. The
+/// integers are stepped exactly, while the default implementation of
+/// `offset` computes in f64 and is only exact up to 2^53.
+pub trait Number: Copy + PartialOrd + Display + FromStr + 'static {
+    /// The number of decimals displayed by default
+    const DECIMALS: usize;
+
+    /// Convert the number into a f64
+    fn to_f64(self) -> f64;
+
+    /// Convert a f64 into the number, saturating at the bounds of the type
+    fn from_f64(value: f64) -> Self;
+
+    /// Add the step the given number of times, the result being rounded to
+    /// the number of decimals and kept between the min and the max
+    fn offset(
+        self,
+        step: Self,
+        steps: i32,
+        decimals: usize,
+        min: Self,
+        max: Self,
+    ) -> Self {
+        let factor = 10_f64.powi(decimals as i32);
+        let value = self.to_f64() + step.to_f64() * f64::from(steps);
+        let value = Self::from_f64((value * factor).round() / factor);
+        clamp(value, min, max)
+    }
+}
+
+macro_rules! integer {
+    ($($type:ty),*) => {$(
+        impl Number for $type {
+            const DECIMALS: usize = 0;
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn from_f64(value: f64) -> Self {
+                value as $type
+            }
+
+            fn offset(
+                self,
+                step: Self,
+                steps: i32,
+                _decimals: usize,
+                min: Self,
+                max: Self,
+            ) -> Self {
+                let value = self as i128 + step as i128 * i128::from(steps);
+                let value = value.max(min as i128).min(max as i128);
+                value as $type
+            }
+        }
+    )*};
+}
+
+macro_rules! float {
+    ($($type:ty),*) => {$(
+        impl Number for $type {
+            const DECIMALS: usize = 2;
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn from_f64(value: f64) -> Self {
+                value as $type
+            }
+        }
+    )*};
+}
+
+integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+float!(f32, f64);
+
+/// Keep a number between the min and the max
+fn clamp<T: Number>(value: T, min: T, max: T) -> T {
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+/// # The state of a SpinBox
+///
+/// ## Fields
+///
+/// ```text
+/// value: T
+/// min: T
+/// max: T
+/// step: T
+/// decimals: usize
+/// prefix: String
+/// suffix: String
+/// request: u32
+/// validation: Validation
+/// disabled: bool
+/// stretched: bool
+/// style: String
+/// tooltip: Option<Tooltip>
+/// ```
+pub struct SpinBoxState<T: Number> {
+    value: T,
+    min: T,
+    max: T,
+    step: T,
+    decimals: usize,
+    prefix: String,
+    suffix: String,
+    request: u32,
+    validation: Validation,
+    disabled: bool,
+    stretched: bool,
+    style: String,
+    tooltip: Option<Tooltip>,
+}
+
+impl<T: Number> SpinBoxState<T> {
+    /// Get the value
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Get the min
+    pub fn min(&self) -> T {
+        self.min
+    }
+
+    /// Get the max
+    pub fn max(&self) -> T {
+        self.max
+    }
+
+    /// Get the step
+    pub fn step(&self) -> T {
+        self.step
+    }
+
+    /// Get the number of decimals
+    pub fn decimals(&self) -> usize {
+        self.decimals
+    }
+
+    /// Get the prefix
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Get the suffix
+    pub fn suffix(&self) -> &str {
+        &self.suffix
+    }
+
+    /// Get the text displayed, made of the prefix, the value and the suffix
+    pub fn text(&self) -> String {
+        format!(
+            "{}{:.*}{}",
+            self.prefix, self.decimals, self.value, self.suffix
+        )
+    }
+
+    /// Get the validation
+    pub fn validation(&self) -> &Validation {
+        &self.validation
+    }
+
+    /// Get the disabled flag
+    pub fn disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Get the stretched flag
+    pub fn stretched(&self) -> bool {
+        self.stretched
+    }
+
+    /// Get the style
+    pub fn style(&self) -> &str {
+        &self.style
+    }
+
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
+    /// Set the value, kept between the min and the max
+    ///
+    /// A value different from the current one replaces the text typed in
+    /// the input, even if the input has the focus.
+    pub fn set_value(&mut self, value: T) {
+        let value = clamp(value, self.min, self.max);
+        if value != self.value {
+            self.value = value;
+            self.request += 1;
+        }
+    }
+
+    /// Set the min, the value being kept above it
+    pub fn set_min(&mut self, min: T) {
+        self.min = min;
+        self.set_value(self.value);
+    }
+
+    /// Set the max, the value being kept below it
+    pub fn set_max(&mut self, max: T) {
+        self.max = max;
+        self.set_value(self.value);
+    }
+
+    /// Set the step
+    pub fn set_step(&mut self, step: T) {
+        self.step = step;
+    }
+
+    /// Set the number of decimals
+    pub fn set_decimals(&mut self, decimals: usize) {
+        self.decimals = decimals;
+    }
+
+    /// Set the prefix
+    pub fn set_prefix(&mut self, prefix: &str) {
+        self.prefix = prefix.to_string();
+    }
+
+    /// Set the suffix
+    pub fn set_suffix(&mut self, suffix: &str) {
+        self.suffix = suffix.to_string();
+    }
+
+    /// Set the disabled flag
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+
+    /// Set the stretched flag
+    pub fn set_stretched(&mut self, stretched: bool) {
+        self.stretched = stretched;
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.style = style.to_string();
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
+
+    /// Add the step the given number of times to the value, return true if
+    /// the value has changed
+    ///
+    /// The value is rounded to the number of decimals and kept between the
+    /// min and the max.
+    pub fn step_by(&mut self, steps: i32) -> bool {
+        let previous = self.value;
+        self.set_value(self.value.offset(
+            self.step,
+            steps,
+            self.decimals,
+            self.min,
+            self.max,
+        ));
+        self.value != previous
+    }
+
+    /// Parse a text typed by the user, the prefix and the suffix being
+    /// optional
+    ///
+    /// Return None if the text is not a number or if the number is out of
+    /// the bounds.
+    pub fn parse(&self, text: &str) -> Option<T> {
+        let text = text.trim();
+        let text = text.strip_prefix(self.prefix.as_str()).unwrap_or(text);
+        let text = text.strip_suffix(self.suffix.as_str()).unwrap_or(text);
+        match text.trim().parse::<T>() {
+            Ok(value) if value >= self.min && value <= self.max => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// # The listener of a SpinBox
+pub trait SpinBoxListener<T: Number> {
+    /// Function triggered on update event
+    fn on_update(&self, state: &mut SpinBoxState<T>);
+
+    /// Function triggered on change event
+    ///
+    /// The texts which are not numbers between the min and the max are
+    /// rejected and do not trigger this function.
+    fn on_change(&self, state: &SpinBoxState<T>);
+}
+
+/// # A field holding a number
+///
+/// The number can be typed, or changed by a step with the buttons, the
+/// mouse wheel and the Up and Down keys. The PageUp and PageDown keys
+/// change the number by ten steps.
+///
+/// ## Fields
+///
+/// ```text
+/// name: String
+/// state: SpinBoxState<T>
+/// listener: Option<Box<dyn SpinBoxListener<T>>>
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// name: name.to_string()
+/// state:
+///     value: 0
+///     min: 0
+///     max: 100
+///     step: 1
+///     decimals: T::DECIMALS
+///     prefix: "".to_string()
+///     suffix: "".to_string()
+///     request: 0
+///     validation: Validation::new()
+///     disabled: false
+///     stretched: false
+///     style: "".to_string()
+///     tooltip: None
+/// listener: None
+/// ```
+///
+/// ## Style
+///
+/// ```text
+/// div.spinbox[.disabled][.invalid]
+///     input
+///     div.spinbox-buttons
+///         div.spinbox-up
+///         div.spinbox-down
+///     div.validation-error
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use neutrino::widgets::spinbox::{
+///     SpinBox,
+///     SpinBoxListener,
+///     SpinBoxState
+/// };
+///
+///
+/// struct Order {
+///     weight: f64,
+/// }
+///
+/// impl Order {
+///     fn new() -> Self {
+///         Self { weight: 1.5 }
+///     }
+///
+///     fn weight(&self) -> f64 {
+///         self.weight
+///     }
+///
+///     fn set_weight(&mut self, weight: f64) {
+///         self.weight = weight;
+///     }
+/// }
+///
+///
+/// struct MySpinBoxListener {
+///     order: Rc<RefCell<Order>>,
+/// }
+///
+/// impl MySpinBoxListener {
+///    pub fn new(order: Rc<RefCell<Order>>) -> Self {
+///        Self { order }
+///    }
+/// }
+///
+/// impl SpinBoxListener<f64> for MySpinBoxListener {
+///     fn on_change(&self, state: &SpinBoxState<f64>) {
+///         self.order.borrow_mut().set_weight(state.value());
+///     }
+///
+///     fn on_update(&self, state: &mut SpinBoxState<f64>) {
+///         state.set_value(self.order.borrow().weight());
+///     }
+/// }
+///
+///
+/// fn main() {
+///     let order = Rc::new(RefCell::new(Order::new()));
+///
+///     let my_listener = MySpinBoxListener::new(Rc::clone(&order));
+///
+///     let mut my_spinbox = SpinBox::<f64>::new("my_spinbox");
+///     my_spinbox.set_range(0.0, 10.0);
+///     my_spinbox.set_step(0.25);
+///     my_spinbox.set_suffix(" kg");
+///     my_spinbox.set_listener(Box::new(my_listener));
+/// }
+/// ```
+pub struct SpinBox<T: Number> {
+    name: String,
+    state: SpinBoxState<T>,
+    listener: Option<Box<dyn SpinBoxListener<T>>>,
+}
+
+impl<T: Number> SpinBox<T> {
+    /// Create a SpinBox
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            state: SpinBoxState {
+                value: T::from_f64(0.0),
+                min: T::from_f64(0.0),
+                max: T::from_f64(100.0),
+                step: T::from_f64(1.0),
+                decimals: T::DECIMALS,
+                prefix: "".to_string(),
+                suffix: "".to_string(),
+                request: 0,
+                validation: Validation::new(),
+                disabled: false,
+                stretched: false,
+                style: "".to_string(),
+                tooltip: None,
+            },
+            listener: None,
+        }
+    }
+
+    /// Set the value, kept between the min and the max
+    pub fn set_value(&mut self, value: T) {
+        self.state.set_value(value);
+    }
+
+    /// Set the min and the max
+    pub fn set_range(&mut self, min: T, max: T) {
+        self.state.set_min(min);
+        self.state.set_max(max);
+    }
+
+    /// Set the step
+    pub fn set_step(&mut self, step: T) {
+        self.state.set_step(step);
+    }
+
+    /// Set the number of decimals
+    pub fn set_decimals(&mut self, decimals: usize) {
+        self.state.set_decimals(decimals);
+    }
+
+    /// Set the prefix
+    pub fn set_prefix(&mut self, prefix: &str) {
+        self.state.set_prefix(prefix);
+    }
+
+    /// Set the suffix
+    pub fn set_suffix(&mut self, suffix: &str) {
+        self.state.set_suffix(suffix);
+    }
+
+    /// Set the disabled flag to true
+    pub fn set_disabled(&mut self) {
+        self.state.set_disabled(true);
+    }
+
+    /// Set the stretched flag to true
+    pub fn set_stretched(&mut self) {
+        self.state.set_stretched(true);
+    }
+
+    /// Add a validator
+    pub fn add_validator(&mut self, validator: Validator) {
+        self.state.validation.add(validator);
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &SpinBoxState<T> {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut SpinBoxState<T> {
+        &mut self.state
+    }
+
+    /// Set the listener
+    pub fn set_listener(&mut self, listener: Box<dyn SpinBoxListener<T>>) {
+        self.listener = Some(listener);
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.state.set_style(style);
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
+
+    /// Function triggered on keypress event
+    fn on_keys(&mut self, keys: &HashSet<Key>) {
+        let steps = if keys.contains(&Key::Up) {
+            1
+        } else if keys.contains(&Key::Down) {
+            -1
+        } else if keys.contains(&Key::PageUp) {
+            10
+        } else if keys.contains(&Key::PageDown) {
+            -10
+        } else {
+            return;
+        };
+        self.on_step(steps);
+    }
+
+    /// Change the value by the given number of steps
+    fn on_step(&mut self, steps: i32) {
+        if self.state.step_by(steps) {
+            self.on_value();
+        }
+    }
+
+    /// Validate the new value and notify the listener
+    fn on_value(&mut self) {
+        self.validate();
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_change(&self.state);
+            }
+        }
+    }
+}
+
+impl<T: Number> Widget for SpinBox<T> {
+    fn eval(&self) -> String {
+        let stretched = if self.state.stretched() {
+            "stretched"
+        } else {
+            ""
+        };
+        let disabled = if self.state.disabled() {
+            "disabled"
+        } else {
+            ""
+        };
+        let style = inline_style(&scss_to_css(&format!(
+            r##"#{}{{{}}}"##,
+            self.name,
+            self.state.style(),
+        )));
+        let html = format!(
+            r#"
+            <div id="{}" class="spinbox {} {} {}"
+                onwheel="event.preventDefault(); {}"
+            >
+                <input {} type="text" value="{}" data-value-request="{}"
+                    onchange="{}" onblur="{}"
+                    onkeydown="if (spin(event)) {{ {} }}" />
+                <div class="spinbox-buttons">
+                    <div class="spinbox-up" onclick="{}">&#9650;</div>
+                    <div class="spinbox-down" onclick="{}">&#9660;</div>
+                </div>
+                {}
+            </div>
+            "#,
+            self.name,
+            disabled,
+            stretched,
+            self.state.validation().css(),
+            Event::change_js(
+                &self.name,
+                "'step;' + (event.deltaY < 0 ? 1 : -1)"
+            ),
+            disabled,
+            self.state.text(),
+            self.state.request,
+            Event::change_js(&self.name, "'input;' + this.value"),
+            Event::change_js(&self.name, "'blur'"),
+            Event::keypress_js(&self.name, "down"),
+            Event::change_js(&self.name, "'step;1'"),
+            Event::change_js(&self.name, "'step;-1'"),
+            self.state.validation().eval(),
+        );
+        format!("{}{}", style, html)
+    }
+
+    fn trigger(&mut self, event: &Event) {
+        match event {
+            Event::Update => self.on_update(),
+            Event::Change { source, value } => {
+                if source == &self.name && !self.state.disabled() {
+                    self.on_change(value);
+                }
+            }
+            Event::Keypress { source, keys } => {
+                if source == &self.name && !self.state.disabled() {
+                    self.on_keys(keys);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn on_update(&mut self) {
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_update(&mut self.state);
+            }
+        }
+    }
+
+    fn on_change(&mut self, value: &str) {
+        let values = value.splitn(2, ';').collect::<Vec<&str>>();
+        match values.as_slice() {
+            ["input", text] => {
+                if let Some(value) = self.state.parse(text) {
+                    self.state.value = value;
+                    self.on_value();
+                }
+            }
+            ["step", steps] => {
+                if let Ok(steps) = steps.parse::<i32>() {
+                    self.on_step(steps);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+
+    fn field(&self) -> Option<&dyn Field> {
+        Some(self)
+    }
+
+    fn field_mut(&mut self) -> Option<&mut dyn Field> {
+        Some(self)
+    }
+}
+
+impl<T: Number> Field for SpinBox<T> {
+    /// The value of a form field is a f64, so the integers beyond 2^53 are
+    /// rounded in the values of a Form
+    fn value(&self) -> FieldValue {
+        FieldValue::Number(self.state.value().to_f64())
+    }
+
+    fn validation(&self) -> &Validation {
+        self.state.validation()
+    }
+
+    fn validate(&mut self) -> bool {
+        let value = self.value();
+        self.state.validation.validate(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_value_is_clamped_and_requested() {
+        let mut spinbox = SpinBox::<i32>::new("spinbox");
+        spinbox.set_value(150);
+        assert_eq!(spinbox.state().value(), 100);
+        assert_eq!(spinbox.state.request, 1);
+        spinbox.set_value(100);
+        assert_eq!(spinbox.state.request, 1);
+        spinbox.set_range(-10, 10);
+        assert_eq!(spinbox.state().value(), 10);
+    }
+
+    #[test]
+    fn large_integers_are_stepped_exactly() {
+        let mut spinbox = SpinBox::<u64>::new("spinbox");
+        spinbox.set_range(0, u64::MAX);
+        spinbox.set_value(u64::MAX - 2);
+        assert!(spinbox.state.step_by(1));
+        assert_eq!(spinbox.state().value(), u64::MAX - 1);
+        assert!(spinbox.state.step_by(5));
+        assert_eq!(spinbox.state().value(), u64::MAX);
+    }
+}
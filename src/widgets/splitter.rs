@@ -0,0 +1,589 @@
+use crate::utils::event::Event;
+use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
+use crate::widgets::container::Direction;
+use crate::widgets::widget::{find_as, find_as_mut, Widget};
+
+/// # A pane of a Splitter
+///
+/// ## Fields
+///
+/// ```text
+/// child: Box<dyn Widget>
+/// ratio: f64
+/// min_size: Option<u32>
+/// max_size: Option<u32>
+/// collapsible: bool
+/// collapsed: bool
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// child: child
+/// ratio: 1.0
+/// min_size: None
+/// max_size: None
+/// collapsible: false
+/// collapsed: false
+/// ```
+pub struct SplitterPane {
+    child: Box<dyn Widget>,
+    ratio: f64,
+    min_size: Option<u32>,
+    max_size: Option<u32>,
+    collapsible: bool,
+    collapsed: bool,
+}
+
+impl SplitterPane {
+    /// Create a SplitterPane holding a child
+    pub fn new(child: Box<dyn Widget>) -> Self {
+        Self {
+            child,
+            ratio: 1.0,
+            min_size: None,
+            max_size: None,
+            collapsible: false,
+            collapsed: false,
+        }
+    }
+
+    /// Get the child
+    pub fn child(&self) -> &dyn Widget {
+        self.child.as_ref()
+    }
+
+    /// Get the ratio
+    pub fn ratio(&self) -> f64 {
+        self.ratio
+    }
+
+    /// Get the minimum size
+    pub fn min_size(&self) -> Option<u32> {
+        self.min_size
+    }
+
+    /// Get the maximum size
+    pub fn max_size(&self) -> Option<u32> {
+        self.max_size
+    }
+
+    /// Get the collapsible flag
+    pub fn collapsible(&self) -> bool {
+        self.collapsible
+    }
+
+    /// Get the collapsed flag
+    pub fn collapsed(&self) -> bool {
+        self.collapsed
+    }
+
+    /// Set the ratio
+    ///
+    /// The ratios of the panes are relative to each other.
+    pub fn set_ratio(&mut self, ratio: f64) {
+        self.ratio = ratio.max(0.0);
+    }
+
+    /// Set the minimum size (in pixels)
+    pub fn set_min_size(&mut self, min_size: u32) {
+        self.min_size = Some(min_size);
+    }
+
+    /// Set the maximum size (in pixels)
+    pub fn set_max_size(&mut self, max_size: u32) {
+        self.max_size = Some(max_size);
+    }
+
+    /// Set the collapsible flag to true
+    ///
+    /// A collapsible pane is collapsed or expanded by double clicking on an
+    /// adjacent handle.
+    pub fn set_collapsible(&mut self) {
+        self.collapsible = true;
+    }
+
+    /// Set the collapsed flag
+    pub fn set_collapsed(&mut self, collapsed: bool) {
+        self.collapsed = collapsed;
+    }
+
+    /// Return the HTML representation of the pane
+    fn eval(&self, direction: &Direction) -> String {
+        let dimension = match direction {
+            Direction::Horizontal => "width",
+            Direction::Vertical => "height",
+        };
+        let mut style = format!("flex-grow: {};", self.ratio);
+        if let Some(min_size) = self.min_size {
+            style.push_str(&format!("min-{}: {}px;", dimension, min_size));
+        }
+        if let Some(max_size) = self.max_size {
+            style.push_str(&format!("max-{}: {}px;", dimension, max_size));
+        }
+        format!(
+            r#"
+            <div class="splitter-pane {}" style="{}" data-min="{}"
+                data-max="{}"
+            >
+                {}
+            </div>
+            "#,
+            if self.collapsed { "collapsed" } else { "" },
+            style,
+            self.min_size.unwrap_or(0),
+            match self.max_size {
+                Some(max_size) => max_size.to_string(),
+                None => "".to_string(),
+            },
+            self.child.eval(),
+        )
+    }
+}
+
+/// # The state of a Splitter
+///
+/// ## Fields
+///
+/// ```text
+/// panes: Vec<SplitterPane>
+/// direction: Direction
+/// stretched: bool
+/// style: String
+/// tooltip: Option<Tooltip>
+/// ```
+pub struct SplitterState {
+    panes: Vec<SplitterPane>,
+    direction: Direction,
+    stretched: bool,
+    style: String,
+    tooltip: Option<Tooltip>,
+}
+
+impl SplitterState {
+    /// Get the panes
+    pub fn panes(&self) -> &Vec<SplitterPane> {
+        &self.panes
+    }
+
+    /// Get the mutable panes
+    pub fn panes_mut(&mut self) -> &mut Vec<SplitterPane> {
+        &mut self.panes
+    }
+
+    /// Get the ratios of the panes, their sum being 1
+    pub fn ratios(&self) -> Vec<f64> {
+        let total: f64 = self.panes.iter().map(|pane| pane.ratio()).sum();
+        self.panes
+            .iter()
+            .map(|pane| {
+                if total > 0.0 {
+                    pane.ratio() / total
+                } else {
+                    0.0
+                }
+            })
+            .collect()
+    }
+
+    /// Get the direction
+    pub fn direction(&self) -> &Direction {
+        &self.direction
+    }
+
+    /// Get the stretched flag
+    pub fn stretched(&self) -> bool {
+        self.stretched
+    }
+
+    /// Get the style
+    pub fn style(&self) -> &str {
+        &self.style
+    }
+
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
+    /// Set the ratios of the panes
+    pub fn set_ratios(&mut self, ratios: Vec<f64>) {
+        for (pane, ratio) in self.panes.iter_mut().zip(ratios) {
+            pane.set_ratio(ratio);
+        }
+    }
+
+    /// Set the collapsed flag of the pane at the given index
+    pub fn set_collapsed(&mut self, index: usize, collapsed: bool) {
+        if let Some(pane) = self.panes.get_mut(index) {
+            pane.set_collapsed(collapsed);
+        }
+    }
+
+    /// Set the direction
+    pub fn set_direction(&mut self, direction: Direction) {
+        self.direction = direction;
+    }
+
+    /// Set the stretched flag
+    pub fn set_stretched(&mut self, stretched: bool) {
+        self.stretched = stretched;
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.style = style.to_string();
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
+
+    /// Add a pane
+    pub fn add(&mut self, pane: SplitterPane) {
+        self.panes.push(pane);
+    }
+}
+
+/// # The listener of a Splitter
+pub trait SplitterListener {
+    /// Function triggered on update event
+    fn on_update(&self, state: &mut SplitterState);
+
+    /// Function triggered on change event
+    fn on_change(&self, state: &SplitterState);
+}
+
+/// # A container whose panes are separated by draggable handles
+///
+/// ## Fields
+///
+/// ```text
+/// name: String
+/// state: SplitterState
+/// listener: Option<Box<dyn SplitterListener>>
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// name: name.to_string()
+/// state:
+///     panes: vec![]
+///     direction: Direction::Horizontal
+///     stretched: false
+///     style: "".to_string()
+///     tooltip: None
+/// listener: None
+/// ```
+///
+/// ## Style
+///
+/// ```text
+/// div.splitter[.direction-horizontal][.direction-vertical]
+///     div.splitter-pane[.collapsed]
+///     div.splitter-handle
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use neutrino::widgets::label::Label;
+/// use neutrino::widgets::splitter::{
+///     Splitter,
+///     SplitterListener,
+///     SplitterPane,
+///     SplitterState
+/// };
+///
+///
+/// struct Layout {
+///     ratios: Vec<f64>,
+/// }
+///
+/// impl Layout {
+///     fn new() -> Self {
+///         Self { ratios: vec![0.25, 0.75] }
+///     }
+///
+///     fn ratios(&self) -> &Vec<f64> {
+///         &self.ratios
+///     }
+///
+///     fn set_ratios(&mut self, ratios: Vec<f64>) {
+///         self.ratios = ratios;
+///     }
+/// }
+///
+///
+/// struct MySplitterListener {
+///     layout: Rc<RefCell<Layout>>,
+/// }
+///
+/// impl MySplitterListener {
+///    pub fn new(layout: Rc<RefCell<Layout>>) -> Self {
+///        Self { layout }
+///    }
+/// }
+///
+/// impl SplitterListener for MySplitterListener {
+///     fn on_change(&self, state: &SplitterState) {
+///         self.layout.borrow_mut().set_ratios(state.ratios());
+///     }
+///
+///     fn on_update(&self, state: &mut SplitterState) {
+///         state.set_ratios(self.layout.borrow().ratios().clone());
+///     }
+/// }
+///
+///
+/// fn main() {
+///     let layout = Rc::new(RefCell::new(Layout::new()));
+///
+///     let my_listener = MySplitterListener::new(Rc::clone(&layout));
+///
+///     let mut explorer = SplitterPane::new(Box::new(Label::new("explorer")));
+///     explorer.set_min_size(150);
+///     explorer.set_collapsible();
+///
+///     let editor = SplitterPane::new(Box::new(Label::new("editor")));
+///
+///     let mut my_splitter = Splitter::new("my_splitter");
+///     my_splitter.add(explorer);
+///     my_splitter.add(editor);
+///     my_splitter.set_listener(Box::new(my_listener));
+/// }
+/// ```
+pub struct Splitter {
+    name: String,
+    state: SplitterState,
+    listener: Option<Box<dyn SplitterListener>>,
+}
+
+impl Splitter {
+    /// Create a Splitter
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            state: SplitterState {
+                panes: vec![],
+                direction: Direction::Horizontal,
+                stretched: false,
+                style: "".to_string(),
+                tooltip: None,
+            },
+            listener: None,
+        }
+    }
+
+    /// Set the direction
+    pub fn set_direction(&mut self, direction: Direction) {
+        self.state.set_direction(direction);
+    }
+
+    /// Set the ratios of the panes
+    pub fn set_ratios(&mut self, ratios: Vec<f64>) {
+        self.state.set_ratios(ratios);
+    }
+
+    /// Set the stretched flag to true
+    pub fn set_stretched(&mut self) {
+        self.state.set_stretched(true);
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &SplitterState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut SplitterState {
+        &mut self.state
+    }
+
+    /// Set the listener
+    pub fn set_listener(&mut self, listener: Box<dyn SplitterListener>) {
+        self.listener = Some(listener);
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.state.set_style(style);
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
+
+    /// Add a pane
+    pub fn add(&mut self, pane: SplitterPane) {
+        self.state.add(pane);
+    }
+
+    /// Find a widget by name in the splitter and downcast it to the type T
+    pub fn find<T: Widget + 'static>(&self, name: &str) -> Option<&T> {
+        find_as(self, name)
+    }
+
+    /// Find a mutable widget by name in the splitter and downcast it to the
+    /// type T
+    pub fn find_mut<T: Widget + 'static>(
+        &mut self,
+        name: &str,
+    ) -> Option<&mut T> {
+        find_as_mut(self, name)
+    }
+
+    /// Function triggered when a handle has been dragged
+    fn on_resize(&mut self, value: &str) {
+        let sizes = value
+            .split(',')
+            .map(|size| size.parse::<f64>().unwrap_or(0.0))
+            .collect::<Vec<f64>>();
+        let visible = self
+            .state
+            .panes
+            .iter()
+            .filter(|pane| !pane.collapsed())
+            .map(|pane| pane.ratio())
+            .sum::<f64>();
+        for (pane, size) in self.state.panes.iter_mut().zip(sizes) {
+            if !pane.collapsed() {
+                pane.set_ratio(size * visible);
+            }
+        }
+    }
+
+    /// Function triggered when a handle has been double clicked
+    fn on_collapse(&mut self, handle: usize) {
+        let index = match self.state.panes.get(handle) {
+            Some(pane) if pane.collapsible() => handle,
+            _ => handle + 1,
+        };
+        if let Some(pane) = self.state.panes.get_mut(index) {
+            if pane.collapsible() {
+                let collapsed = pane.collapsed();
+                pane.set_collapsed(!collapsed);
+            }
+        }
+    }
+}
+
+impl Widget for Splitter {
+    fn eval(&self) -> String {
+        let stretched = if self.state.stretched() {
+            "stretched"
+        } else {
+            ""
+        };
+        let vertical = match self.state.direction() {
+            Direction::Horizontal => "false",
+            Direction::Vertical => "true",
+        };
+        let style = inline_style(&scss_to_css(&format!(
+            r##"#{}{{{}}}"##,
+            self.name,
+            self.state.style(),
+        )));
+        let mut html = format!(
+            r#"<div id="{}" class="splitter {} {}">"#,
+            self.name,
+            self.state.direction().css(),
+            stretched,
+        );
+        for (i, pane) in self.state.panes.iter().enumerate() {
+            if i > 0 {
+                html.push_str(&format!(
+                    r#"
+                    <div class="splitter-handle"
+                        onmousedown="drag(event, '{}', {}, {})"
+                        ondblclick="{}"
+                    ></div>
+                    "#,
+                    self.name,
+                    i - 1,
+                    vertical,
+                    Event::change_js(
+                        &self.name,
+                        &format!("'collapse;{}'", i - 1)
+                    ),
+                ));
+            }
+            html.push_str(&pane.eval(self.state.direction()));
+        }
+        html.push_str("</div>");
+        format!("{}{}", style, html)
+    }
+
+    fn trigger(&mut self, event: &Event) {
+        match event {
+            Event::Update => self.on_update(),
+            Event::Change { source, value } => {
+                if source == &self.name {
+                    self.on_change(value)
+                }
+            }
+            _ => (),
+        }
+        for pane in self.state.panes.iter_mut() {
+            pane.child.trigger(event);
+        }
+    }
+
+    fn on_update(&mut self) {
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_update(&mut self.state);
+            }
+        }
+    }
+
+    fn on_change(&mut self, value: &str) {
+        let values = value.split(';').collect::<Vec<&str>>();
+        match (values.first(), values.get(1)) {
+            (Some(&"resize"), Some(sizes)) => self.on_resize(sizes),
+            (Some(&"collapse"), Some(handle)) => match handle.parse() {
+                Ok(handle) => self.on_collapse(handle),
+                Err(_) => return,
+            },
+            _ => return,
+        }
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_change(&self.state);
+            }
+        }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+
+    fn children(&self) -> Vec<&dyn Widget> {
+        self.state
+            .panes
+            .iter()
+            .map(|pane| pane.child.as_ref())
+            .collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Widget> {
+        self.state
+            .panes
+            .iter_mut()
+            .map(|pane| pane.child.as_mut() as &mut dyn Widget)
+            .collect()
+    }
+}
@@ -0,0 +1,252 @@
+use crate::utils::event::Event;
+use crate::utils::tooltip::Tooltip;
+use crate::widgets::widget::Widget;
+
+/// # The state of a StatusBar
+///
+/// ## Fields
+///
+/// ```text
+/// message: String
+/// sections: Vec<String>
+/// tooltip: Option<Tooltip>
+/// ```
+pub struct StatusBarState {
+    message: String,
+    sections: Vec<String>,
+    tooltip: Option<Tooltip>,
+}
+
+impl StatusBarState {
+    /// Get the message
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Get the sections
+    pub fn sections(&self) -> &Vec<String> {
+        &self.sections
+    }
+
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
+    /// Set the message
+    pub fn set_message(&mut self, message: &str) {
+        self.message = message.to_string();
+    }
+
+    /// Set the sections
+    pub fn set_sections(&mut self, sections: Vec<String>) {
+        self.sections = sections;
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
+}
+
+/// # The listener of a StatusBar
+pub trait StatusBarListener {
+    /// Function triggered on update event
+    fn on_update(&self, state: &mut StatusBarState);
+}
+
+/// # A bar docked at the bottom of a Window
+///
+/// The message is displayed on the left and the sections, holding permanent
+/// information such as the cursor position, on the right. Like the widgets
+/// of the tree, it can be found by name and mutated by a Dispatcher.
+///
+/// ## Fields
+///
+/// ```text
+/// name: String
+/// state: StatusBarState
+/// listener: Option<Box<dyn StatusBarListener>>
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// name: name.to_string()
+/// state:
+///     message: "".to_string()
+///     sections: vec![]
+///     tooltip: None
+/// listener: None
+/// ```
+///
+/// ## Style
+///
+/// ```text
+/// div.statusbar
+///     div.statusbar-message
+///     div.statusbar-section
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use neutrino::widgets::statusbar::{
+///     StatusBar,
+///     StatusBarListener,
+///     StatusBarState
+/// };
+/// use neutrino::Window;
+///
+///
+/// struct Document {
+///     line: u32,
+///     saved: bool,
+/// }
+///
+/// impl Document {
+///     fn new() -> Self {
+///         Self { line: 1, saved: true }
+///     }
+///
+///     fn line(&self) -> u32 {
+///         self.line
+///     }
+///
+///     fn saved(&self) -> bool {
+///         self.saved
+///     }
+/// }
+///
+///
+/// struct MyStatusBarListener {
+///     document: Rc<RefCell<Document>>,
+/// }
+///
+/// impl MyStatusBarListener {
+///    pub fn new(document: Rc<RefCell<Document>>) -> Self {
+///        Self { document }
+///    }
+/// }
+///
+/// impl StatusBarListener for MyStatusBarListener {
+///     fn on_update(&self, state: &mut StatusBarState) {
+///         let document = self.document.borrow();
+///         state.set_message(if document.saved() { "Saved" } else { "" });
+///         state.set_sections(vec![format!("Line {}", document.line())]);
+///     }
+/// }
+///
+///
+/// fn main() {
+///     let document = Rc::new(RefCell::new(Document::new()));
+///
+///     let my_listener = MyStatusBarListener::new(Rc::clone(&document));
+///
+///     let mut my_statusbar = StatusBar::new("my_statusbar");
+///     my_statusbar.add_section("UTF-8");
+///     my_statusbar.set_listener(Box::new(my_listener));
+///
+///     let mut my_window = Window::new();
+///     my_window.set_statusbar(my_statusbar);
+/// }
+/// ```
+pub struct StatusBar {
+    name: String,
+    state: StatusBarState,
+    listener: Option<Box<dyn StatusBarListener>>,
+}
+
+impl StatusBar {
+    /// Create a StatusBar
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            state: StatusBarState {
+                message: "".to_string(),
+                sections: vec![],
+                tooltip: None,
+            },
+            listener: None,
+        }
+    }
+
+    /// Set the message
+    pub fn set_message(&mut self, message: &str) {
+        self.state.set_message(message);
+    }
+
+    /// Add a section
+    pub fn add_section(&mut self, section: &str) {
+        self.state.sections.push(section.to_string());
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &StatusBarState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut StatusBarState {
+        &mut self.state
+    }
+
+    /// Set the listener
+    pub fn set_listener(&mut self, listener: Box<dyn StatusBarListener>) {
+        self.listener = Some(listener);
+    }
+}
+
+impl Widget for StatusBar {
+    fn eval(&self) -> String {
+        let mut s = format!(
+            r#"
+            <div id="{}" class="statusbar" role="status">
+                <div class="statusbar-message">{}</div>
+            "#,
+            self.name,
+            self.state.message(),
+        );
+        for section in self.state.sections() {
+            s.push_str(&format!(
+                r#"<div class="statusbar-section">{}</div>"#,
+                section
+            ));
+        }
+        s.push_str(r#"</div>"#);
+        s
+    }
+
+    fn trigger(&mut self, event: &Event) {
+        if let Event::Update = event {
+            self.on_update();
+        }
+    }
+
+    fn on_update(&mut self) {
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_update(&mut self.state);
+            }
+        }
+    }
+
+    fn on_change(&mut self, _value: &str) {}
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+}
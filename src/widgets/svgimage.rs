@@ -0,0 +1,298 @@
+use crate::utils::event::Event;
+use crate::utils::icon::Icon;
+use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::svg::Svg;
+use crate::utils::tooltip::Tooltip;
+use crate::widgets::widget::Widget;
+
+/// # The state of an SvgImage
+///
+/// ## Fields
+///
+/// ```text
+/// svg: Svg
+/// stretched: bool
+/// style: String
+/// tooltip: Option<Tooltip>
+/// ```
+pub struct SvgImageState {
+    svg: Svg,
+    stretched: bool,
+    style: String,
+    tooltip: Option<Tooltip>,
+}
+
+impl SvgImageState {
+    /// Get the svg
+    pub fn svg(&self) -> &Svg {
+        &self.svg
+    }
+
+    /// Get the stretched flag
+    pub fn stretched(&self) -> bool {
+        self.stretched
+    }
+
+    /// Get the style
+    pub fn style(&self) -> &str {
+        &self.style
+    }
+
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
+    /// Set the svg
+    pub fn set_svg(&mut self, svg: Svg) {
+        self.svg = svg;
+    }
+
+    /// Set the stretched flag
+    pub fn set_stretched(&mut self, stretched: bool) {
+        self.stretched = stretched;
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.style = style.to_string();
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
+}
+
+/// # The listener of an SvgImage
+pub trait SvgImageListener {
+    /// Function triggered on update event
+    fn on_update(&self, state: &mut SvgImageState);
+}
+
+/// # An element displaying an SVG image inlined in the page
+///
+/// The image is scaled to the size of the element without losing quality.
+/// Once recolored, with `set_monochrome` or when created from an Icon, it is
+/// drawn with the text colour of the theme, which can be overridden with the
+/// `color` property of the style.
+///
+/// ## Fields
+///
+/// ```text
+/// name: String
+/// state: SvgImageState
+/// listener: Option<Box<dyn SvgImageListener>>
+/// ```
+///
+/// ## Default values
+///
+/// The variable `svg` is built in the constructors from the given markup,
+/// path or Icon.
+///
+/// ```text
+/// name: name.to_string()
+/// state:
+///     svg: svg
+///     stretched: false
+///     style: "".to_string()
+///     tooltip: None
+/// listener: None
+/// ```
+///
+/// ## Style
+///
+/// ```text
+/// div.svgimage[.stretched]
+///     svg
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use neutrino::widgets::svgimage::{
+///     SvgImage,
+///     SvgImageListener,
+///     SvgImageState
+/// };
+/// use neutrino::utils::icon::BreezeIcon;
+/// use neutrino::utils::svg::Svg;
+///
+///
+/// struct Connection {
+///     online: bool,
+/// }
+///
+/// impl Connection {
+///     fn new() -> Self {
+///         Self { online: false }
+///     }
+///
+///     fn online(&self) -> bool {
+///         self.online
+///     }
+/// }
+///
+///
+/// struct MySvgImageListener {
+///     connection: Rc<RefCell<Connection>>,
+/// }
+///
+/// impl MySvgImageListener {
+///    pub fn new(connection: Rc<RefCell<Connection>>) -> Self {
+///        Self { connection }
+///    }
+/// }
+///
+/// impl SvgImageListener for MySvgImageListener {
+///     fn on_update(&self, state: &mut SvgImageState) {
+///         let icon = if self.connection.borrow().online() {
+///             BreezeIcon::Check
+///         } else {
+///             BreezeIcon::Lock
+///         };
+///         state.set_svg(Svg::from_icon(Box::new(icon)));
+///     }
+/// }
+///
+///
+/// fn main() {
+///     let connection = Rc::new(RefCell::new(Connection::new()));
+///
+///     let my_listener = MySvgImageListener::new(Rc::clone(&connection));
+///
+///     let mut my_svgimage = SvgImage::from_icon(
+///         "my_svgimage",
+///         Box::new(BreezeIcon::Lock)
+///     );
+///     my_svgimage.set_style("width: 32px; height: 32px;");
+///     my_svgimage.set_listener(Box::new(my_listener));
+/// }
+/// ```
+pub struct SvgImage {
+    name: String,
+    state: SvgImageState,
+    listener: Option<Box<dyn SvgImageListener>>,
+}
+
+impl SvgImage {
+    /// Create an SvgImage from markup
+    pub fn from_markup(name: &str, markup: &str) -> Self {
+        Self::from_svg(name, Svg::new(markup))
+    }
+
+    /// Create an SvgImage from a path
+    pub fn from_path(name: &str, path: &str) -> Self {
+        Self::from_svg(name, Svg::from_path(path))
+    }
+
+    /// Create an SvgImage from an icon
+    pub fn from_icon(name: &str, icon: Box<dyn Icon>) -> Self {
+        Self::from_svg(name, Svg::from_icon(icon))
+    }
+
+    /// Create an SvgImage from an Svg
+    fn from_svg(name: &str, svg: Svg) -> Self {
+        Self {
+            name: name.to_string(),
+            state: SvgImageState {
+                svg,
+                stretched: false,
+                style: "".to_string(),
+                tooltip: None,
+            },
+            listener: None,
+        }
+    }
+
+    /// Draw the image with the text colour
+    pub fn set_monochrome(&mut self) {
+        self.state.svg.recolor();
+    }
+
+    /// Set the stretched flag to true
+    pub fn set_stretched(&mut self) {
+        self.state.set_stretched(true);
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &SvgImageState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut SvgImageState {
+        &mut self.state
+    }
+
+    /// Set the listener
+    pub fn set_listener(&mut self, listener: Box<dyn SvgImageListener>) {
+        self.listener = Some(listener);
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.state.set_style(style);
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
+}
+
+impl Widget for SvgImage {
+    fn eval(&self) -> String {
+        let stretched = if self.state.stretched() {
+            "stretched"
+        } else {
+            ""
+        };
+        let style = inline_style(&scss_to_css(&format!(
+            r##"#{}{{{}}}"##,
+            self.name,
+            self.state.style(),
+        )));
+        let html = format!(
+            r#"<div id="{}" class="svgimage {}">{}</div>"#,
+            self.name,
+            stretched,
+            self.state.svg().eval(""),
+        );
+        format!("{}{}", style, html)
+    }
+
+    fn trigger(&mut self, event: &Event) {
+        match event {
+            Event::Update => self.on_update(),
+            Event::Change { source, value } => {
+                if source == &self.name {
+                    self.on_change(value)
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn on_update(&mut self) {
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_update(&mut self.state);
+            }
+        }
+    }
+
+    fn on_change(&mut self, _value: &str) {}
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+}
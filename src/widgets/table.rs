@@ -0,0 +1,1040 @@
+use crate::utils::event::{Event, Key};
+use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
+use crate::widgets::checkbox::CheckBox;
+use crate::widgets::combo::Combo;
+use crate::widgets::listview::SelectionMode;
+use crate::widgets::textinput::TextInput;
+use crate::widgets::widget::Widget;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+/// # The type of the values of a column
+pub enum ColumnType {
+    Text,
+    Number,
+    Bool,
+    Choice(Vec<String>),
+}
+
+/// # The value of a cell
+#[derive(Debug, Clone, PartialEq)]
+pub enum CellValue {
+    Text(String),
+    Number(f64),
+    Bool(bool),
+    Choice(usize),
+}
+
+/// # The sort order of a column
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+impl SortOrder {
+    fn css(self) -> &'static str {
+        match self {
+            SortOrder::Ascending => "ascending",
+            SortOrder::Descending => "descending",
+        }
+    }
+}
+
+/// # A column of a Table
+///
+/// ## Fields
+///
+/// ```text
+/// title: String
+/// column_type: ColumnType
+/// width: u32
+/// sortable: bool
+/// editable: bool
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// title: title.to_string()
+/// column_type: column_type
+/// width: 120
+/// sortable: true
+/// editable: false
+/// ```
+pub struct Column {
+    title: String,
+    column_type: ColumnType,
+    width: u32,
+    sortable: bool,
+    editable: bool,
+}
+
+impl Column {
+    /// Create a Column
+    pub fn new(title: &str, column_type: ColumnType) -> Self {
+        Self {
+            title: title.to_string(),
+            column_type,
+            width: 120,
+            sortable: true,
+            editable: false,
+        }
+    }
+
+    /// Get the title
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Get the type of the values
+    pub fn column_type(&self) -> &ColumnType {
+        &self.column_type
+    }
+
+    /// Get the width
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Get the sortable flag
+    pub fn sortable(&self) -> bool {
+        self.sortable
+    }
+
+    /// Get the editable flag
+    pub fn editable(&self) -> bool {
+        self.editable
+    }
+
+    /// Set the width (in pixels)
+    pub fn set_width(&mut self, width: u32) {
+        self.width = width;
+    }
+
+    /// Set the sortable flag
+    pub fn set_sortable(&mut self, sortable: bool) {
+        self.sortable = sortable;
+    }
+
+    /// Set the editable flag
+    ///
+    /// The cells of an editable column are edited by double clicking on them.
+    pub fn set_editable(&mut self, editable: bool) {
+        self.editable = editable;
+    }
+
+    /// Return the text displayed for a value of the column
+    fn text(&self, value: &CellValue) -> String {
+        match (value, &self.column_type) {
+            (CellValue::Choice(index), ColumnType::Choice(choices)) => {
+                choices.get(*index).cloned().unwrap_or_default()
+            }
+            (CellValue::Text(text), _) => text.to_string(),
+            (CellValue::Number(number), _) => number.to_string(),
+            (CellValue::Bool(flag), _) => flag.to_string(),
+            (CellValue::Choice(index), _) => index.to_string(),
+        }
+    }
+
+    /// Compare two values of the column
+    fn compare(&self, a: &CellValue, b: &CellValue) -> Ordering {
+        match (a, b) {
+            (CellValue::Number(a), CellValue::Number(b)) => {
+                a.partial_cmp(b).unwrap_or(Ordering::Equal)
+            }
+            (CellValue::Bool(a), CellValue::Bool(b)) => a.cmp(b),
+            _ => self.text(a).cmp(&self.text(b)),
+        }
+    }
+}
+
+/// # The state of a Table
+///
+/// ## Fields
+///
+/// ```text
+/// columns: Vec<Column>
+/// rows: Vec<Vec<CellValue>>
+/// sort: Option<(usize, SortOrder)>
+/// selected: Vec<usize>
+/// anchor: Option<usize>
+/// selection_mode: SelectionMode
+/// disabled: bool
+/// stretched: bool
+/// style: String
+/// tooltip: Option<Tooltip>
+/// ```
+pub struct TableState {
+    columns: Vec<Column>,
+    rows: Vec<Vec<CellValue>>,
+    sort: Option<(usize, SortOrder)>,
+    selected: Vec<usize>,
+    anchor: Option<usize>,
+    selection_mode: SelectionMode,
+    disabled: bool,
+    stretched: bool,
+    style: String,
+    tooltip: Option<Tooltip>,
+}
+
+impl TableState {
+    /// Get the columns
+    pub fn columns(&self) -> &Vec<Column> {
+        &self.columns
+    }
+
+    /// Get the rows
+    pub fn rows(&self) -> &Vec<Vec<CellValue>> {
+        &self.rows
+    }
+
+    /// Get the index and the order of the sorting column
+    pub fn sort(&self) -> Option<(usize, SortOrder)> {
+        self.sort
+    }
+
+    /// Get the indexes of the selected rows, in ascending order
+    pub fn selected(&self) -> &Vec<usize> {
+        &self.selected
+    }
+
+    /// Get the selection mode
+    pub fn selection_mode(&self) -> &SelectionMode {
+        &self.selection_mode
+    }
+
+    /// Get the disabled flag
+    pub fn disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Get the stretched flag
+    pub fn stretched(&self) -> bool {
+        self.stretched
+    }
+
+    /// Get the style
+    pub fn style(&self) -> &str {
+        &self.style
+    }
+
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
+    /// Get the indexes of the rows in the displayed order
+    pub fn order(&self) -> Vec<usize> {
+        let mut order = (0..self.rows.len()).collect::<Vec<usize>>();
+        if let Some((index, sort_order)) = self.sort {
+            if let Some(column) = self.columns.get(index) {
+                order.sort_by(|&a, &b| {
+                    let ordering = match (
+                        self.rows[a].get(index),
+                        self.rows[b].get(index),
+                    ) {
+                        (Some(a), Some(b)) => column.compare(a, b),
+                        _ => Ordering::Equal,
+                    };
+                    match sort_order {
+                        SortOrder::Ascending => ordering,
+                        SortOrder::Descending => ordering.reverse(),
+                    }
+                });
+            }
+        }
+        order
+    }
+
+    /// Set the columns
+    pub fn set_columns(&mut self, columns: Vec<Column>) {
+        self.columns = columns;
+        self.sort = self.sort.filter(|(index, _)| *index < self.columns.len());
+    }
+
+    /// Set the rows
+    ///
+    /// The selected indexes which are out of the new rows are dropped.
+    pub fn set_rows(&mut self, rows: Vec<Vec<CellValue>>) {
+        let len = rows.len();
+        self.rows = rows;
+        self.selected.retain(|&index| index < len);
+        self.anchor = self.anchor.filter(|&index| index < len);
+    }
+
+    /// Set the value of a cell
+    pub fn set_cell(&mut self, row: usize, column: usize, value: CellValue) {
+        if let Some(cell) = self
+            .rows
+            .get_mut(row)
+            .and_then(|cells| cells.get_mut(column))
+        {
+            *cell = value;
+        }
+    }
+
+    /// Set the index and the order of the sorting column
+    pub fn set_sort(&mut self, sort: Option<(usize, SortOrder)>) {
+        self.sort = sort;
+    }
+
+    /// Set the indexes of the selected rows
+    pub fn set_selected(&mut self, selected: Vec<usize>) {
+        let len = self.rows.len();
+        self.selected = selected;
+        self.selected.retain(|&index| index < len);
+        self.selected.sort();
+        self.selected.dedup();
+        if let SelectionMode::Single = self.selection_mode {
+            self.selected.truncate(1);
+        }
+    }
+
+    /// Set the selection mode
+    ///
+    /// Switching to single selection keeps only the anchor row if it is
+    /// selected, the first selected row otherwise.
+    pub fn set_selection_mode(&mut self, selection_mode: SelectionMode) {
+        self.selection_mode = selection_mode;
+        if let SelectionMode::Single = self.selection_mode {
+            let kept = match self.anchor {
+                Some(anchor) if self.selected.contains(&anchor) => Some(anchor),
+                _ => self.selected.first().cloned(),
+            };
+            self.selected = kept.into_iter().collect();
+            self.anchor = kept;
+        }
+    }
+
+    /// Set the disabled flag
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+
+    /// Set the stretched flag
+    pub fn set_stretched(&mut self, stretched: bool) {
+        self.stretched = stretched;
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.style = style.to_string();
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
+
+    /// Select the row at the given index
+    ///
+    /// In multiple selection mode, `toggle` adds or removes the row from the
+    /// selection and `extend` selects every displayed row from the anchor to
+    /// the row.
+    fn select(&mut self, index: usize, toggle: bool, extend: bool) {
+        match self.selection_mode {
+            SelectionMode::Multiple if extend => {
+                let order = self.order();
+                let position = |row| order.iter().position(|&i| i == row);
+                let start = self.anchor.and_then(position);
+                let end = position(index);
+                if let (Some(start), Some(end)) = (start, end) {
+                    self.selected =
+                        order[start.min(end)..=start.max(end)].to_vec();
+                    self.selected.sort();
+                } else {
+                    self.selected = vec![index];
+                    self.anchor = Some(index);
+                }
+            }
+            SelectionMode::Multiple if toggle => {
+                match self.selected.iter().position(|&i| i == index) {
+                    Some(position) => {
+                        self.selected.remove(position);
+                    }
+                    None => {
+                        self.selected.push(index);
+                        self.selected.sort();
+                    }
+                }
+                self.anchor = Some(index);
+            }
+            _ => {
+                self.selected = vec![index];
+                self.anchor = Some(index);
+            }
+        }
+    }
+}
+
+/// # The listener of a Table
+pub trait TableListener {
+    /// Function triggered on update event
+    fn on_update(&self, state: &mut TableState);
+
+    /// Function triggered when the sorting column or order has changed
+    fn on_sort(&self, state: &TableState);
+
+    /// Function triggered when the selected rows have changed
+    fn on_select(&self, state: &TableState);
+
+    /// Function triggered when the value of a cell has been edited
+    fn on_edit(&self, state: &TableState, row: usize, column: usize);
+}
+
+/// # The widget used to edit a cell
+enum Editor {
+    Input(TextInput),
+    CheckBox(CheckBox),
+    Combo(Combo),
+}
+
+impl Editor {
+    /// Create the editor of a value of a column
+    fn new(name: &str, column: &Column, value: &CellValue) -> Option<Self> {
+        match (&column.column_type, value) {
+            (ColumnType::Text, _) | (ColumnType::Number, _) => {
+                let mut input = TextInput::new(name);
+                input.set_value(&column.text(value));
+                input.set_stretched();
+                Some(Editor::Input(input))
+            }
+            (ColumnType::Bool, CellValue::Bool(checked)) => {
+                let mut checkbox = CheckBox::new(name);
+                if *checked {
+                    checkbox.set_checked();
+                }
+                Some(Editor::CheckBox(checkbox))
+            }
+            (ColumnType::Choice(choices), CellValue::Choice(selected))
+                if !choices.is_empty() =>
+            {
+                let mut combo = Combo::new(name);
+                combo.set_choices(choices.iter().map(AsRef::as_ref).collect());
+                combo.set_selected((*selected).min(choices.len() - 1) as u32);
+                combo.set_opened();
+                combo.set_stretched();
+                Some(Editor::Combo(combo))
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the widget
+    fn widget(&self) -> &dyn Widget {
+        match self {
+            Editor::Input(input) => input,
+            Editor::CheckBox(checkbox) => checkbox,
+            Editor::Combo(combo) => combo,
+        }
+    }
+
+    /// Get the mutable widget
+    fn widget_mut(&mut self) -> &mut dyn Widget {
+        match self {
+            Editor::Input(input) => input,
+            Editor::CheckBox(checkbox) => checkbox,
+            Editor::Combo(combo) => combo,
+        }
+    }
+
+    /// Return the edited value, or None if it is not valid
+    fn value(&self, column: &Column) -> Option<CellValue> {
+        match (self, &column.column_type) {
+            (Editor::Input(input), ColumnType::Number) => input
+                .state()
+                .value()
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .map(CellValue::Number),
+            (Editor::Input(input), _) => {
+                Some(CellValue::Text(input.state().value().to_string()))
+            }
+            (Editor::CheckBox(checkbox), _) => {
+                Some(CellValue::Bool(checkbox.state().checked()))
+            }
+            (Editor::Combo(combo), _) => combo
+                .state()
+                .selection()
+                .map(|selected| CellValue::Choice(selected as usize)),
+        }
+    }
+
+    /// Return true if the edition ends once the editor has changed
+    fn done(&self) -> bool {
+        match self {
+            Editor::Input(_) => false,
+            Editor::CheckBox(_) => true,
+            Editor::Combo(combo) => !combo.state().opened(),
+        }
+    }
+}
+
+/// # A table of typed values
+///
+/// Clicking on the header of a sortable column sorts the rows, and dragging
+/// the right edge of a header resizes the column. The cells of editable
+/// columns are edited by double clicking on them: a TextInput edits text and
+/// numbers and the edition is validated with the Enter key or cancelled with
+/// the Escape key, a CheckBox edits booleans and a Combo edits choices.
+///
+/// The indexes of the rows given to the listener are the indexes in
+/// `rows()`, whatever the displayed order.
+///
+/// ## Fields
+///
+/// ```text
+/// name: String
+/// state: TableState
+/// editor: Option<(usize, usize, Editor)>
+/// listener: Option<Box<dyn TableListener>>
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// name: name.to_string()
+/// state:
+///     columns: vec![]
+///     rows: vec![]
+///     sort: None
+///     selected: vec![]
+///     anchor: None
+///     selection_mode: SelectionMode::Single
+///     disabled: false
+///     stretched: false
+///     style: "".to_string()
+///     tooltip: None
+/// editor: None
+/// listener: None
+/// ```
+///
+/// ## Style
+///
+/// ```text
+/// div.table[.disabled]
+///     table
+///         th.table-header[.sortable][.ascending][.descending]
+///             div.table-title
+///             div.table-handle
+///         tr.table-row[.selected]
+///             td.table-cell[.editable][.editing]
+///                 div.table-check[.checked]
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use neutrino::widgets::table::{
+///     CellValue,
+///     Column,
+///     ColumnType,
+///     Table,
+///     TableListener,
+///     TableState
+/// };
+///
+///
+/// struct Task {
+///     title: String,
+///     done: bool,
+/// }
+///
+/// struct Tasks {
+///     tasks: Vec<Task>,
+/// }
+///
+/// impl Tasks {
+///     fn new() -> Self {
+///         Self {
+///             tasks: vec![
+///                 Task { title: "Write".to_string(), done: false },
+///                 Task { title: "Review".to_string(), done: true },
+///             ]
+///         }
+///     }
+///
+///     fn set_done(&mut self, index: usize, done: bool) {
+///         self.tasks[index].done = done;
+///     }
+/// }
+///
+///
+/// struct MyTableListener {
+///     tasks: Rc<RefCell<Tasks>>,
+/// }
+///
+/// impl MyTableListener {
+///    pub fn new(tasks: Rc<RefCell<Tasks>>) -> Self {
+///        Self { tasks }
+///    }
+/// }
+///
+/// impl TableListener for MyTableListener {
+///     fn on_edit(&self, state: &TableState, row: usize, column: usize) {
+///         if let CellValue::Bool(done) = state.rows()[row][column] {
+///             self.tasks.borrow_mut().set_done(row, done);
+///         }
+///     }
+///
+///     fn on_update(&self, state: &mut TableState) {
+///         let rows = self
+///             .tasks
+///             .borrow()
+///             .tasks
+///             .iter()
+///             .map(|task| vec![
+///                 CellValue::Text(task.title.to_string()),
+///                 CellValue::Bool(task.done),
+///             ])
+///             .collect();
+///         state.set_rows(rows);
+///     }
+///
+///     fn on_select(&self, _state: &TableState) {}
+///
+///     fn on_sort(&self, _state: &TableState) {}
+/// }
+///
+///
+/// fn main() {
+///     let tasks = Rc::new(RefCell::new(Tasks::new()));
+///
+///     let my_listener = MyTableListener::new(Rc::clone(&tasks));
+///
+///     let mut done = Column::new("Done", ColumnType::Bool);
+///     done.set_editable(true);
+///
+///     let title = Column::new("Title", ColumnType::Text);
+///
+///     let mut my_table = Table::new("my_table");
+///     my_table.set_columns(vec![title, done]);
+///     my_table.set_listener(Box::new(my_listener));
+/// }
+/// ```
+pub struct Table {
+    name: String,
+    state: TableState,
+    editor: Option<(usize, usize, Editor)>,
+    listener: Option<Box<dyn TableListener>>,
+}
+
+impl Table {
+    /// Create a Table
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            state: TableState {
+                columns: vec![],
+                rows: vec![],
+                sort: None,
+                selected: vec![],
+                anchor: None,
+                selection_mode: SelectionMode::Single,
+                disabled: false,
+                stretched: false,
+                style: "".to_string(),
+                tooltip: None,
+            },
+            editor: None,
+            listener: None,
+        }
+    }
+
+    /// Set the columns
+    pub fn set_columns(&mut self, columns: Vec<Column>) {
+        self.state.set_columns(columns);
+    }
+
+    /// Set the rows
+    pub fn set_rows(&mut self, rows: Vec<Vec<CellValue>>) {
+        self.state.set_rows(rows);
+    }
+
+    /// Set the selection mode
+    pub fn set_selection_mode(&mut self, selection_mode: SelectionMode) {
+        self.state.set_selection_mode(selection_mode);
+    }
+
+    /// Set the disabled flag to true
+    pub fn set_disabled(&mut self) {
+        self.state.set_disabled(true);
+    }
+
+    /// Set the stretched flag to true
+    pub fn set_stretched(&mut self) {
+        self.state.set_stretched(true);
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &TableState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut TableState {
+        &mut self.state
+    }
+
+    /// Set the listener
+    pub fn set_listener(&mut self, listener: Box<dyn TableListener>) {
+        self.listener = Some(listener);
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.state.set_style(style);
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
+
+    /// Return the name of the editor
+    fn editor_name(&self) -> String {
+        format!("{}-editor", self.name)
+    }
+
+    /// Function triggered when a header has been clicked
+    fn on_sort(&mut self, index: usize) {
+        match self.state.columns.get(index) {
+            Some(column) if column.sortable() => (),
+            _ => return,
+        }
+        let order = match self.state.sort {
+            Some((i, SortOrder::Ascending)) if i == index => {
+                SortOrder::Descending
+            }
+            _ => SortOrder::Ascending,
+        };
+        self.state.sort = Some((index, order));
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_sort(&self.state);
+            }
+        }
+    }
+
+    /// Function triggered when a row has been clicked
+    fn on_select(&mut self, index: usize, toggle: bool, extend: bool) {
+        if index >= self.state.rows.len() {
+            return;
+        }
+        let selected = self.state.selected.clone();
+        self.state.select(index, toggle, extend);
+        if self.state.selected == selected {
+            return;
+        }
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_select(&self.state);
+            }
+        }
+    }
+
+    /// Function triggered when a cell has been double clicked
+    fn on_edit(&mut self, row: usize, column: usize) {
+        self.commit();
+        if self.editor.is_some() {
+            return;
+        }
+        let editor = match (
+            self.state.columns.get(column),
+            self.state.rows.get(row).and_then(|cells| cells.get(column)),
+        ) {
+            (Some(col), Some(value)) if col.editable() => {
+                Editor::new(&self.editor_name(), col, value)
+            }
+            _ => None,
+        };
+        self.editor = editor.map(|editor| (row, column, editor));
+    }
+
+    /// Close the editor and set the edited value
+    ///
+    /// The editor is kept open if the edited value is not valid.
+    fn commit(&mut self) {
+        let (row, column, editor) = match self.editor.take() {
+            Some(editor) => editor,
+            None => return,
+        };
+        let value = match self.state.columns.get(column) {
+            Some(col) => editor.value(col),
+            None => return,
+        };
+        let value = match value {
+            Some(value) => value,
+            None => {
+                self.editor = Some((row, column, editor));
+                return;
+            }
+        };
+        match self.state.rows.get(row).and_then(|cells| cells.get(column)) {
+            Some(cell) if cell != &value => (),
+            _ => return,
+        }
+        self.state.set_cell(row, column, value);
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_edit(&self.state, row, column);
+            }
+        }
+    }
+
+    /// Function triggered on keypress event
+    fn on_keys(&mut self, keys: &HashSet<Key>) {
+        if keys.contains(&Key::Enter) {
+            self.commit();
+        } else if keys.contains(&Key::Escape) {
+            self.editor = None;
+        }
+    }
+
+    /// Return the HTML representation of the header
+    fn eval_header(&self) -> String {
+        let mut html = "<colgroup>".to_string();
+        for column in self.state.columns.iter() {
+            html.push_str(&format!(
+                r#"<col style="width: {}px;">"#,
+                column.width()
+            ));
+        }
+        html.push_str("</colgroup><thead><tr>");
+        for (i, column) in self.state.columns.iter().enumerate() {
+            let sortable = if column.sortable() { "sortable" } else { "" };
+            let order = match self.state.sort {
+                Some((index, order)) if index == i => order.css(),
+                _ => "",
+            };
+            html.push_str(&format!(
+                r#"
+                <th class="table-header {} {}" onclick="{}">
+                    <div class="table-title">{}</div>
+                    <div class="table-handle"
+                        onmousedown="resize(event, '{}', {})"
+                    ></div>
+                </th>
+                "#,
+                sortable,
+                order,
+                Event::change_js(&self.name, &format!("'sort;{}'", i)),
+                column.title(),
+                self.name,
+                i,
+            ));
+        }
+        html.push_str("</tr></thead>");
+        html
+    }
+
+    /// Return the HTML representation of the cell at the given indexes
+    fn eval_cell(&self, row: usize, column: usize) -> String {
+        let col = &self.state.columns[column];
+        let editable = if col.editable() { "editable" } else { "" };
+        let content = match (&self.editor, self.state.rows[row].get(column)) {
+            (Some((r, c, editor)), _) if *r == row && *c == column => {
+                return format!(
+                    r#"<td class="table-cell editing">{}</td>"#,
+                    editor.widget().eval()
+                );
+            }
+            (_, Some(CellValue::Bool(checked))) => format!(
+                r#"<div class="table-check {}"></div>"#,
+                if *checked { "checked" } else { "" }
+            ),
+            (_, Some(value)) => col.text(value),
+            (_, None) => "".to_string(),
+        };
+        format!(
+            r#"<td class="table-cell {}" ondblclick="{}">{}</td>"#,
+            editable,
+            Event::change_js(&self.name, &format!("'edit;{};{}'", row, column)),
+            content,
+        )
+    }
+}
+
+impl Widget for Table {
+    fn eval(&self) -> String {
+        let stretched = if self.state.stretched() {
+            "stretched"
+        } else {
+            ""
+        };
+        let disabled = if self.state.disabled() {
+            "disabled"
+        } else {
+            ""
+        };
+        let style = inline_style(&scss_to_css(&format!(
+            r##"#{}{{{}}}"##,
+            self.name,
+            self.state.style(),
+        )));
+        let mut html = format!(
+            r#"
+            <div id="{}" class="table {} {}" onkeydown="{}">
+                <table>{}<tbody>
+            "#,
+            self.name,
+            disabled,
+            stretched,
+            Event::keypress_js(&self.name, "down"),
+            self.eval_header(),
+        );
+        for row in self.state.order() {
+            let selected = if self.state.selected.contains(&row) {
+                "selected"
+            } else {
+                ""
+            };
+            html.push_str(&format!(
+                r#"<tr class="table-row {}" onclick="{}">"#,
+                selected,
+                Event::change_js(
+                    &self.name,
+                    &format!(
+                        "'click;{};' + event.ctrlKey + ';' + event.shiftKey",
+                        row
+                    )
+                ),
+            ));
+            for column in 0..self.state.columns.len() {
+                html.push_str(&self.eval_cell(row, column));
+            }
+            html.push_str("</tr>");
+        }
+        html.push_str("</tbody></table></div>");
+        format!("{}{}", style, html)
+    }
+
+    fn trigger(&mut self, event: &Event) {
+        let editor_name = self.editor_name();
+        if let Some((_, _, editor)) = &mut self.editor {
+            editor.widget_mut().trigger(event);
+            match event {
+                Event::Change { source, .. }
+                | Event::Keypress { source, .. }
+                    if source == &editor_name && editor.done() =>
+                {
+                    self.commit();
+                }
+                _ => (),
+            }
+        }
+        match event {
+            Event::Update => self.on_update(),
+            Event::Change { source, value } => {
+                if source == &self.name && !self.state.disabled() {
+                    self.on_change(value);
+                }
+            }
+            Event::Keypress { source, keys } => {
+                if source == &self.name && !self.state.disabled() {
+                    self.on_keys(keys);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn on_update(&mut self) {
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_update(&mut self.state);
+            }
+        }
+    }
+
+    fn on_change(&mut self, value: &str) {
+        let values = value.split(';').collect::<Vec<&str>>();
+        match values.as_slice() {
+            ["sort", index] => {
+                self.commit();
+                if let Ok(index) = index.parse::<usize>() {
+                    self.on_sort(index);
+                }
+            }
+            ["resize", index, width] => {
+                if let (Ok(index), Ok(width)) =
+                    (index.parse::<usize>(), width.parse::<f64>())
+                {
+                    if let Some(column) = self.state.columns.get_mut(index) {
+                        column.set_width(width.max(0.0) as u32);
+                    }
+                }
+            }
+            ["click", index, control, shift] => {
+                if let Ok(index) = index.parse::<usize>() {
+                    match &self.editor {
+                        Some((row, _, _)) if *row == index => (),
+                        _ => self.commit(),
+                    }
+                    self.on_select(index, *control == "true", *shift == "true");
+                }
+            }
+            ["edit", row, column] => {
+                if let (Ok(row), Ok(column)) =
+                    (row.parse::<usize>(), column.parse::<usize>())
+                {
+                    self.on_edit(row, column);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> Table {
+        let mut table = Table::new("table");
+        table.set_columns(vec![Column::new("Title", ColumnType::Text)]);
+        table.set_rows(
+            (0..4)
+                .map(|i| vec![CellValue::Text(i.to_string())])
+                .collect(),
+        );
+        table.set_selection_mode(SelectionMode::Multiple);
+        table
+    }
+
+    #[test]
+    fn single_selection_keeps_the_anchor_row() {
+        let mut table = table();
+        table.state.select(1, false, false);
+        table.state.select(3, true, false);
+        table.state.select(2, true, false);
+        table.set_selection_mode(SelectionMode::Single);
+        assert_eq!(table.state().selected(), &vec![2]);
+    }
+
+    #[test]
+    fn single_selection_keeps_the_first_selected_row() {
+        let mut table = table();
+        table.state_mut().set_selected(vec![3, 1]);
+        table.set_selection_mode(SelectionMode::Single);
+        assert_eq!(table.state().selected(), &vec![1]);
+    }
+}
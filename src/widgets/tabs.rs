@@ -1,5 +1,6 @@
 use crate::utils::event::Event;
 use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
 use crate::widgets::container::Direction;
 use crate::widgets::widget::Widget;
 
@@ -14,6 +15,7 @@ use crate::widgets::widget::Widget;
 /// direction: Direction
 /// stretched: bool
 /// style: String
+/// tooltip: Option<Tooltip>
 /// ```
 pub struct TabsState {
     titles: Vec<String>,
@@ -22,6 +24,7 @@ pub struct TabsState {
     direction: Direction,
     stretched: bool,
     style: String,
+    tooltip: Option<Tooltip>,
 }
 
 impl TabsState {
@@ -55,6 +58,11 @@ impl TabsState {
         &self.style
     }
 
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
     /// Set the titles
     pub fn set_titles(&mut self, titles: Vec<&str>) {
         self.titles = titles
@@ -88,11 +96,109 @@ impl TabsState {
         self.style = style.to_string();
     }
 
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
+
     /// Add a tab
-    fn add(&mut self, name: &str, child: Box<dyn Widget>) {
+    pub fn add(&mut self, name: &str, child: Box<dyn Widget>) {
         self.titles.push(name.to_string());
         self.children.push(child);
     }
+
+    /// Insert a tab at the given index
+    pub fn insert(&mut self, index: usize, name: &str, child: Box<dyn Widget>) {
+        let index = index.min(self.children.len());
+        if !self.children.is_empty() && index as u32 <= self.selected {
+            self.selected += 1;
+        }
+        self.titles.insert(index, name.to_string());
+        self.children.insert(index, child);
+    }
+
+    /// Remove the tab whose child has the given name
+    pub fn remove(&mut self, name: &str) -> Option<Box<dyn Widget>> {
+        let index = self.index(name)?;
+        self.titles.remove(index);
+        let child = self.children.remove(index);
+        let len = self.children.len() as u32;
+        if (index as u32) < self.selected || self.selected >= len {
+            self.selected = self.selected.saturating_sub(1);
+        }
+        Some(child)
+    }
+
+    /// Move the tab whose child has the given name to the given index
+    pub fn move_to(&mut self, name: &str, index: usize) {
+        if let Some(from) = self.index(name) {
+            let to = index.min(self.children.len() - 1);
+            let title = self.titles.remove(from);
+            let child = self.children.remove(from);
+            self.titles.insert(to, title);
+            self.children.insert(to, child);
+            let (from, to) = (from as u32, to as u32);
+            if self.selected == from {
+                self.selected = to;
+            } else if from < self.selected && to >= self.selected {
+                self.selected -= 1;
+            } else if from > self.selected && to <= self.selected {
+                self.selected += 1;
+            }
+        }
+    }
+
+    /// Get the index of the tab whose child has the given name
+    pub fn index(&self, name: &str) -> Option<usize> {
+        self.children.iter().position(|child| child.name() == name)
+    }
+
+    /// Set the tabs from a list of items
+    ///
+    /// Each item is identified by a key which is used as the name of its
+    /// child, and is given a title by `title`. The children whose name
+    /// matches a key are kept with their state and listener, the missing ones
+    /// are created by `build` from the key and the item, and the tabs whose
+    /// name matches no key are removed. The tabs are ordered as the items and
+    /// the selected tab stays selected if it is kept.
+    pub fn set_children_from<T, K, N, B>(
+        &mut self,
+        items: &[T],
+        key: K,
+        title: N,
+        build: B,
+    ) where
+        K: Fn(&T) -> String,
+        N: Fn(&T) -> String,
+        B: Fn(&str, &T) -> Box<dyn Widget>,
+    {
+        let selected = self
+            .children
+            .get(self.selected as usize)
+            .map(|child| child.name().to_string());
+        let mut titles = vec![];
+        let mut children = vec![];
+        for item in items {
+            let name = key(item);
+            let child = match self.index(&name) {
+                Some(index) => {
+                    self.titles.remove(index);
+                    self.children.remove(index)
+                }
+                None => build(&name, item),
+            };
+            titles.push(title(item));
+            children.push(child);
+        }
+        self.titles = titles;
+        self.children = children;
+        self.selected = match selected.and_then(|name| self.index(&name)) {
+            Some(index) => index as u32,
+            None => self
+                .selected
+                .min((self.children.len() as u32).saturating_sub(1)),
+        };
+    }
 }
 
 /// # The listener of a Tabs
@@ -125,6 +231,7 @@ pub trait TabsListener {
 ///     direction: Direction::Horizontal
 ///     stretched: false
 ///     style: "".to_string()
+///     tooltip: None
 /// listener: None
 /// ```
 ///
@@ -230,6 +337,7 @@ impl Tabs {
                 direction: Direction::Horizontal,
                 stretched: false,
                 style: "".to_string(),
+                tooltip: None,
             },
             listener: None,
         }
@@ -271,6 +379,16 @@ impl Tabs {
         self.state.set_stretched(true);
     }
 
+    /// Get the state
+    pub fn state(&self) -> &TabsState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut TabsState {
+        &mut self.state
+    }
+
     /// Set the listener
     pub fn set_listener(&mut self, listener: Box<dyn TabsListener>) {
         self.listener = Some(listener);
@@ -281,6 +399,11 @@ impl Tabs {
         self.state.set_style(style);
     }
 
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
+
     /// Add a tab
     pub fn add(&mut self, name: &str, child: Box<dyn Widget>) {
         self.state.add(name, child);
@@ -332,30 +455,36 @@ impl Widget for Tabs {
         }
         html.push_str(&format!(
             r#"</div><div class="tab">{}</div></div>"#,
-            self.state.children[self.state.selected() as usize].eval()
+            match self.state.children.get(self.state.selected() as usize) {
+                Some(child) => child.eval(),
+                None => "".to_string(),
+            }
         ));
         format!("{}{}", style, html)
     }
 
     fn trigger(&mut self, event: &Event) {
+        let selected = self.state.selected as usize;
         match event {
             Event::Update => {
-                self.state.children[self.state.selected as usize]
-                    .trigger(event);
+                if let Some(child) = self.state.children.get_mut(selected) {
+                    child.trigger(event);
+                }
                 self.on_update()
             }
             Event::Change { source, value } => {
                 if source == &self.name {
                     self.on_change(value);
-                } else {
-                    self.state.children[self.state.selected as usize]
-                        .trigger(event);
+                } else if let Some(child) =
+                    self.state.children.get_mut(selected)
+                {
+                    child.trigger(event);
                 };
             }
             _ => {
-                self.state.children[self.state.selected as usize].trigger(
-                    event
-                )
+                if let Some(child) = self.state.children.get_mut(selected) {
+                    child.trigger(event);
+                }
             }
         }
     }
@@ -381,4 +510,28 @@ impl Widget for Tabs {
             }
         }
     }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+
+    fn children(&self) -> Vec<&dyn Widget> {
+        self.state
+            .children
+            .iter()
+            .map(|child| child.as_ref())
+            .collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Widget> {
+        self.state
+            .children
+            .iter_mut()
+            .map(|child| child.as_mut() as &mut dyn Widget)
+            .collect()
+    }
 }
@@ -0,0 +1,825 @@
+use crate::utils::event::Event;
+use crate::utils::html::escape;
+use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
+use crate::widgets::widget::Widget;
+
+/// # The state of a TextArea
+///
+/// The selection offsets are counted in characters.
+///
+/// ## Fields
+///
+/// ```text
+/// value: String
+/// placeholder: String
+/// rows: u32
+/// wrap: bool
+/// selection_start: usize
+/// selection_end: usize
+/// caret: usize
+/// request: u32
+/// value_request: u32
+/// read_only: bool
+/// max_length: Option<usize>
+/// rich: bool
+/// disabled: bool
+/// stretched: bool
+/// style: String
+/// tooltip: Option<Tooltip>
+/// ```
+pub struct TextAreaState {
+    value: String,
+    placeholder: String,
+    rows: u32,
+    wrap: bool,
+    selection_start: usize,
+    selection_end: usize,
+    caret: usize,
+    request: u32,
+    value_request: u32,
+    read_only: bool,
+    max_length: Option<usize>,
+    rich: bool,
+    disabled: bool,
+    stretched: bool,
+    style: String,
+    tooltip: Option<Tooltip>,
+}
+
+impl TextAreaState {
+    /// Get the value
+    ///
+    /// In rich mode, the value is written in Markdown.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Get the placeholder
+    pub fn placeholder(&self) -> &str {
+        &self.placeholder
+    }
+
+    /// Get the number of visible rows
+    pub fn rows(&self) -> u32 {
+        self.rows
+    }
+
+    /// Get the wrap flag
+    pub fn wrap(&self) -> bool {
+        self.wrap
+    }
+
+    /// Get the start and the end of the selection
+    pub fn selection(&self) -> (usize, usize) {
+        (self.selection_start, self.selection_end)
+    }
+
+    /// Get the caret position
+    pub fn caret(&self) -> usize {
+        self.caret
+    }
+
+    /// Get the read only flag
+    pub fn read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Get the maximum length
+    pub fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    /// Get the rich flag
+    pub fn rich(&self) -> bool {
+        self.rich
+    }
+
+    /// Get the disabled flag
+    pub fn disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Get the stretched flag
+    pub fn stretched(&self) -> bool {
+        self.stretched
+    }
+
+    /// Get the style
+    pub fn style(&self) -> &str {
+        &self.style
+    }
+
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
+    /// Set the value
+    ///
+    /// The value is truncated to the maximum length. A value different from
+    /// the current one replaces the text typed in the editor, even if the
+    /// editor has the focus.
+    pub fn set_value(&mut self, value: &str) {
+        let previous = self.value.to_string();
+        self.assign(value);
+        if self.value != previous {
+            self.value_request += 1;
+        }
+    }
+
+    /// Set the value typed by the user, truncated to the maximum length
+    fn assign(&mut self, value: &str) {
+        self.value = match self.max_length {
+            Some(max_length) => value.chars().take(max_length).collect(),
+            None => value.to_string(),
+        };
+        let len = self.value.chars().count();
+        self.selection_start = self.selection_start.min(len);
+        self.selection_end = self.selection_end.min(len);
+        self.caret = self.caret.min(len);
+    }
+
+    /// Set the placeholder
+    pub fn set_placeholder(&mut self, placeholder: &str) {
+        self.placeholder = placeholder.to_string();
+    }
+
+    /// Set the number of visible rows
+    pub fn set_rows(&mut self, rows: u32) {
+        self.rows = rows;
+    }
+
+    /// Set the wrap flag
+    pub fn set_wrap(&mut self, wrap: bool) {
+        self.wrap = wrap;
+    }
+
+    /// Select the characters from `start` to `end`
+    ///
+    /// The caret is placed at `end`, so that a backward selection is made
+    /// when `end` is lower than `start`.
+    pub fn set_selection(&mut self, start: usize, end: usize) {
+        let len = self.value.chars().count();
+        self.selection_start = start.min(end).min(len);
+        self.selection_end = start.max(end).min(len);
+        self.caret = end.min(len);
+        self.request += 1;
+    }
+
+    /// Set the caret position and clear the selection
+    pub fn set_caret(&mut self, caret: usize) {
+        self.set_selection(caret, caret);
+    }
+
+    /// Set the read only flag
+    pub fn set_read_only(&mut self, read_only: bool) {
+        self.read_only = read_only;
+    }
+
+    /// Set the maximum length
+    pub fn set_max_length(&mut self, max_length: Option<usize>) {
+        self.max_length = max_length;
+        let value = self.value.to_string();
+        self.set_value(&value);
+    }
+
+    /// Set the rich flag
+    pub fn set_rich(&mut self, rich: bool) {
+        self.rich = rich;
+    }
+
+    /// Set the disabled flag
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+
+    /// Set the stretched flag
+    pub fn set_stretched(&mut self, stretched: bool) {
+        self.stretched = stretched;
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.style = style.to_string();
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
+}
+
+/// # The listener of a TextArea
+pub trait TextAreaListener {
+    /// Function triggered on update event
+    fn on_update(&self, state: &mut TextAreaState);
+
+    /// Function triggered on change event
+    ///
+    /// The value, the selection or the caret position has changed.
+    fn on_change(&self, state: &TextAreaState);
+}
+
+/// # A zone where multi-line text can be written
+///
+/// In rich mode, the text is edited with basic formatting (bold, italic,
+/// bulleted and numbered lists) and the value is written in Markdown:
+/// `**bold**`, `*italic*`, `- item` and `1. item`. The selection and the
+/// caret position are only tracked in plain mode.
+///
+/// While the editor has the focus, the text displayed is the one typed by
+/// the user, so that the characters typed during a render are not lost,
+/// unless the value is changed with `set_value`.
+///
+/// ## Fields
+///
+/// ```text
+/// name: String
+/// state: TextAreaState
+/// listener: Option<Box<dyn TextAreaListener>>
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// name: name.to_string()
+/// state:
+///     value: "".to_string()
+///     placeholder: "".to_string()
+///     rows: 4
+///     wrap: true
+///     selection_start: 0
+///     selection_end: 0
+///     caret: 0
+///     request: 0
+///     value_request: 0
+///     read_only: false
+///     max_length: None
+///     rich: false
+///     disabled: false
+///     stretched: false
+///     style: "".to_string()
+///     tooltip: None
+/// listener: None
+/// ```
+///
+/// ## Style
+///
+/// ```text
+/// div.textarea[.rich][.nowrap][.read-only][.disabled]
+///     textarea
+///     div.textarea-toolbar
+///         div.textarea-button
+///     div.textarea-editor
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use neutrino::widgets::textarea::{
+///     TextArea,
+///     TextAreaListener,
+///     TextAreaState
+/// };
+///
+///
+/// struct Note {
+///     content: String,
+/// }
+///
+/// impl Note {
+///     fn new() -> Self {
+///         Self { content: "**Shopping**\n- Milk\n- Eggs".to_string() }
+///     }
+///
+///     fn content(&self) -> &str {
+///         &self.content
+///     }
+///
+///     fn set_content(&mut self, content: &str) {
+///         self.content = content.to_string();
+///     }
+/// }
+///
+///
+/// struct MyTextAreaListener {
+///     note: Rc<RefCell<Note>>,
+/// }
+///
+/// impl MyTextAreaListener {
+///    pub fn new(note: Rc<RefCell<Note>>) -> Self {
+///        Self { note }
+///    }
+/// }
+///
+/// impl TextAreaListener for MyTextAreaListener {
+///     fn on_change(&self, state: &TextAreaState) {
+///         self.note.borrow_mut().set_content(state.value());
+///     }
+///
+///     fn on_update(&self, state: &mut TextAreaState) {
+///         state.set_value(self.note.borrow().content());
+///     }
+/// }
+///
+///
+/// fn main() {
+///     let note = Rc::new(RefCell::new(Note::new()));
+///
+///     let my_listener = MyTextAreaListener::new(Rc::clone(&note));
+///
+///     let mut my_textarea = TextArea::new("my_textarea");
+///     my_textarea.set_rows(10);
+///     my_textarea.set_max_length(2000);
+///     my_textarea.set_rich();
+///     my_textarea.set_listener(Box::new(my_listener));
+/// }
+/// ```
+pub struct TextArea {
+    name: String,
+    state: TextAreaState,
+    listener: Option<Box<dyn TextAreaListener>>,
+}
+
+impl TextArea {
+    /// Create a TextArea
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            state: TextAreaState {
+                value: "".to_string(),
+                placeholder: "".to_string(),
+                rows: 4,
+                wrap: true,
+                selection_start: 0,
+                selection_end: 0,
+                caret: 0,
+                request: 0,
+                value_request: 0,
+                read_only: false,
+                max_length: None,
+                rich: false,
+                disabled: false,
+                stretched: false,
+                style: "".to_string(),
+                tooltip: None,
+            },
+            listener: None,
+        }
+    }
+
+    /// Set the value
+    pub fn set_value(&mut self, value: &str) {
+        self.state.set_value(value);
+    }
+
+    /// Set the placeholder
+    pub fn set_placeholder(&mut self, placeholder: &str) {
+        self.state.set_placeholder(placeholder);
+    }
+
+    /// Set the number of visible rows
+    pub fn set_rows(&mut self, rows: u32) {
+        self.state.set_rows(rows);
+    }
+
+    /// Set the wrap flag to false
+    pub fn set_nowrap(&mut self) {
+        self.state.set_wrap(false);
+    }
+
+    /// Set the maximum length
+    pub fn set_max_length(&mut self, max_length: usize) {
+        self.state.set_max_length(Some(max_length));
+    }
+
+    /// Set the read only flag to true
+    pub fn set_read_only(&mut self) {
+        self.state.set_read_only(true);
+    }
+
+    /// Set the rich flag to true
+    pub fn set_rich(&mut self) {
+        self.state.set_rich(true);
+    }
+
+    /// Set the disabled flag to true
+    pub fn set_disabled(&mut self) {
+        self.state.set_disabled(true);
+    }
+
+    /// Set the stretched flag to true
+    pub fn set_stretched(&mut self) {
+        self.state.set_stretched(true);
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &TextAreaState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut TextAreaState {
+        &mut self.state
+    }
+
+    /// Set the listener
+    pub fn set_listener(&mut self, listener: Box<dyn TextAreaListener>) {
+        self.listener = Some(listener);
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.state.set_style(style);
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
+
+    /// Return the HTML representation of the plain editor
+    fn eval_plain(&self) -> String {
+        let disabled = if self.state.disabled() {
+            "disabled"
+        } else {
+            ""
+        };
+        let read_only = if self.state.read_only() {
+            "readonly"
+        } else {
+            ""
+        };
+        let max_length = match self.state.max_length() {
+            Some(max_length) => format!(r#"maxlength="{}""#, max_length),
+            None => "".to_string(),
+        };
+        let select =
+            Event::change_js(&self.name, "'select;' + selection(this)");
+        format!(
+            r#"
+            <textarea {} {} {} rows="{}" wrap="{}" placeholder="{}"
+                data-selection="{};{};{}" data-selection-request="{}"
+                data-value-request="{}"
+                oninput="{}" onselect="{}" onkeyup="{}" onmouseup="{}"
+            >{}</textarea>
+            "#,
+            disabled,
+            read_only,
+            max_length,
+            self.state.rows(),
+            if self.state.wrap() { "soft" } else { "off" },
+            escape(self.state.placeholder()),
+            self.state.selection_start,
+            self.state.selection_end,
+            self.state.caret,
+            self.state.request,
+            self.state.value_request,
+            Event::change_js(
+                &self.name,
+                "'input;' + selection(this) + ';' + this.value"
+            ),
+            select,
+            select,
+            select,
+            escape(&self.state.value),
+        )
+    }
+
+    /// Return the HTML representation of the rich editor
+    fn eval_rich(&self) -> String {
+        let editable = !self.state.disabled() && !self.state.read_only();
+        let mut html = "".to_string();
+        if editable {
+            html.push_str(r#"<div class="textarea-toolbar">"#);
+            let commands = [
+                ("bold", "B"),
+                ("italic", "I"),
+                ("insertUnorderedList", "&bull;"),
+                ("insertOrderedList", "1."),
+            ];
+            for (command, text) in commands.iter() {
+                html.push_str(&format!(
+                    r#"
+                    <div class="textarea-button textarea-{}"
+                        onmousedown="event.preventDefault();
+                            document.execCommand('{}')"
+                    >{}</div>
+                    "#,
+                    command, command, text,
+                ));
+            }
+            html.push_str("</div>");
+        }
+        html.push_str(&format!(
+            r#"
+            <div class="textarea-editor" contenteditable="{}"
+                data-placeholder="{}" data-value-request="{}"
+                style="min-height: {}em;" oninput="{}"
+            >{}</div>
+            "#,
+            editable,
+            escape(self.state.placeholder()),
+            self.state.value_request,
+            f64::from(self.state.rows()) * 1.5,
+            Event::change_js(&self.name, "'input;0;0;0;' + markdown(this)"),
+            markdown_to_html(&self.state.value),
+        ));
+        html
+    }
+
+    /// Set the selection from a value sent by javascript
+    fn select(&mut self, start: &str, end: &str, caret: &str) {
+        if let (Ok(start), Ok(end), Ok(caret)) =
+            (start.parse(), end.parse(), caret.parse())
+        {
+            self.state.selection_start = start;
+            self.state.selection_end = end;
+            self.state.caret = caret;
+        }
+    }
+}
+
+impl Widget for TextArea {
+    fn eval(&self) -> String {
+        let stretched = if self.state.stretched() {
+            "stretched"
+        } else {
+            ""
+        };
+        let disabled = if self.state.disabled() {
+            "disabled"
+        } else {
+            ""
+        };
+        let read_only = if self.state.read_only() {
+            "read-only"
+        } else {
+            ""
+        };
+        let rich = if self.state.rich() { "rich" } else { "" };
+        let nowrap = if self.state.wrap() { "" } else { "nowrap" };
+        let style = inline_style(&scss_to_css(&format!(
+            r##"#{}{{{}}}"##,
+            self.name,
+            self.state.style(),
+        )));
+        let html = format!(
+            r#"
+            <div id="{}" class="textarea {} {} {} {} {}">{}</div>
+            "#,
+            self.name,
+            rich,
+            nowrap,
+            read_only,
+            disabled,
+            stretched,
+            if self.state.rich() {
+                self.eval_rich()
+            } else {
+                self.eval_plain()
+            },
+        );
+        format!("{}{}", style, html)
+    }
+
+    fn trigger(&mut self, event: &Event) {
+        match event {
+            Event::Update => self.on_update(),
+            Event::Change { source, value } => {
+                if source == &self.name
+                    && !self.state.disabled()
+                    && !self.state.read_only()
+                {
+                    self.on_change(value);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn on_update(&mut self) {
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_update(&mut self.state);
+            }
+        }
+    }
+
+    fn on_change(&mut self, value: &str) {
+        let values = value.splitn(5, ';').collect::<Vec<&str>>();
+        match values.as_slice() {
+            ["input", start, end, caret, value] => {
+                self.state.assign(value);
+                if !self.state.rich() {
+                    self.select(start, end, caret);
+                }
+            }
+            ["select", start, end, caret] => {
+                let selection = self.state.selection();
+                let current = self.state.caret();
+                self.select(start, end, caret);
+                if self.state.selection() == selection
+                    && self.state.caret() == current
+                {
+                    return;
+                }
+            }
+            _ => return,
+        }
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_change(&self.state);
+            }
+        }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+}
+
+/// A part of an inline Markdown text
+enum Token {
+    Text(String),
+    Tag(&'static str),
+}
+
+/// Return the HTML representation of an inline Markdown text
+///
+/// The tags are always properly nested: when bold and italic texts overlap,
+/// the tag closed first is opened last, and a tag closed while another one is
+/// opened inside it closes and reopens that one.
+fn inline_to_html(text: &str) -> String {
+    let mut tokens = vec![];
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let c = match c {
+            '\\' => chars.next().unwrap_or('\\'),
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                tokens.push(Token::Tag("b"));
+                continue;
+            }
+            '*' => {
+                tokens.push(Token::Tag("i"));
+                continue;
+            }
+            c => c,
+        };
+        match tokens.last_mut() {
+            Some(Token::Text(text)) => text.push(c),
+            _ => tokens.push(Token::Text(c.to_string())),
+        }
+    }
+
+    let mut html = "".to_string();
+    let mut opened: Vec<&str> = vec![];
+    let mut i = 0;
+    while i < tokens.len() {
+        match tokens[i] {
+            Token::Text(ref text) => html.push_str(&escape(text)),
+            Token::Tag(tag) if opened.contains(&tag) => {
+                let position = opened.iter().rposition(|&t| t == tag).unwrap();
+                let inner = opened.split_off(position + 1);
+                for t in inner.iter().rev() {
+                    html.push_str(&format!("</{}>", t));
+                }
+                opened.pop();
+                html.push_str(&format!("</{}>", tag));
+                for t in inner {
+                    html.push_str(&format!("<{}>", t));
+                    opened.push(t);
+                }
+            }
+            Token::Tag(tag) => {
+                let next = match tokens.get(i + 1) {
+                    Some(Token::Tag(next)) if !opened.contains(next) => {
+                        Some(*next)
+                    }
+                    _ => None,
+                };
+                let closed_first = tokens[i + 1..].iter().skip(1).find_map(
+                    |token| match token {
+                        Token::Tag(t) => Some(*t),
+                        Token::Text(_) => None,
+                    },
+                );
+                let tags = match next {
+                    Some(next) if next != tag && closed_first == Some(tag) => {
+                        i += 1;
+                        vec![next, tag]
+                    }
+                    _ => vec![tag],
+                };
+                for t in tags {
+                    html.push_str(&format!("<{}>", t));
+                    opened.push(t);
+                }
+            }
+        }
+        i += 1;
+    }
+    for tag in opened.iter().rev() {
+        html.push_str(&format!("</{}>", tag));
+    }
+    html
+}
+
+/// Return the HTML representation of a Markdown text
+///
+/// Only bold and italic texts and bulleted and numbered lists are supported.
+fn markdown_to_html(markdown: &str) -> String {
+    let mut html = "".to_string();
+    let mut list: Option<&str> = None;
+    for line in markdown.lines() {
+        let trimmed = line.trim_start();
+        let digits = trimmed.chars().take_while(char::is_ascii_digit).count();
+        let (tag, text) =
+            if trimmed.starts_with("- ") || trimmed.starts_with("* ") {
+                (Some("ul"), &trimmed[2..])
+            } else if digits > 0 && trimmed[digits..].starts_with(". ") {
+                (Some("ol"), &trimmed[digits + 2..])
+            } else {
+                (None, line)
+            };
+        if list != tag {
+            if let Some(list) = list {
+                html.push_str(&format!("</{}>", list));
+            }
+            if let Some(tag) = tag {
+                html.push_str(&format!("<{}>", tag));
+            }
+            list = tag;
+        }
+        match tag {
+            Some(_) => {
+                html.push_str(&format!("<li>{}</li>", inline_to_html(text)))
+            }
+            None if text.is_empty() => html.push_str("<div><br></div>"),
+            None => {
+                html.push_str(&format!("<div>{}</div>", inline_to_html(text)))
+            }
+        }
+    }
+    if let Some(list) = list {
+        html.push_str(&format!("</{}>", list));
+    }
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escaped_list_markers_stay_in_paragraphs() {
+        assert_eq!(markdown_to_html("\\- a"), "<div>- a</div>");
+        assert_eq!(markdown_to_html("1\\. a"), "<div>1. a</div>");
+        assert_eq!(markdown_to_html("- a"), "<ul><li>a</li></ul>");
+        assert_eq!(markdown_to_html("1. a"), "<ol><li>a</li></ol>");
+    }
+
+    #[test]
+    fn overlapping_bold_and_italic_are_nested() {
+        assert_eq!(
+            markdown_to_html("***a** b*"),
+            "<div><i><b>a</b> b</i></div>"
+        );
+        assert_eq!(
+            markdown_to_html("***a* b**"),
+            "<div><b><i>a</i> b</b></div>"
+        );
+        assert_eq!(
+            markdown_to_html("**a *b** c*"),
+            "<div><b>a <i>b</i></b><i> c</i></div>"
+        );
+    }
+
+    #[test]
+    fn set_value_requests_a_render_of_the_value() {
+        let mut textarea = TextArea::new("textarea");
+        textarea.set_value("a");
+        assert!(textarea.eval().contains(r#"data-value-request="1""#));
+        textarea.trigger(&Event::Change {
+            source: "textarea".to_string(),
+            value: "input;0;0;0;ab".to_string(),
+        });
+        assert_eq!(textarea.state().value(), "ab");
+        textarea.set_value("ab");
+        textarea.set_rich();
+        assert!(textarea.eval().contains(r#"data-value-request="1""#));
+        textarea.set_value("b");
+        assert!(textarea.eval().contains(r#"data-value-request="2""#));
+    }
+}
@@ -1,9 +1,14 @@
 use crate::utils::event::Event;
+use crate::utils::html::escape;
 use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
+use crate::utils::validation::{Field, FieldValue, Validation, Validator};
 use crate::widgets::widget::Widget;
 
 /// # The state of a TextInput
 ///
+/// The selection offsets are counted in characters.
+///
 /// ## Fields
 ///
 /// ```text
@@ -11,18 +16,32 @@ use crate::widgets::widget::Widget;
 /// input_type: InputType,
 /// placeholder: String
 /// size: u32
+/// selection_start: usize
+/// selection_end: usize
+/// caret: usize
+/// request: u32
+/// value_request: u32
+/// validation: Validation
 /// disabled: bool
 /// stretched: bool
 /// style: String
+/// tooltip: Option<Tooltip>
 /// ```
 pub struct TextInputState {
     value: String,
     input_type: InputType,
     placeholder: String,
     size: u32,
+    selection_start: usize,
+    selection_end: usize,
+    caret: usize,
+    request: u32,
+    value_request: u32,
+    validation: Validation,
     disabled: bool,
     stretched: bool,
     style: String,
+    tooltip: Option<Tooltip>,
 }
 
 impl TextInputState {
@@ -46,6 +65,21 @@ impl TextInputState {
         self.size
     }
 
+    /// Get the start and the end of the selection
+    pub fn selection(&self) -> (usize, usize) {
+        (self.selection_start, self.selection_end)
+    }
+
+    /// Get the caret position
+    pub fn caret(&self) -> usize {
+        self.caret
+    }
+
+    /// Get the validation
+    pub fn validation(&self) -> &Validation {
+        &self.validation
+    }
+
     /// Get the disabled flag
     pub fn disabled(&self) -> bool {
         self.disabled
@@ -61,9 +95,29 @@ impl TextInputState {
         &self.style
     }
 
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
     /// Set the value
+    ///
+    /// A value different from the current one replaces the text typed in
+    /// the input, even if the input has the focus.
     pub fn set_value(&mut self, value: &str) {
+        if self.value != value {
+            self.value_request += 1;
+        }
+        self.assign(value);
+    }
+
+    /// Set the value typed by the user
+    fn assign(&mut self, value: &str) {
         self.value = value.to_string();
+        let len = self.value.chars().count();
+        self.selection_start = self.selection_start.min(len);
+        self.selection_end = self.selection_end.min(len);
+        self.caret = self.caret.min(len);
     }
 
     /// Set the input_type
@@ -81,6 +135,23 @@ impl TextInputState {
         self.size = size;
     }
 
+    /// Select the characters from `start` to `end`
+    ///
+    /// The caret is placed at `end`, so that a backward selection is made
+    /// when `end` is lower than `start`.
+    pub fn set_selection(&mut self, start: usize, end: usize) {
+        let len = self.value.chars().count();
+        self.selection_start = start.min(end).min(len);
+        self.selection_end = start.max(end).min(len);
+        self.caret = end.min(len);
+        self.request += 1;
+    }
+
+    /// Set the caret position and clear the selection
+    pub fn set_caret(&mut self, caret: usize) {
+        self.set_selection(caret, caret);
+    }
+
     /// Set the disabled flag
     pub fn set_disabled(&mut self, disabled: bool) {
         self.disabled = disabled;
@@ -95,6 +166,11 @@ impl TextInputState {
     pub fn set_style(&mut self, style: &str) {
         self.style = style.to_string();
     }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
 }
 
 /// # The listener of a TextInput
@@ -103,11 +179,17 @@ pub trait TextInputListener {
     fn on_update(&self, state: &mut TextInputState);
 
     /// Function triggered on change event
+    ///
+    /// The value, the selection or the caret position has changed.
     fn on_change(&self, state: &TextInputState);
 }
 
 /// # A zone where text can be written.
 ///
+/// While the input has the focus, the value displayed is the one typed by
+/// the user, so that the characters typed during a render are not lost,
+/// unless the value is changed with `set_value`.
+///
 /// ## Fields
 ///
 /// ```text
@@ -125,17 +207,25 @@ pub trait TextInputListener {
 ///     input_type: InputType::Text
 ///     placeholder: "".to_string()
 ///     size: 10
+///     selection_start: 0
+///     selection_end: 0
+///     caret: 0
+///     request: 0
+///     value_request: 0
+///     validation: Validation::new()
 ///     disabled: false
 ///     stretched: false
 ///     style: "".to_string()
+///     tooltip: None
 /// listener: None
 /// ```
 ///
 /// ## Style
 ///
 /// ```text
-/// div.textinput[.disabled]
+/// div.textinput[.disabled][.invalid]
 ///     input[.focus]
+///     div.validation-error
 /// ```
 ///
 ///
@@ -219,9 +309,16 @@ impl TextInput {
                 input_type: InputType::Text,
                 placeholder: "".to_string(),
                 size: 10,
+                selection_start: 0,
+                selection_end: 0,
+                caret: 0,
+                request: 0,
+                value_request: 0,
+                validation: Validation::new(),
                 disabled: false,
                 stretched: false,
                 style: "".to_string(),
+                tooltip: None,
             },
             listener: None,
         }
@@ -257,6 +354,21 @@ impl TextInput {
         self.state.set_disabled(true);
     }
 
+    /// Add a validator
+    pub fn add_validator(&mut self, validator: Validator) {
+        self.state.validation.add(validator);
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &TextInputState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut TextInputState {
+        &mut self.state
+    }
+
     /// Set the listener
     pub fn set_listener(&mut self, listener: Box<dyn TextInputListener>) {
         self.listener = Some(listener);
@@ -266,6 +378,11 @@ impl TextInput {
     pub fn set_style(&mut self, style: &str) {
         self.state.set_style(style);
     }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
 }
 
 impl Widget for TextInput {
@@ -285,24 +402,42 @@ impl Widget for TextInput {
             self.name,
             self.state.style(),
         )));
+        let select =
+            Event::change_js(&self.name, "'select;' + selection(this)");
         let html = format!(
             r#"
-            <div id="{}" class="textinput {} {}">
+            <div id="{}" class="textinput {} {} {}">
                 <input {} type="{}" size="{}" maxlength="{}" 
-                placeholder="{}"  value="{}" onchange="{}" oninput="{}" />
+                placeholder="{}"  value="{}" oninput="{}"
+                data-value-request="{}"
+                data-selection="{};{};{}" data-selection-request="{}"
+                onselect="{}" onkeyup="{}" onmouseup="{}" />
+                {}
             </div>
             "#,
             self.name,
             disabled,
             stretched,
+            self.state.validation().css(),
             disabled,
             self.state.input_type().css(),
             self.state.size(),
             self.state.size(),
-            self.state.placeholder(),
-            self.state.value(),
-            Event::change_js(&self.name, "value"),
-            Event::change_js(&self.name, "value"),
+            escape(self.state.placeholder()),
+            escape(self.state.value()),
+            Event::change_js(
+                &self.name,
+                "'input;' + selection(this) + ';' + this.value"
+            ),
+            self.state.value_request,
+            self.state.selection_start,
+            self.state.selection_end,
+            self.state.caret,
+            self.state.request,
+            select,
+            select,
+            select,
+            self.state.validation().eval(),
         );
         format!("{}{}", style, html)
     }
@@ -329,7 +464,29 @@ impl Widget for TextInput {
     }
 
     fn on_change(&mut self, value: &str) {
-        self.state.set_value(value);
+        let values = value.splitn(5, ';').collect::<Vec<&str>>();
+        let previous = (self.state.selection(), self.state.caret());
+        let (start, end, caret) = match values.as_slice() {
+            ["input", start, end, caret, value] => {
+                self.state.assign(value);
+                (start, end, caret)
+            }
+            ["select", start, end, caret] => (start, end, caret),
+            _ => return,
+        };
+        if let (Ok(start), Ok(end), Ok(caret)) =
+            (start.parse(), end.parse(), caret.parse())
+        {
+            self.state.selection_start = start;
+            self.state.selection_end = end;
+            self.state.caret = caret;
+        }
+        if values[0] == "select"
+            && (self.state.selection(), self.state.caret()) == previous
+        {
+            return;
+        }
+        self.validate();
         match &self.listener {
             None => (),
             Some(listener) => {
@@ -337,6 +494,37 @@ impl Widget for TextInput {
             }
         }
     }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+
+    fn field(&self) -> Option<&dyn Field> {
+        Some(self)
+    }
+
+    fn field_mut(&mut self) -> Option<&mut dyn Field> {
+        Some(self)
+    }
+}
+
+impl Field for TextInput {
+    fn value(&self) -> FieldValue {
+        FieldValue::Text(self.state.value().to_string())
+    }
+
+    fn validation(&self) -> &Validation {
+        self.state.validation()
+    }
+
+    fn validate(&mut self) -> bool {
+        let value = self.value();
+        self.state.validation.validate(&value)
+    }
 }
 
 pub enum InputType {
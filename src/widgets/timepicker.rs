@@ -0,0 +1,582 @@
+use std::collections::HashSet;
+
+use crate::utils::datetime::Time;
+use crate::utils::event::{Event, Key};
+use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
+use crate::utils::validation::{Field, FieldValue, Validation, Validator};
+use crate::widgets::widget::Widget;
+
+/// # The state of a TimePicker
+///
+/// ## Fields
+///
+/// ```text
+/// value: Option<Time>
+/// min: Time
+/// max: Time
+/// step: u32
+/// seconds: bool
+/// placeholder: String
+/// request: u32
+/// validation: Validation
+/// disabled: bool
+/// stretched: bool
+/// style: String
+/// tooltip: Option<Tooltip>
+/// ```
+pub struct TimePickerState {
+    value: Option<Time>,
+    min: Time,
+    max: Time,
+    step: u32,
+    seconds: bool,
+    placeholder: String,
+    request: u32,
+    validation: Validation,
+    disabled: bool,
+    stretched: bool,
+    style: String,
+    tooltip: Option<Tooltip>,
+}
+
+impl TimePickerState {
+    /// Get the value
+    pub fn value(&self) -> Option<Time> {
+        self.value
+    }
+
+    /// Get the min
+    pub fn min(&self) -> Time {
+        self.min
+    }
+
+    /// Get the max
+    pub fn max(&self) -> Time {
+        self.max
+    }
+
+    /// Get the step in seconds
+    pub fn step(&self) -> u32 {
+        self.step
+    }
+
+    /// Get the seconds flag
+    pub fn seconds(&self) -> bool {
+        self.seconds
+    }
+
+    /// Get the text displayed
+    pub fn text(&self) -> String {
+        self.value
+            .map(|time| time.format(self.seconds))
+            .unwrap_or_default()
+    }
+
+    /// Get the placeholder
+    pub fn placeholder(&self) -> &str {
+        &self.placeholder
+    }
+
+    /// Get the validation
+    pub fn validation(&self) -> &Validation {
+        &self.validation
+    }
+
+    /// Get the disabled flag
+    pub fn disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Get the stretched flag
+    pub fn stretched(&self) -> bool {
+        self.stretched
+    }
+
+    /// Get the style
+    pub fn style(&self) -> &str {
+        &self.style
+    }
+
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
+    /// Set the value
+    pub fn set_value(&mut self, value: Option<Time>) {
+        if value != self.value {
+            self.request += 1;
+        }
+        self.value = value;
+    }
+
+    /// Set the min
+    pub fn set_min(&mut self, min: Time) {
+        self.min = min;
+    }
+
+    /// Set the max
+    pub fn set_max(&mut self, max: Time) {
+        self.max = max;
+    }
+
+    /// Set the step in seconds
+    pub fn set_step(&mut self, step: u32) {
+        self.step = step;
+    }
+
+    /// Set the seconds flag
+    pub fn set_seconds(&mut self, seconds: bool) {
+        self.seconds = seconds;
+    }
+
+    /// Set the placeholder
+    pub fn set_placeholder(&mut self, placeholder: &str) {
+        self.placeholder = placeholder.to_string();
+    }
+
+    /// Set the disabled flag
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+
+    /// Set the stretched flag
+    pub fn set_stretched(&mut self, stretched: bool) {
+        self.stretched = stretched;
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.style = style.to_string();
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
+
+    /// Add a number of seconds, which can be negative, to the value, return
+    /// true if the value has changed
+    ///
+    /// The value is kept between the min and the max, an empty value
+    /// becoming the min.
+    pub fn shift(&mut self, seconds: i64) -> bool {
+        let previous = self.value;
+        let value = match self.value {
+            Some(value) => {
+                let target = i64::from(value.seconds()) + seconds;
+                let target = target
+                    .max(i64::from(self.min.seconds()))
+                    .min(i64::from(self.max.seconds()));
+                value.add_seconds(target - i64::from(value.seconds()))
+            }
+            None => self.min,
+        };
+        self.set_value(Some(value));
+        self.value != previous
+    }
+
+    /// Parse a text typed by the user, an empty text clearing the time
+    ///
+    /// Return None if the text is not a time in the `HH:MM` or `HH:MM:SS`
+    /// format or if the time is out of the bounds.
+    pub fn parse(&self, text: &str) -> Option<Option<Time>> {
+        if text.trim().is_empty() {
+            return Some(None);
+        }
+        match Time::parse(text) {
+            Some(time) if time >= self.min && time <= self.max => {
+                Some(Some(time))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// # The listener of a TimePicker
+pub trait TimePickerListener {
+    /// Function triggered on update event
+    fn on_update(&self, state: &mut TimePickerState);
+
+    /// Function triggered on change event
+    ///
+    /// The texts which are not times between the min and the max are
+    /// rejected and do not trigger this function.
+    fn on_change(&self, state: &TimePickerState);
+}
+
+/// # A field holding a time of the day
+///
+/// The time can be typed in the `HH:MM` or `HH:MM:SS` format, or changed by
+/// a step with the buttons, the mouse wheel and the Up and Down keys. The
+/// PageUp and PageDown keys change the time by an hour.
+///
+/// ## Fields
+///
+/// ```text
+/// name: String
+/// state: TimePickerState
+/// listener: Option<Box<dyn TimePickerListener>>
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// name: name.to_string()
+/// state:
+///     value: None
+///     min: Time::new(0, 0, 0).unwrap()
+///     max: Time::new(23, 59, 59).unwrap()
+///     step: 60
+///     seconds: false
+///     placeholder: "HH:MM".to_string()
+///     request: 0
+///     validation: Validation::new()
+///     disabled: false
+///     stretched: false
+///     style: "".to_string()
+///     tooltip: None
+/// listener: None
+/// ```
+///
+/// ## Style
+///
+/// ```text
+/// div.spinbox.timepicker[.disabled][.invalid]
+///     input
+///     div.spinbox-buttons
+///         div.spinbox-up
+///         div.spinbox-down
+///     div.validation-error
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use neutrino::utils::datetime::Time;
+/// use neutrino::widgets::timepicker::{
+///     TimePicker,
+///     TimePickerListener,
+///     TimePickerState
+/// };
+///
+///
+/// struct Alarm {
+///     time: Option<Time>,
+/// }
+///
+/// impl Alarm {
+///     fn new() -> Self {
+///         Self { time: Time::new(7, 30, 0) }
+///     }
+///
+///     fn time(&self) -> Option<Time> {
+///         self.time
+///     }
+///
+///     fn set_time(&mut self, time: Option<Time>) {
+///         self.time = time;
+///     }
+/// }
+///
+///
+/// struct MyTimePickerListener {
+///     alarm: Rc<RefCell<Alarm>>,
+/// }
+///
+/// impl MyTimePickerListener {
+///    pub fn new(alarm: Rc<RefCell<Alarm>>) -> Self {
+///        Self { alarm }
+///    }
+/// }
+///
+/// impl TimePickerListener for MyTimePickerListener {
+///     fn on_change(&self, state: &TimePickerState) {
+///         self.alarm.borrow_mut().set_time(state.value());
+///     }
+///
+///     fn on_update(&self, state: &mut TimePickerState) {
+///         state.set_value(self.alarm.borrow().time());
+///     }
+/// }
+///
+///
+/// fn main() {
+///     let alarm = Rc::new(RefCell::new(Alarm::new()));
+///
+///     let my_listener = MyTimePickerListener::new(Rc::clone(&alarm));
+///
+///     let mut my_timepicker = TimePicker::new("my_timepicker");
+///     my_timepicker.set_range(
+///         Time::new(6, 0, 0).unwrap(),
+///         Time::new(12, 0, 0).unwrap(),
+///     );
+///     my_timepicker.set_step(15 * 60);
+///     my_timepicker.set_listener(Box::new(my_listener));
+/// }
+/// ```
+pub struct TimePicker {
+    name: String,
+    state: TimePickerState,
+    listener: Option<Box<dyn TimePickerListener>>,
+}
+
+impl TimePicker {
+    /// Create a TimePicker
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            state: TimePickerState {
+                value: None,
+                min: Time::new(0, 0, 0).unwrap(),
+                max: Time::new(23, 59, 59).unwrap(),
+                step: 60,
+                seconds: false,
+                placeholder: "HH:MM".to_string(),
+                request: 0,
+                validation: Validation::new(),
+                disabled: false,
+                stretched: false,
+                style: "".to_string(),
+                tooltip: None,
+            },
+            listener: None,
+        }
+    }
+
+    /// Set the value
+    pub fn set_value(&mut self, value: Time) {
+        self.state.set_value(Some(value));
+    }
+
+    /// Set the min and the max
+    pub fn set_range(&mut self, min: Time, max: Time) {
+        self.state.set_min(min);
+        self.state.set_max(max);
+    }
+
+    /// Set the step in seconds
+    pub fn set_step(&mut self, step: u32) {
+        self.state.set_step(step);
+    }
+
+    /// Set the seconds flag to true, displaying the seconds
+    pub fn set_seconds(&mut self) {
+        self.state.set_seconds(true);
+        self.state.set_placeholder("HH:MM:SS");
+    }
+
+    /// Set the placeholder
+    pub fn set_placeholder(&mut self, placeholder: &str) {
+        self.state.set_placeholder(placeholder);
+    }
+
+    /// Set the disabled flag to true
+    pub fn set_disabled(&mut self) {
+        self.state.set_disabled(true);
+    }
+
+    /// Set the stretched flag to true
+    pub fn set_stretched(&mut self) {
+        self.state.set_stretched(true);
+    }
+
+    /// Add a validator
+    pub fn add_validator(&mut self, validator: Validator) {
+        self.state.validation.add(validator);
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &TimePickerState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut TimePickerState {
+        &mut self.state
+    }
+
+    /// Set the listener
+    pub fn set_listener(&mut self, listener: Box<dyn TimePickerListener>) {
+        self.listener = Some(listener);
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.state.set_style(style);
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
+
+    /// Function triggered on keypress event
+    fn on_keys(&mut self, keys: &HashSet<Key>) {
+        let step = i64::from(self.state.step());
+        let seconds = if keys.contains(&Key::Up) {
+            step
+        } else if keys.contains(&Key::Down) {
+            -step
+        } else if keys.contains(&Key::PageUp) {
+            3600
+        } else if keys.contains(&Key::PageDown) {
+            -3600
+        } else {
+            return;
+        };
+        self.on_shift(seconds);
+    }
+
+    /// Change the value by the given number of seconds
+    fn on_shift(&mut self, seconds: i64) {
+        if self.state.shift(seconds) {
+            self.on_value();
+        }
+    }
+
+    /// Validate the new value and notify the listener
+    fn on_value(&mut self) {
+        self.validate();
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_change(&self.state);
+            }
+        }
+    }
+}
+
+impl Widget for TimePicker {
+    fn eval(&self) -> String {
+        let stretched = if self.state.stretched() {
+            "stretched"
+        } else {
+            ""
+        };
+        let disabled = if self.state.disabled() {
+            "disabled"
+        } else {
+            ""
+        };
+        let style = inline_style(&scss_to_css(&format!(
+            r##"#{}{{{}}}"##,
+            self.name,
+            self.state.style(),
+        )));
+        let html = format!(
+            r#"
+            <div id="{}" class="spinbox timepicker {} {} {}"
+                onwheel="event.preventDefault(); {}"
+            >
+                <input {} type="text" value="{}" placeholder="{}"
+                    data-value-request="{}" onchange="{}" onblur="{}"
+                    onkeydown="if (spin(event)) {{ {} }}" />
+                <div class="spinbox-buttons">
+                    <div class="spinbox-up" onclick="{}">&#9650;</div>
+                    <div class="spinbox-down" onclick="{}">&#9660;</div>
+                </div>
+                {}
+            </div>
+            "#,
+            self.name,
+            disabled,
+            stretched,
+            self.state.validation().css(),
+            Event::change_js(
+                &self.name,
+                "'step;' + (event.deltaY < 0 ? 1 : -1)"
+            ),
+            disabled,
+            self.state.text(),
+            self.state.placeholder(),
+            self.state.request,
+            Event::change_js(&self.name, "'input;' + this.value"),
+            Event::change_js(&self.name, "'blur'"),
+            Event::keypress_js(&self.name, "down"),
+            Event::change_js(&self.name, "'step;1'"),
+            Event::change_js(&self.name, "'step;-1'"),
+            self.state.validation().eval(),
+        );
+        format!("{}{}", style, html)
+    }
+
+    fn trigger(&mut self, event: &Event) {
+        match event {
+            Event::Update => self.on_update(),
+            Event::Change { source, value } => {
+                if source == &self.name && !self.state.disabled() {
+                    self.on_change(value);
+                }
+            }
+            Event::Keypress { source, keys } => {
+                if source == &self.name && !self.state.disabled() {
+                    self.on_keys(keys);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn on_update(&mut self) {
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_update(&mut self.state);
+            }
+        }
+    }
+
+    fn on_change(&mut self, value: &str) {
+        let values = value.splitn(2, ';').collect::<Vec<&str>>();
+        match values.as_slice() {
+            ["input", text] => {
+                if let Some(value) = self.state.parse(text) {
+                    self.state.set_value(value);
+                    self.on_value();
+                }
+            }
+            ["step", steps] => {
+                if let Ok(steps) = steps.parse::<i64>() {
+                    self.on_shift(steps * i64::from(self.state.step()));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+
+    fn field(&self) -> Option<&dyn Field> {
+        Some(self)
+    }
+
+    fn field_mut(&mut self) -> Option<&mut dyn Field> {
+        Some(self)
+    }
+}
+
+impl Field for TimePicker {
+    fn value(&self) -> FieldValue {
+        FieldValue::Text(self.state.text())
+    }
+
+    fn validation(&self) -> &Validation {
+        self.state.validation()
+    }
+
+    fn validate(&mut self) -> bool {
+        let value = self.value();
+        self.state.validation.validate(&value)
+    }
+}
@@ -0,0 +1,559 @@
+use crate::utils::event::{Event, Key};
+use crate::utils::icon::Icon;
+use crate::utils::pixmap::Pixmap;
+use crate::utils::tooltip::Tooltip;
+use crate::widgets::widget::{eval_tooltips, Widget};
+
+/// # The state of a ToolBar
+///
+/// ## Fields
+///
+/// ```text
+/// clicked: Option<String>
+/// checked: Vec<String>
+/// disabled: Vec<String>
+/// opened: bool
+/// ```
+pub struct ToolBarState {
+    clicked: Option<String>,
+    checked: Vec<String>,
+    disabled: Vec<String>,
+    opened: bool,
+}
+
+impl ToolBarState {
+    /// Get the name of the last clicked button
+    pub fn clicked(&self) -> Option<&str> {
+        self.clicked.as_ref().map(String::as_ref)
+    }
+
+    /// Get the checked flag of a toggle button
+    pub fn checked(&self, name: &str) -> bool {
+        self.checked.iter().any(|checked| checked == name)
+    }
+
+    /// Get the disabled flag of a button
+    pub fn disabled(&self, name: &str) -> bool {
+        self.disabled.iter().any(|disabled| disabled == name)
+    }
+
+    /// Get the opened flag of the overflow menu
+    pub fn opened(&self) -> bool {
+        self.opened
+    }
+
+    /// Set the checked flag of a toggle button
+    pub fn set_checked(&mut self, name: &str, checked: bool) {
+        self.checked.retain(|other| other != name);
+        if checked {
+            self.checked.push(name.to_string());
+        }
+    }
+
+    /// Set the disabled flag of a button
+    pub fn set_disabled(&mut self, name: &str, disabled: bool) {
+        self.disabled.retain(|other| other != name);
+        if disabled {
+            self.disabled.push(name.to_string());
+        }
+    }
+
+    /// Set the opened flag of the overflow menu
+    pub fn set_opened(&mut self, opened: bool) {
+        self.opened = opened;
+    }
+}
+
+/// # The listener of a ToolBar
+pub trait ToolBarListener {
+    /// Function triggered on update event
+    fn on_update(&self, state: &mut ToolBarState);
+
+    /// Function triggered on change event
+    fn on_change(&self, state: &ToolBarState);
+}
+
+enum ToolItem {
+    Button(ToolButton),
+    Separator,
+    Widget(Box<dyn Widget>),
+}
+
+/// # A bar of tools docked under the menubar of a Window
+///
+/// A ToolBar holds buttons, separators and widgets such as a Combo or a
+/// TextInput. The buttons and separators which do not fit in the window are
+/// hidden, the buttons being moved to a menu opened by a chevron, while the
+/// widgets always stay visible.
+///
+/// ## Fields
+///
+/// ```text
+/// items: Vec<ToolItem>
+/// state: ToolBarState
+/// listener: Option<Box<dyn ToolBarListener>>
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// items: vec![]
+/// state:
+///     clicked: None
+///     checked: vec![]
+///     disabled: vec![]
+///     opened: false
+/// listener: None
+/// ```
+///
+/// ## Style
+///
+/// ```text
+/// div.toolbar
+///     div.toolbar-items
+///         div.toolbar-button[.toggle][.checked][.disabled][.overflowed]
+///             svg.icon | img
+///             span
+///         div.toolbar-separator[.overflowed]
+///         div.toolbar-widget
+///     div.toolbar-chevron[.shown]
+///     div.toolbar-menu[.opened]
+///         div.toolbar-menuitem[.checked][.disabled][.overflowed]
+///             svg.icon | img
+///             span
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use neutrino::utils::icon::BreezeIcon;
+/// use neutrino::utils::tooltip::Tooltip;
+/// use neutrino::widgets::combo::Combo;
+/// use neutrino::widgets::toolbar::{
+///     ToolBar,
+///     ToolBarListener,
+///     ToolBarState,
+///     ToolButton
+/// };
+/// use neutrino::Window;
+///
+///
+/// struct Editor {
+///     bold: bool,
+/// }
+///
+/// impl Editor {
+///     fn new() -> Self {
+///         Self { bold: false }
+///     }
+///
+///     fn bold(&self) -> bool {
+///         self.bold
+///     }
+///
+///     fn set_bold(&mut self, bold: bool) {
+///         self.bold = bold;
+///     }
+/// }
+///
+///
+/// struct MyToolBarListener {
+///     editor: Rc<RefCell<Editor>>,
+/// }
+///
+/// impl MyToolBarListener {
+///    pub fn new(editor: Rc<RefCell<Editor>>) -> Self {
+///        Self { editor }
+///    }
+/// }
+///
+/// impl ToolBarListener for MyToolBarListener {
+///     fn on_change(&self, state: &ToolBarState) {
+///         if state.clicked() == Some("bold") {
+///             self.editor.borrow_mut().set_bold(state.checked("bold"));
+///         }
+///     }
+///
+///     fn on_update(&self, state: &mut ToolBarState) {
+///         state.set_checked("bold", self.editor.borrow().bold());
+///     }
+/// }
+///
+///
+/// fn main() {
+///     let editor = Rc::new(RefCell::new(Editor::new()));
+///
+///     let my_listener = MyToolBarListener::new(Rc::clone(&editor));
+///
+///     let mut save = ToolButton::new("save");
+///     save.set_icon(Box::new(BreezeIcon::Check));
+///     save.set_tooltip(Tooltip::new("Save"));
+///
+///     let mut bold = ToolButton::new("bold");
+///     bold.set_text("Bold");
+///     bold.set_toggle();
+///
+///     let mut font = Combo::new("font");
+///     font.set_choices(vec!["Sans", "Serif"]);
+///
+///     let mut my_toolbar = ToolBar::new();
+///     my_toolbar.add_button(save);
+///     my_toolbar.add_separator();
+///     my_toolbar.add_button(bold);
+///     my_toolbar.add_widget(Box::new(font));
+///     my_toolbar.set_listener(Box::new(my_listener));
+///
+///     let mut my_window = Window::new();
+///     my_window.set_toolbar(my_toolbar);
+/// }
+/// ```
+pub struct ToolBar {
+    items: Vec<ToolItem>,
+    state: ToolBarState,
+    listener: Option<Box<dyn ToolBarListener>>,
+}
+
+impl Default for ToolBar {
+    fn default() -> Self {
+        Self {
+            items: vec![],
+            state: ToolBarState {
+                clicked: None,
+                checked: vec![],
+                disabled: vec![],
+                opened: false,
+            },
+            listener: None,
+        }
+    }
+}
+
+impl ToolBar {
+    /// Create a ToolBar
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add a button
+    pub fn add_button(&mut self, button: ToolButton) {
+        self.items.push(ToolItem::Button(button));
+    }
+
+    /// Add a separator
+    pub fn add_separator(&mut self) {
+        self.items.push(ToolItem::Separator);
+    }
+
+    /// Add a widget
+    pub fn add_widget(&mut self, widget: Box<dyn Widget>) {
+        self.items.push(ToolItem::Widget(widget));
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &ToolBarState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut ToolBarState {
+        &mut self.state
+    }
+
+    /// Set the listener
+    pub fn set_listener(&mut self, listener: Box<dyn ToolBarListener>) {
+        self.listener = Some(listener);
+    }
+
+    /// Get the widgets
+    pub fn widgets(&self) -> Vec<&dyn Widget> {
+        self.items
+            .iter()
+            .filter_map(|item| match item {
+                ToolItem::Widget(widget) => Some(widget.as_ref()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Get the mutable widgets
+    pub fn widgets_mut(&mut self) -> Vec<&mut dyn Widget> {
+        self.items
+            .iter_mut()
+            .filter_map(|item| match item {
+                ToolItem::Widget(widget) => {
+                    Some(widget.as_mut() as &mut dyn Widget)
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Return the HTML representation of the widget
+    pub fn eval(&self) -> String {
+        let mut items = "".to_string();
+        let mut menu = "".to_string();
+        for (i, item) in self.items.iter().enumerate() {
+            match item {
+                ToolItem::Button(button) => {
+                    items.push_str(&button.eval(i, &self.state));
+                    menu.push_str(&button.eval_menuitem(i, &self.state));
+                }
+                ToolItem::Separator => items.push_str(&format!(
+                    r#"<div class="toolbar-separator" data-index="{}"></div>"#,
+                    i
+                )),
+                ToolItem::Widget(widget) => items.push_str(&format!(
+                    r#"<div class="toolbar-widget" data-index="{}">{}</div>"#,
+                    i,
+                    widget.eval()
+                )),
+            }
+        }
+        let opened = if self.state.opened() { "opened" } else { "" };
+        format!(
+            r#"
+            <div class="toolbar" role="toolbar">
+                <div class="toolbar-items">{}</div>
+                <div class="toolbar-chevron" onclick="{}">»</div>
+                <div class="toolbar-menu {}">{}</div>
+            </div>
+            "#,
+            items,
+            Event::change_js("neutrino:toolbar", "'overflow'"),
+            opened,
+            menu,
+        )
+    }
+
+    /// Return the HTML representation of the tooltips of the buttons and
+    /// the widgets
+    pub fn eval_tooltips(&self) -> String {
+        let mut html = "".to_string();
+        for item in self.items.iter() {
+            match item {
+                ToolItem::Button(button) => {
+                    if let Some(tooltip) = &button.tooltip {
+                        html.push_str(&tooltip.eval(&button.name));
+                    }
+                }
+                ToolItem::Separator => (),
+                ToolItem::Widget(widget) => {
+                    html.push_str(&eval_tooltips(widget.as_ref()));
+                }
+            }
+        }
+        html
+    }
+
+    /// Trigger functions depending on the event
+    pub fn trigger(&mut self, event: &Event) {
+        for widget in self.widgets_mut() {
+            widget.trigger(event);
+        }
+        match event {
+            Event::Update => self.on_update(),
+            Event::Change { source, value } => {
+                if source == "neutrino:toolbar" {
+                    self.on_change(value);
+                } else {
+                    self.state.set_opened(false);
+                }
+            }
+            Event::Keypress { source, keys } => {
+                if source == "app" && keys.contains(&Key::Escape) {
+                    self.state.set_opened(false);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Function triggered on update event
+    fn on_update(&mut self) {
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_update(&mut self.state);
+            }
+        }
+    }
+
+    /// Function triggered on change event
+    fn on_change(&mut self, value: &str) {
+        let values = value.splitn(2, ';').collect::<Vec<&str>>();
+        match values.as_slice() {
+            ["overflow"] => {
+                self.state.set_opened(!self.state.opened());
+            }
+            ["click", name] => {
+                self.state.set_opened(false);
+                let toggle = self.items.iter().find_map(|item| match item {
+                    ToolItem::Button(button) if button.name == *name => {
+                        Some(button.toggle)
+                    }
+                    _ => None,
+                });
+                let toggle = match toggle {
+                    Some(toggle) if !self.state.disabled(name) => toggle,
+                    _ => return,
+                };
+                if toggle {
+                    let checked = self.state.checked(name);
+                    self.state.set_checked(name, !checked);
+                }
+                self.state.clicked = Some(name.to_string());
+                match &self.listener {
+                    None => (),
+                    Some(listener) => {
+                        listener.on_change(&self.state);
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+/// # A button of a ToolBar
+///
+/// A toggle button stays checked once clicked, until it is clicked again.
+/// A monochrome SVG icon is inlined and drawn with the text colour.
+///
+/// ## Fields
+///
+/// ```text
+/// name: String
+/// text: Option<String>
+/// icon: Option<Pixmap>
+/// monochrome: bool
+/// toggle: bool
+/// tooltip: Option<Tooltip>
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// name: name.to_string()
+/// text: None
+/// icon: None
+/// monochrome: false
+/// toggle: false
+/// tooltip: None
+/// ```
+pub struct ToolButton {
+    name: String,
+    text: Option<String>,
+    icon: Option<Pixmap>,
+    monochrome: bool,
+    toggle: bool,
+    tooltip: Option<Tooltip>,
+}
+
+impl ToolButton {
+    /// Create a ToolButton
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            text: None,
+            icon: None,
+            monochrome: false,
+            toggle: false,
+            tooltip: None,
+        }
+    }
+
+    /// Set the text
+    pub fn set_text(&mut self, text: &str) {
+        self.text = Some(text.to_string());
+    }
+
+    /// Set the icon
+    pub fn set_icon(&mut self, icon: Box<dyn Icon>) {
+        let mut pixmap = Pixmap::from_icon(icon);
+        pixmap.set_monochrome(self.monochrome);
+        self.icon = Some(pixmap);
+    }
+
+    /// Set the monochrome flag to true
+    pub fn set_monochrome(&mut self) {
+        self.monochrome = true;
+        if let Some(icon) = &mut self.icon {
+            icon.set_monochrome(true);
+        }
+    }
+
+    /// Set the toggle flag to true
+    pub fn set_toggle(&mut self) {
+        self.toggle = true;
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.tooltip = Some(tooltip);
+    }
+
+    /// Return the HTML representation of the icon and the text
+    fn eval_content(&self) -> String {
+        let icon = match &self.icon {
+            Some(icon) => icon.eval_icon(),
+            None => "".to_string(),
+        };
+        let text = match &self.text {
+            Some(text) => format!("<span>{}</span>", text),
+            None => "".to_string(),
+        };
+        format!("{}{}", icon, text)
+    }
+
+    /// Return the classes depending on the state
+    fn css(&self, state: &ToolBarState) -> String {
+        let mut classes = vec![];
+        if self.toggle {
+            classes.push("toggle");
+        }
+        if state.checked(&self.name) {
+            classes.push("checked");
+        }
+        if state.disabled(&self.name) {
+            classes.push("disabled");
+        }
+        classes.join(" ")
+    }
+
+    /// Return the HTML representation of the button
+    fn eval(&self, index: usize, state: &ToolBarState) -> String {
+        let click = format!("'click;{}'", self.name);
+        format!(
+            r#"
+            <div id="{}" class="toolbar-button {}" data-index="{}"
+                tabindex="0" onclick="{}"
+                onkeydown="if (event.key === 'Enter' || event.key === ' ') {{
+                    event.preventDefault(); this.click();
+                }}">{}</div>
+            "#,
+            self.name,
+            self.css(state),
+            index,
+            Event::change_js("neutrino:toolbar", &click),
+            self.eval_content(),
+        )
+    }
+
+    /// Return the HTML representation of the button in the overflow menu
+    fn eval_menuitem(&self, index: usize, state: &ToolBarState) -> String {
+        let click = format!("'click;{}'", self.name);
+        format!(
+            r#"
+            <div class="toolbar-menuitem {}" data-index="{}"
+                onclick="{}">{}</div>
+            "#,
+            self.css(state),
+            index,
+            Event::change_js("neutrino:toolbar", &click),
+            self.eval_content(),
+        )
+    }
+}
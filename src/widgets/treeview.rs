@@ -0,0 +1,923 @@
+use crate::utils::event::{Event, Key};
+use crate::utils::icon::Icon;
+use crate::utils::pixmap::Pixmap;
+use crate::utils::style::{inline_style, scss_to_css};
+use crate::utils::tooltip::Tooltip;
+use crate::widgets::listview::SelectionMode;
+use crate::widgets::widget::Widget;
+use std::collections::HashSet;
+
+/// # A node of a TreeView
+///
+/// ## Fields
+///
+/// ```text
+/// id: String
+/// text: String
+/// icon: Option<Pixmap>
+/// children: Vec<TreeNode>
+/// lazy: bool
+/// expanded: bool
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// id: id.to_string()
+/// text: text.to_string()
+/// icon: None
+/// children: vec![]
+/// lazy: false
+/// expanded: false
+/// ```
+pub struct TreeNode {
+    id: String,
+    text: String,
+    icon: Option<Pixmap>,
+    children: Vec<TreeNode>,
+    lazy: bool,
+    expanded: bool,
+}
+
+impl TreeNode {
+    /// Create a TreeNode
+    ///
+    /// The id has to be unique in the tree.
+    pub fn new(id: &str, text: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            text: text.to_string(),
+            icon: None,
+            children: vec![],
+            lazy: false,
+            expanded: false,
+        }
+    }
+
+    /// Get the id
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Get the text
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Get the icon
+    pub fn icon(&self) -> Option<&Pixmap> {
+        self.icon.as_ref()
+    }
+
+    /// Get the children
+    pub fn children(&self) -> &Vec<TreeNode> {
+        &self.children
+    }
+
+    /// Get the lazy flag
+    pub fn lazy(&self) -> bool {
+        self.lazy
+    }
+
+    /// Get the expanded flag
+    pub fn expanded(&self) -> bool {
+        self.expanded
+    }
+
+    /// Set the text
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.to_string();
+    }
+
+    /// Set the icon
+    pub fn set_icon(&mut self, icon: Box<dyn Icon>) {
+        self.icon = Some(Pixmap::from_icon(icon));
+    }
+
+    /// Add a child
+    pub fn add(&mut self, child: TreeNode) {
+        self.children.push(child);
+        self.lazy = false;
+    }
+
+    /// Set the children
+    pub fn set_children(&mut self, children: Vec<TreeNode>) {
+        self.children = children;
+        self.lazy = false;
+    }
+
+    /// Set the lazy flag to true
+    ///
+    /// A lazy node can be expanded although its children are not known yet:
+    /// they are set by the listener when the node is expanded for the first
+    /// time.
+    pub fn set_lazy(&mut self) {
+        self.lazy = true;
+    }
+
+    /// Set the expanded flag
+    pub fn set_expanded(&mut self, expanded: bool) {
+        self.expanded = expanded;
+    }
+
+    /// Return true if the node can be expanded
+    fn expandable(&self) -> bool {
+        self.lazy || !self.children.is_empty()
+    }
+
+    /// Find a node by id in the node and its descendants
+    fn find(&self, id: &str) -> Option<&TreeNode> {
+        if self.id == id {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.find(id))
+    }
+
+    /// Find a mutable node by id in the node and its descendants
+    fn find_mut(&mut self, id: &str) -> Option<&mut TreeNode> {
+        if self.id == id {
+            return Some(self);
+        }
+        self.children
+            .iter_mut()
+            .find_map(|child| child.find_mut(id))
+    }
+
+    /// Push the visible nodes and their depth
+    fn visible<'a>(&'a self, depth: usize, rows: &mut Vec<Row<'a>>) {
+        rows.push(Row { depth, node: self });
+        if self.expanded {
+            for child in self.children.iter() {
+                child.visible(depth + 1, rows);
+            }
+        }
+    }
+}
+
+/// A visible node and its depth
+struct Row<'a> {
+    depth: usize,
+    node: &'a TreeNode,
+}
+
+/// # The state of a TreeView
+///
+/// ## Fields
+///
+/// ```text
+/// nodes: Vec<TreeNode>
+/// selected: Vec<String>
+/// cursor: Option<String>
+/// anchor: Option<String>
+/// selection_mode: SelectionMode
+/// disabled: bool
+/// stretched: bool
+/// style: String
+/// tooltip: Option<Tooltip>
+/// ```
+pub struct TreeViewState {
+    nodes: Vec<TreeNode>,
+    selected: Vec<String>,
+    cursor: Option<String>,
+    anchor: Option<String>,
+    selection_mode: SelectionMode,
+    disabled: bool,
+    stretched: bool,
+    style: String,
+    tooltip: Option<Tooltip>,
+}
+
+impl TreeViewState {
+    /// Get the root nodes
+    pub fn nodes(&self) -> &Vec<TreeNode> {
+        &self.nodes
+    }
+
+    /// Get the ids of the selected nodes
+    pub fn selected(&self) -> &Vec<String> {
+        &self.selected
+    }
+
+    /// Get the id of the node having the keyboard focus
+    pub fn cursor(&self) -> Option<&str> {
+        self.cursor.as_ref().map(String::as_ref)
+    }
+
+    /// Get the selection mode
+    pub fn selection_mode(&self) -> &SelectionMode {
+        &self.selection_mode
+    }
+
+    /// Get the disabled flag
+    pub fn disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Get the stretched flag
+    pub fn stretched(&self) -> bool {
+        self.stretched
+    }
+
+    /// Get the style
+    pub fn style(&self) -> &str {
+        &self.style
+    }
+
+    /// Get the tooltip
+    pub fn tooltip(&self) -> Option<&Tooltip> {
+        self.tooltip.as_ref()
+    }
+
+    /// Find a node by id
+    pub fn node(&self, id: &str) -> Option<&TreeNode> {
+        self.nodes.iter().find_map(|node| node.find(id))
+    }
+
+    /// Find a mutable node by id
+    pub fn node_mut(&mut self, id: &str) -> Option<&mut TreeNode> {
+        self.nodes.iter_mut().find_map(|node| node.find_mut(id))
+    }
+
+    /// Set the root nodes
+    ///
+    /// The selected ids which are not in the new nodes are dropped.
+    pub fn set_nodes(&mut self, nodes: Vec<TreeNode>) {
+        self.nodes = nodes;
+        self.clean();
+    }
+
+    /// Set the children of the node having the given id
+    pub fn set_children(&mut self, id: &str, children: Vec<TreeNode>) {
+        if let Some(node) = self.node_mut(id) {
+            node.set_children(children);
+        }
+        self.clean();
+    }
+
+    /// Set the expanded flag of the node having the given id
+    pub fn set_expanded(&mut self, id: &str, expanded: bool) {
+        if let Some(node) = self.node_mut(id) {
+            node.set_expanded(expanded);
+        }
+    }
+
+    /// Set the ids of the selected nodes
+    pub fn set_selected(&mut self, selected: Vec<String>) {
+        self.selected = selected;
+        self.clean();
+        if let SelectionMode::Single = self.selection_mode {
+            self.selected.truncate(1);
+        }
+    }
+
+    /// Set the id of the node having the keyboard focus
+    pub fn set_cursor(&mut self, cursor: Option<&str>) {
+        self.cursor = cursor.map(str::to_string);
+        self.clean();
+    }
+
+    /// Set the selection mode
+    ///
+    /// Switching to single selection keeps only the node having the keyboard
+    /// focus if it is selected, the first selected node otherwise.
+    pub fn set_selection_mode(&mut self, selection_mode: SelectionMode) {
+        self.selection_mode = selection_mode;
+        if let SelectionMode::Single = self.selection_mode {
+            let kept = match &self.cursor {
+                Some(cursor) if self.selected.contains(cursor) => {
+                    Some(cursor.to_string())
+                }
+                _ => self.selected.first().cloned(),
+            };
+            self.selected = kept.iter().cloned().collect();
+            self.anchor = kept;
+        }
+    }
+
+    /// Set the disabled flag
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+
+    /// Set the stretched flag
+    pub fn set_stretched(&mut self, stretched: bool) {
+        self.stretched = stretched;
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.style = style.to_string();
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip;
+    }
+
+    /// Get the visible nodes and their depth
+    fn visible(&self) -> Vec<Row<'_>> {
+        let mut rows = vec![];
+        for node in self.nodes.iter() {
+            node.visible(0, &mut rows);
+        }
+        rows
+    }
+
+    /// Drop the ids which are not in the tree anymore
+    fn clean(&mut self) {
+        let selected = self
+            .selected
+            .iter()
+            .filter(|id| self.node(id).is_some())
+            .cloned()
+            .collect();
+        self.selected = selected;
+        if self.cursor.as_ref().and_then(|id| self.node(id)).is_none() {
+            self.cursor = None;
+        }
+        if self.anchor.as_ref().and_then(|id| self.node(id)).is_none() {
+            self.anchor = None;
+        }
+    }
+
+    /// Select the visible node at the given index
+    ///
+    /// In multiple selection mode, `toggle` adds or removes the node from the
+    /// selection and `extend` selects every visible node from the anchor to
+    /// the node.
+    fn select(&mut self, index: usize, toggle: bool, extend: bool) {
+        let ids = self
+            .visible()
+            .iter()
+            .map(|row| row.node.id().to_string())
+            .collect::<Vec<String>>();
+        let id = match ids.get(index) {
+            Some(id) => id.to_string(),
+            None => return,
+        };
+        let anchor = self
+            .anchor
+            .as_ref()
+            .and_then(|anchor| ids.iter().position(|id| id == anchor));
+        match (&self.selection_mode, anchor) {
+            (SelectionMode::Multiple, Some(anchor)) if extend => {
+                self.selected =
+                    ids[anchor.min(index)..=anchor.max(index)].to_vec();
+            }
+            (SelectionMode::Multiple, _) if toggle => {
+                match self.selected.iter().position(|i| i == &id) {
+                    Some(position) => {
+                        self.selected.remove(position);
+                    }
+                    None => self.selected.push(id.to_string()),
+                }
+                self.anchor = Some(id.to_string());
+            }
+            _ => {
+                self.selected = vec![id.to_string()];
+                self.anchor = Some(id.to_string());
+            }
+        }
+        self.cursor = Some(id);
+    }
+}
+
+/// # The listener of a TreeView
+pub trait TreeViewListener {
+    /// Function triggered on update event
+    fn on_update(&self, state: &mut TreeViewState);
+
+    /// Function triggered when a node has been expanded or collapsed
+    ///
+    /// The children of a lazy node are set here with `set_children`.
+    fn on_expand(&self, state: &mut TreeViewState, id: &str);
+
+    /// Function triggered when the selected nodes have changed
+    fn on_select(&self, state: &TreeViewState);
+
+    /// Function triggered when a node has been double clicked or the Enter
+    /// key has been pressed on it
+    fn on_activate(&self, state: &TreeViewState, id: &str);
+}
+
+/// # A tree of expandable nodes
+///
+/// The keyboard navigation follows the usual conventions: the Up, Down, Home
+/// and End keys move the focus, the Right key expands a node or moves to its
+/// first child, the Left key collapses a node or moves to its parent and the
+/// `*` key expands all the siblings of the focused node. In multiple
+/// selection mode, the Shift key extends the selection, the Control key
+/// moves the focus without selecting and the Space key toggles the
+/// selection of the focused node.
+///
+/// ## Fields
+///
+/// ```text
+/// name: String
+/// state: TreeViewState
+/// listener: Option<Box<dyn TreeViewListener>>
+/// ```
+///
+/// ## Default values
+///
+/// ```text
+/// name: name.to_string()
+/// state:
+///     nodes: vec![]
+///     selected: vec![]
+///     cursor: None
+///     anchor: None
+///     selection_mode: SelectionMode::Single
+///     disabled: false
+///     stretched: false
+///     style: "".to_string()
+///     tooltip: None
+/// listener: None
+/// ```
+///
+/// ## Style
+///
+/// ```text
+/// div.treeview[.disabled]
+///     div.treeview-row[.selected][.cursor]
+///         div.treeview-expander[.expandable][.expanded]
+///         img.treeview-icon
+///         span.treeview-text
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::fs;
+/// use std::rc::Rc;
+///
+/// use neutrino::widgets::treeview::{
+///     TreeNode,
+///     TreeView,
+///     TreeViewListener,
+///     TreeViewState
+/// };
+///
+///
+/// struct Browser {
+///     path: Option<String>,
+/// }
+///
+/// impl Browser {
+///     fn new() -> Self {
+///         Self { path: None }
+///     }
+///
+///     fn set_path(&mut self, path: &str) {
+///         self.path = Some(path.to_string());
+///     }
+/// }
+///
+///
+/// fn entries(path: &str) -> Vec<TreeNode> {
+///     let mut nodes = vec![];
+///     if let Ok(entries) = fs::read_dir(path) {
+///         for entry in entries.flatten() {
+///             let path = entry.path();
+///             let name = entry.file_name().to_string_lossy().to_string();
+///             let mut node = TreeNode::new(&path.to_string_lossy(), &name);
+///             if path.is_dir() {
+///                 node.set_lazy();
+///             }
+///             nodes.push(node);
+///         }
+///     }
+///     nodes
+/// }
+///
+///
+/// struct MyTreeViewListener {
+///     browser: Rc<RefCell<Browser>>,
+/// }
+///
+/// impl MyTreeViewListener {
+///    pub fn new(browser: Rc<RefCell<Browser>>) -> Self {
+///        Self { browser }
+///    }
+/// }
+///
+/// impl TreeViewListener for MyTreeViewListener {
+///     fn on_expand(&self, state: &mut TreeViewState, id: &str) {
+///         if let Some(true) = state.node(id).map(|node| node.lazy()) {
+///             state.set_children(id, entries(id));
+///         }
+///     }
+///
+///     fn on_activate(&self, _state: &TreeViewState, id: &str) {
+///         self.browser.borrow_mut().set_path(id);
+///     }
+///
+///     fn on_select(&self, _state: &TreeViewState) {}
+///
+///     fn on_update(&self, _state: &mut TreeViewState) {}
+/// }
+///
+///
+/// fn main() {
+///     let browser = Rc::new(RefCell::new(Browser::new()));
+///
+///     let my_listener = MyTreeViewListener::new(Rc::clone(&browser));
+///
+///     let mut root = TreeNode::new(".", "Project");
+///     root.set_lazy();
+///
+///     let mut my_treeview = TreeView::new("my_treeview");
+///     my_treeview.set_nodes(vec![root]);
+///     my_treeview.set_listener(Box::new(my_listener));
+/// }
+/// ```
+pub struct TreeView {
+    name: String,
+    state: TreeViewState,
+    listener: Option<Box<dyn TreeViewListener>>,
+}
+
+impl TreeView {
+    /// Create a TreeView
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            state: TreeViewState {
+                nodes: vec![],
+                selected: vec![],
+                cursor: None,
+                anchor: None,
+                selection_mode: SelectionMode::Single,
+                disabled: false,
+                stretched: false,
+                style: "".to_string(),
+                tooltip: None,
+            },
+            listener: None,
+        }
+    }
+
+    /// Set the root nodes
+    pub fn set_nodes(&mut self, nodes: Vec<TreeNode>) {
+        self.state.set_nodes(nodes);
+    }
+
+    /// Set the selection mode
+    pub fn set_selection_mode(&mut self, selection_mode: SelectionMode) {
+        self.state.set_selection_mode(selection_mode);
+    }
+
+    /// Set the disabled flag to true
+    pub fn set_disabled(&mut self) {
+        self.state.set_disabled(true);
+    }
+
+    /// Set the stretched flag to true
+    pub fn set_stretched(&mut self) {
+        self.state.set_stretched(true);
+    }
+
+    /// Get the state
+    pub fn state(&self) -> &TreeViewState {
+        &self.state
+    }
+
+    /// Get the mutable state
+    pub fn state_mut(&mut self) -> &mut TreeViewState {
+        &mut self.state
+    }
+
+    /// Set the listener
+    pub fn set_listener(&mut self, listener: Box<dyn TreeViewListener>) {
+        self.listener = Some(listener);
+    }
+
+    /// Set the style
+    pub fn set_style(&mut self, style: &str) {
+        self.state.set_style(style);
+    }
+
+    /// Set the tooltip
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.state.set_tooltip(Some(tooltip));
+    }
+
+    /// Expand or collapse the node having the given id
+    fn expand(&mut self, id: &str, expanded: bool) {
+        match self.state.node_mut(id) {
+            Some(node) if node.expandable() && node.expanded != expanded => {
+                node.set_expanded(expanded);
+            }
+            _ => return,
+        }
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_expand(&mut self.state, id);
+            }
+        }
+        if !expanded {
+            let cursor = self.state.cursor.clone();
+            if let Some(cursor) = cursor {
+                let hidden = match self.state.node(id) {
+                    Some(node) => cursor != id && node.find(&cursor).is_some(),
+                    None => false,
+                };
+                if hidden {
+                    self.state.cursor = Some(id.to_string());
+                }
+            }
+        }
+    }
+
+    /// Select the visible node at the given index
+    fn select(&mut self, index: usize, toggle: bool, extend: bool) {
+        let selected = self.state.selected.clone();
+        self.state.select(index, toggle, extend);
+        if self.state.selected == selected {
+            return;
+        }
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_select(&self.state);
+            }
+        }
+    }
+
+    /// Activate the node having the given id
+    fn activate(&mut self, id: &str) {
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_activate(&self.state, id);
+            }
+        }
+    }
+
+    /// Function triggered on keypress event
+    fn on_keys(&mut self, keys: &HashSet<Key>) {
+        let rows = self
+            .state
+            .visible()
+            .iter()
+            .map(|row| {
+                (
+                    row.depth,
+                    row.node.id().to_string(),
+                    row.node.expandable(),
+                    row.node.expanded(),
+                )
+            })
+            .collect::<Vec<(usize, String, bool, bool)>>();
+        if rows.is_empty() {
+            return;
+        }
+        let cursor =
+            self.state.cursor.as_ref().and_then(|cursor| {
+                rows.iter().position(|row| &row.1 == cursor)
+            });
+        let index = match cursor {
+            Some(index) => index,
+            None => {
+                self.select(0, false, false);
+                return;
+            }
+        };
+        let (depth, id, expandable, expanded) = rows[index].clone();
+        let target = if keys.contains(&Key::Down) {
+            Some((index + 1).min(rows.len() - 1))
+        } else if keys.contains(&Key::Up) {
+            Some(index.saturating_sub(1))
+        } else if keys.contains(&Key::Home) {
+            Some(0)
+        } else if keys.contains(&Key::End) {
+            Some(rows.len() - 1)
+        } else if keys.contains(&Key::Right) {
+            if expandable && !expanded {
+                self.expand(&id, true);
+                None
+            } else if expanded {
+                Some((index + 1).min(rows.len() - 1))
+            } else {
+                None
+            }
+        } else if keys.contains(&Key::Left) {
+            if expanded {
+                self.expand(&id, false);
+                None
+            } else {
+                rows[..index].iter().rposition(|row| row.0 + 1 == depth)
+            }
+        } else if keys.contains(&Key::Asterisk) {
+            let parent = rows[..index]
+                .iter()
+                .rposition(|row| row.0 + 1 == depth)
+                .map_or(0, |parent| parent + 1);
+            let siblings = rows[parent..]
+                .iter()
+                .take_while(|row| row.0 >= depth)
+                .filter(|row| row.0 == depth && row.2 && !row.3)
+                .map(|row| row.1.to_string())
+                .collect::<Vec<String>>();
+            for sibling in siblings {
+                self.expand(&sibling, true);
+            }
+            None
+        } else if keys.contains(&Key::Enter) {
+            self.activate(&id);
+            None
+        } else if keys.contains(&Key::Space) {
+            self.select(index, true, false);
+            None
+        } else {
+            None
+        };
+        match (target, &self.state.selection_mode) {
+            (Some(target), SelectionMode::Multiple)
+                if keys.contains(&Key::Control) =>
+            {
+                self.state.cursor = Some(rows[target].1.to_string());
+            }
+            (Some(target), _) => {
+                self.select(target, false, keys.contains(&Key::Shift));
+            }
+            (None, _) => (),
+        }
+    }
+}
+
+impl Widget for TreeView {
+    fn eval(&self) -> String {
+        let stretched = if self.state.stretched() {
+            "stretched"
+        } else {
+            ""
+        };
+        let disabled = if self.state.disabled() {
+            "disabled"
+        } else {
+            ""
+        };
+        let style = inline_style(&scss_to_css(&format!(
+            r##"#{}{{{}}}"##,
+            self.name,
+            self.state.style(),
+        )));
+        let mut html = format!(
+            r#"
+            <div id="{}" class="treeview {} {}" tabindex="0"
+                onkeydown="navigate(event); {}"
+            >
+            "#,
+            self.name,
+            disabled,
+            stretched,
+            Event::keypress_js(&self.name, "down"),
+        );
+        for (i, row) in self.state.visible().iter().enumerate() {
+            let node = row.node;
+            let selected = if self.state.selected.contains(&node.id) {
+                "selected"
+            } else {
+                ""
+            };
+            let cursor = if self.state.cursor() == Some(node.id()) {
+                "cursor"
+            } else {
+                ""
+            };
+            let expandable = if node.expandable() { "expandable" } else { "" };
+            let expanded = if node.expanded() { "expanded" } else { "" };
+            let icon = match node.icon() {
+                Some(icon) => format!(
+                    r#"
+                    <img class="treeview-icon" src="data:image/{};base64,{}" />
+                    "#,
+                    icon.extension(),
+                    icon.data(),
+                ),
+                None => "".to_string(),
+            };
+            html.push_str(&format!(
+                r#"
+                <div class="treeview-row {} {}" style="padding-left: {}em;"
+                    onclick="{}" ondblclick="{}"
+                >
+                    <div class="treeview-expander {} {}" onclick="{}"></div>
+                    {}
+                    <span class="treeview-text">{}</span>
+                </div>
+                "#,
+                selected,
+                cursor,
+                row.depth as f32 * 1.5,
+                Event::change_js(
+                    &self.name,
+                    &format!(
+                        "'click;{};' + event.ctrlKey + ';' + event.shiftKey",
+                        i
+                    )
+                ),
+                Event::change_js(&self.name, &format!("'activate;{}'", i)),
+                expandable,
+                expanded,
+                Event::change_js(&self.name, &format!("'toggle;{}'", i)),
+                icon,
+                node.text(),
+            ));
+        }
+        html.push_str("</div>");
+        format!("{}{}", style, html)
+    }
+
+    fn trigger(&mut self, event: &Event) {
+        match event {
+            Event::Update => self.on_update(),
+            Event::Change { source, value } => {
+                if source == &self.name && !self.state.disabled() {
+                    self.on_change(value);
+                }
+            }
+            Event::Keypress { source, keys } => {
+                if source == &self.name && !self.state.disabled() {
+                    self.on_keys(keys);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn on_update(&mut self) {
+        match &self.listener {
+            None => (),
+            Some(listener) => {
+                listener.on_update(&mut self.state);
+            }
+        }
+    }
+
+    fn on_change(&mut self, value: &str) {
+        let values = value.split(';').collect::<Vec<&str>>();
+        let index = match values.get(1).map(|index| index.parse::<usize>()) {
+            Some(Ok(index)) => index,
+            _ => return,
+        };
+        let node = match self.state.visible().get(index) {
+            Some(row) => (row.node.id().to_string(), row.node.expanded()),
+            None => return,
+        };
+        match values.as_slice() {
+            ["click", _, control, shift] => {
+                self.select(index, *control == "true", *shift == "true");
+            }
+            ["toggle", _] => self.expand(&node.0, !node.1),
+            ["activate", _] => self.activate(&node.0),
+            _ => (),
+        }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tooltip(&self) -> Option<&Tooltip> {
+        self.state.tooltip()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn treeview() -> TreeView {
+        let mut treeview = TreeView::new("treeview");
+        treeview.set_nodes(vec![
+            TreeNode::new("a", "A"),
+            TreeNode::new("b", "B"),
+            TreeNode::new("c", "C"),
+        ]);
+        treeview.set_selection_mode(SelectionMode::Multiple);
+        treeview
+    }
+
+    #[test]
+    fn single_selection_keeps_the_cursor_node() {
+        let mut treeview = treeview();
+        treeview.state.select(0, false, false);
+        treeview.state.select(2, true, false);
+        treeview.state.select(1, true, false);
+        treeview.set_selection_mode(SelectionMode::Single);
+        assert_eq!(treeview.state().selected(), &vec!["b".to_string()]);
+    }
+
+    #[test]
+    fn single_selection_keeps_the_first_selected_node() {
+        let mut treeview = treeview();
+        let selected = vec!["c".to_string(), "a".to_string()];
+        treeview.state_mut().set_selected(selected);
+        treeview.set_selection_mode(SelectionMode::Single);
+        assert_eq!(treeview.state().selected(), &vec!["c".to_string()]);
+    }
+}
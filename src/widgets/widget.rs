@@ -1,7 +1,32 @@
 use crate::utils::event::Event;
+use crate::utils::tooltip::Tooltip;
+use crate::utils::validation::Field;
+use std::any::Any;
+
+/// # Trait giving access to a widget as its concrete type
+///
+/// This trait is implemented for every type and lets a `dyn Widget` be
+/// downcast to the widget it holds.
+pub trait AsAny {
+    /// Return the widget as Any
+    fn as_any(&self) -> &dyn Any;
+
+    /// Return the widget as mutable Any
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: Any> AsAny for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
 
 /// # Trait that any of the widgets have to implement
-pub trait Widget {
+pub trait Widget: AsAny {
     /// Return the HTML representation of the widget
     fn eval(&self) -> String;
 
@@ -13,4 +38,104 @@ pub trait Widget {
 
     /// Function triggered on change event
     fn on_change(&mut self, _value: &str);
+
+    /// Get the name
+    ///
+    /// A widget without a name is never found by the lookup functions. The
+    /// names starting with `neutrino:` are reserved for the events of the
+    /// Window.
+    fn name(&self) -> &str {
+        ""
+    }
+
+    /// Get the children
+    fn children(&self) -> Vec<&dyn Widget> {
+        vec![]
+    }
+
+    /// Get the mutable children
+    fn children_mut(&mut self) -> Vec<&mut dyn Widget> {
+        vec![]
+    }
+
+    /// Get the widget as a validated field
+    fn field(&self) -> Option<&dyn Field> {
+        None
+    }
+
+    /// Get the widget as a mutable validated field
+    fn field_mut(&mut self) -> Option<&mut dyn Field> {
+        None
+    }
+
+    /// Get the tooltip
+    fn tooltip(&self) -> Option<&Tooltip> {
+        None
+    }
+}
+
+/// Find a widget by name in a widget tree
+pub fn find<'a>(root: &'a dyn Widget, name: &str) -> Option<&'a dyn Widget> {
+    if !name.is_empty() && root.name() == name {
+        return Some(root);
+    }
+    for child in root.children() {
+        if let Some(widget) = find(child, name) {
+            return Some(widget);
+        }
+    }
+    None
+}
+
+/// Find a mutable widget by name in a widget tree
+pub fn find_mut<'a>(
+    root: &'a mut dyn Widget,
+    name: &str,
+) -> Option<&'a mut dyn Widget> {
+    if !name.is_empty() && root.name() == name {
+        return Some(root);
+    }
+    for child in root.children_mut() {
+        if let Some(widget) = find_mut(child, name) {
+            return Some(widget);
+        }
+    }
+    None
+}
+
+/// Find a widget by name in a widget tree and downcast it to the type T
+///
+/// Return None if no widget has this name or if it is not a T.
+pub fn find_as<'a, T: Widget + 'static>(
+    root: &'a dyn Widget,
+    name: &str,
+) -> Option<&'a T> {
+    find(root, name).and_then(|widget| widget.as_any().downcast_ref::<T>())
+}
+
+/// Find a mutable widget by name in a widget tree and downcast it to the
+/// type T
+///
+/// Return None if no widget has this name or if it is not a T.
+pub fn find_as_mut<'a, T: Widget + 'static>(
+    root: &'a mut dyn Widget,
+    name: &str,
+) -> Option<&'a mut T> {
+    find_mut(root, name)
+        .and_then(|widget| widget.as_any_mut().downcast_mut::<T>())
+}
+
+/// Return the HTML representation of the tooltips of a widget tree
+///
+/// The tooltips are rendered apart from their widgets so that they are not
+/// clipped by the containers holding them.
+pub fn eval_tooltips(root: &dyn Widget) -> String {
+    let mut html = match root.tooltip() {
+        Some(tooltip) => tooltip.eval(root.name()),
+        None => "".to_string(),
+    };
+    for child in root.children() {
+        html.push_str(&eval_tooltips(child));
+    }
+    html
 }
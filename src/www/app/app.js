@@ -1,7 +1,32 @@
 let node = document.getElementById("app");
 
 function render(template) {
-    morphdom(node, template);
+    morphdom(node, template, {
+        onBeforeElUpdated: function(from, to) {
+            if (from !== document.activeElement) {
+                return true;
+            }
+            if (from.dataset.valueRequest !== to.dataset.valueRequest) {
+                return true;
+            }
+            if (from.type === "range") {
+                return true;
+            }
+            if (from.nodeName === "INPUT" || from.nodeName === "TEXTAREA") {
+                to.value = from.value;
+            }
+            if (from.nodeName === "INPUT") {
+                to.setAttribute("value", from.value);
+            }
+            return !from.isContentEditable;
+        }
+    });
+    restore();
+    reselect();
+    retip();
+    expire();
+    overflow();
+    paint();
 }
 
 function emit(arg) {
@@ -10,4 +35,540 @@ function emit(arg) {
 
 window.onload = function() {
     emit({ type: "Update" });
-}
\ No newline at end of file
+}
+function drag(event, id, index, vertical) {
+    event.preventDefault();
+    event.stopPropagation();
+    let splitter = document.getElementById(id);
+    let panes = Array.prototype.filter.call(splitter.children, function(child) {
+        return child.classList.contains("splitter-pane");
+    });
+    let position = function(e) {
+        return vertical ? e.clientY : e.clientX;
+    };
+    let sizes = panes.map(function(pane) {
+        return vertical ? pane.offsetHeight : pane.offsetWidth;
+    });
+    panes.forEach(function(pane, i) {
+        pane.style.flexGrow = sizes[i];
+    });
+    let limit = function(pane, size) {
+        let min = parseFloat(pane.getAttribute("data-min")) || 0;
+        let max = parseFloat(pane.getAttribute("data-max")) || Infinity;
+        return Math.min(Math.max(size, min), max);
+    };
+    let start = position(event);
+    let total = sizes[index] + sizes[index + 1];
+    let move = function(e) {
+        let before = limit(panes[index], sizes[index] + position(e) - start);
+        let after = limit(panes[index + 1], total - before);
+        panes[index].style.flexGrow = total - after;
+        panes[index + 1].style.flexGrow = after;
+    };
+    let up = function() {
+        document.removeEventListener("mousemove", move);
+        document.removeEventListener("mouseup", up);
+        let current = panes.map(function(pane) {
+            return parseFloat(pane.style.flexGrow) || 0;
+        });
+        let sum = current.reduce(function(a, b) { return a + b; }, 0);
+        emit({
+            type: "Change",
+            source: id,
+            value: "resize;" + current.map(function(size) {
+                return sum > 0 ? size / sum : 0;
+            }).join(",")
+        });
+    };
+    document.addEventListener("mousemove", move);
+    document.addEventListener("mouseup", up);
+}
+
+function resize(event, id, index) {
+    event.preventDefault();
+    event.stopPropagation();
+    let table = document.getElementById(id);
+    let column = table.querySelectorAll("col")[index];
+    let start = event.clientX;
+    let width = table.querySelectorAll("th")[index].offsetWidth;
+    let move = function(e) {
+        column.style.width = Math.max(width + e.clientX - start, 16) + "px";
+    };
+    let swallow = function(e) {
+        e.stopPropagation();
+    };
+    let up = function() {
+        document.removeEventListener("mousemove", move);
+        document.removeEventListener("mouseup", up);
+        document.addEventListener("click", swallow, true);
+        setTimeout(function() {
+            document.removeEventListener("click", swallow, true);
+        }, 0);
+        emit({
+            type: "Change",
+            source: id,
+            value: "resize;" + index + ";" + parseFloat(column.style.width)
+        });
+    };
+    document.addEventListener("mousemove", move);
+    document.addEventListener("mouseup", up);
+}
+
+function scrolled(area, delay) {
+    clearTimeout(area.scrollTimer);
+    area.scrollTimer = setTimeout(function() {
+        report(area);
+    }, delay);
+}
+
+function report(area) {
+    emit({
+        type: "Change",
+        source: area.id,
+        value: "scroll;" + area.scrollLeft + ";" + area.scrollTop + ";" +
+            area.clientWidth + ";" + area.clientHeight
+    });
+}
+
+function restore() {
+    let areas = document.querySelectorAll("[data-request]");
+    Array.prototype.forEach.call(areas, function(area) {
+        let viewport = area.getAttribute("data-viewport");
+        if (viewport !== null && parseFloat(viewport) !== area.clientHeight) {
+            report(area);
+        }
+        let request = area.getAttribute("data-request");
+        if (area.scrollRequest === request) {
+            return;
+        }
+        area.scrollRequest = request;
+        let target = document.getElementById(area.getAttribute("data-target"));
+        if (target) {
+            let offset = target.getBoundingClientRect();
+            let origin = area.getBoundingClientRect();
+            area.scrollLeft += offset.left - origin.left;
+            area.scrollTop += offset.top - origin.top;
+        } else {
+            area.scrollLeft = parseFloat(area.getAttribute("data-x")) || 0;
+            area.scrollTop = parseFloat(area.getAttribute("data-y")) || 0;
+        }
+    });
+}
+
+function navigate(event) {
+    let keys = [
+        "ArrowUp", "ArrowDown", "ArrowLeft", "ArrowRight",
+        "Home", "End", "PageUp", "PageDown", " "
+    ];
+    if (keys.indexOf(event.key) > -1) {
+        event.preventDefault();
+    }
+}
+
+function spin(event) {
+    let keys = ["ArrowUp", "ArrowDown", "PageUp", "PageDown"];
+    if (keys.indexOf(event.key) > -1) {
+        event.preventDefault();
+        return true;
+    }
+    return false;
+}
+
+function choose(event) {
+    let keys = ["ArrowUp", "ArrowDown", "Enter", "Escape"];
+    if (keys.indexOf(event.key) > -1) {
+        event.preventDefault();
+        return true;
+    }
+    return false;
+}
+
+function pick(event, opened) {
+    let keys = opened
+        ? [
+            "ArrowUp", "ArrowDown", "ArrowLeft", "ArrowRight",
+            "Home", "End", "PageUp", "PageDown", "Enter", "Escape"
+        ]
+        : ["ArrowDown", "Escape"];
+    if (keys.indexOf(event.key) > -1) {
+        event.preventDefault();
+        return true;
+    }
+    return false;
+}
+
+function cycle(event) {
+    let steps = {ArrowUp: -1, ArrowLeft: -1, ArrowDown: 1, ArrowRight: 1};
+    let current = event.currentTarget;
+    if (event.key === " ") {
+        event.preventDefault();
+        current.click();
+        return;
+    }
+    if (!(event.key in steps)) {
+        return;
+    }
+    event.preventDefault();
+    let buttons = Array.from(
+        document.querySelectorAll(".radio-button:not(.disabled)")
+    ).filter(function(button) {
+        return button.dataset.group === current.dataset.group;
+    });
+    let index = buttons.indexOf(current);
+    if (buttons.length === 0 || index === -1) {
+        return;
+    }
+    let next = buttons[
+        (index + steps[event.key] + buttons.length) % buttons.length
+    ];
+    next.focus();
+    next.click();
+}
+
+function locate(event) {
+    let area = event.currentTarget.getBoundingClientRect();
+    let x = (event.clientX - area.left) / area.width;
+    let y = (event.clientY - area.top) / area.height;
+    return Math.min(Math.max(x, 0), 1) + ";" + Math.min(Math.max(y, 0), 1);
+}
+
+function typeahead(event) {
+    if (event.ctrlKey || event.altKey || event.metaKey) {
+        return null;
+    }
+    if (event.key.length === 1) {
+        event.preventDefault();
+        return "type;" + event.key;
+    }
+    if (event.key === "Backspace") {
+        event.preventDefault();
+        return "back";
+    }
+    return null;
+}
+
+function selection(element) {
+    let count = function(index) {
+        return Array.from(element.value.slice(0, index)).length;
+    };
+    let start = count(element.selectionStart);
+    let end = count(element.selectionEnd);
+    let caret = element.selectionDirection === "backward" ? start : end;
+    return start + ";" + end + ";" + caret;
+}
+
+function reselect() {
+    let elements = document.querySelectorAll("[data-selection-request]");
+    Array.prototype.forEach.call(elements, function(element) {
+        let request = element.getAttribute("data-selection-request");
+        if (element.selectionRequest === request) {
+            return;
+        }
+        let initial = element.selectionRequest === undefined && request === "0";
+        element.selectionRequest = request;
+        if (initial) {
+            return;
+        }
+        let chars = Array.from(element.value);
+        let index = function(count) {
+            return chars.slice(0, count).join("").length;
+        };
+        let values = element.getAttribute("data-selection").split(";");
+        let start = index(parseInt(values[0], 10));
+        let end = index(parseInt(values[1], 10));
+        let backward = parseInt(values[2], 10) < parseInt(values[1], 10);
+        element.setSelectionRange(start, end, backward ? "backward" : "forward");
+    });
+}
+
+function markdown(element) {
+    let inline = function(node) {
+        if (node.nodeType === Node.TEXT_NODE) {
+            return node.textContent.replace(/([\\*])/g, "\\$1");
+        }
+        let text = Array.prototype.map.call(node.childNodes, inline).join("");
+        switch (node.nodeName) {
+            case "B":
+            case "STRONG":
+                return text.trim() ? "**" + text + "**" : text;
+            case "I":
+            case "EM":
+                return text.trim() ? "*" + text + "*" : text;
+            default:
+                return text;
+        }
+    };
+    let paragraph = function(text) {
+        return text
+            .replace(/^(\s*)- /, "$1\\- ")
+            .replace(/^(\s*\d+)\. /, "$1\\. ");
+    };
+    let lines = [];
+    let line = null;
+    let flush = function() {
+        if (line !== null) {
+            lines.push(paragraph(line));
+            line = null;
+        }
+    };
+    Array.prototype.forEach.call(element.childNodes, function(child) {
+        switch (child.nodeName) {
+            case "UL":
+            case "OL":
+                flush();
+                Array.prototype.forEach.call(child.children, function(item, i) {
+                    let bullet = child.nodeName === "UL" ? "- " : (i + 1) + ". ";
+                    lines.push(bullet + inline(item));
+                });
+                break;
+            case "DIV":
+            case "P":
+                flush();
+                lines.push(paragraph(inline(child)));
+                break;
+            case "BR":
+                flush();
+                break;
+            default:
+                line = (line || "") + inline(child);
+        }
+    });
+    flush();
+    return lines.join("\n");
+}
+
+function overflow() {
+    let toolbars = document.querySelectorAll(".toolbar");
+    Array.prototype.forEach.call(toolbars, function(toolbar) {
+        let items = toolbar.querySelector(".toolbar-items");
+        let limit = items.getBoundingClientRect().right;
+        let overflowed = false;
+        let children = Array.prototype.slice.call(items.children);
+        children.forEach(function(item) {
+            item.classList.remove("overflowed");
+        });
+        // The widgets never overflow, so the room they take after an item is
+        // kept free when checking whether the item fits
+        let rects = children.map(function(item) {
+            return item.getBoundingClientRect();
+        });
+        let widgets = 0;
+        children.forEach(function(item, i) {
+            if (item.classList.contains("toolbar-widget")) {
+                widgets += rects[i].width;
+            }
+        });
+        children.forEach(function(item, i) {
+            if (item.classList.contains("toolbar-widget")) {
+                widgets -= rects[i].width;
+                return;
+            }
+            overflowed = overflowed || rects[i].right + widgets > limit;
+            item.classList.toggle("overflowed", overflowed);
+            let entry = toolbar.querySelector(
+                '.toolbar-menuitem[data-index="' + item.dataset.index + '"]'
+            );
+            if (entry) {
+                entry.classList.toggle("overflowed", overflowed);
+            }
+        });
+        toolbar.querySelector(".toolbar-chevron")
+            .classList.toggle("shown", overflowed);
+    });
+}
+
+window.addEventListener("resize", overflow);
+
+function point(event) {
+    let canvas = event.currentTarget;
+    let box = canvas.getBoundingClientRect();
+    let x = (event.clientX - box.left) * canvas.width / box.width;
+    let y = (event.clientY - box.top) * canvas.height / box.height;
+    return x + ";" + y + ";" + event.buttons;
+}
+
+let images = {};
+
+function paint() {
+    let canvases = document.querySelectorAll("canvas[data-commands]");
+    Array.prototype.forEach.call(canvases, function(canvas) {
+        let key = canvas.width + ";" + canvas.height + ";" +
+            canvas.dataset.commands;
+        if (canvas.painted === key) {
+            return;
+        }
+        canvas.painted = key;
+        canvas.width = canvas.width;
+        let context = canvas.getContext("2d");
+        JSON.parse(canvas.dataset.commands).forEach(function(command) {
+            let name = command[0];
+            let args = command.slice(1);
+            if (name.charAt(0) === "=") {
+                context[name.slice(1)] = args[0];
+            } else if (name === "drawImage") {
+                let image = images[args[0]];
+                if (!image) {
+                    image = new Image();
+                    image.onload = function() {
+                        Array.prototype.forEach.call(canvases, function(c) {
+                            c.painted = null;
+                        });
+                        paint();
+                    };
+                    image.src = args[0];
+                    images[args[0]] = image;
+                }
+                if (image.complete) {
+                    context.drawImage.apply(
+                        context, [image].concat(args.slice(1))
+                    );
+                }
+            } else {
+                context[name].apply(context, args);
+            }
+        });
+    });
+}
+
+let expiries = {};
+
+function expire() {
+    let toasts = document.querySelectorAll(".toast[data-timeout]");
+    Array.prototype.forEach.call(toasts, function(toast) {
+        let id = toast.dataset.id;
+        if (id in expiries) {
+            return;
+        }
+        expiries[id] = setTimeout(function() {
+            emit({ type: "Change", source: "neutrino:toasts", value: "dismiss;" + id });
+        }, parseInt(toast.dataset.timeout, 10));
+    });
+}
+
+let hint = { id: null, shown: false, timer: null, keyboard: false };
+
+function owner(element) {
+    while (element && element !== node) {
+        if (element.id && document.querySelector(
+            '.tooltip[data-for="' + CSS.escape(element.id) + '"]'
+        )) {
+            return element;
+        }
+        element = element.parentElement;
+    }
+    return null;
+}
+
+function tooltip(id) {
+    return document.querySelector('.tooltip[data-for="' + CSS.escape(id) + '"]');
+}
+
+function place(tip, target) {
+    let gap = 6;
+    let box = target.getBoundingClientRect();
+    let width = tip.offsetWidth;
+    let height = tip.offsetHeight;
+    let points = {
+        top: [box.left + (box.width - width) / 2, box.top - height - gap],
+        bottom: [box.left + (box.width - width) / 2, box.bottom + gap],
+        left: [box.left - width - gap, box.top + (box.height - height) / 2],
+        right: [box.right + gap, box.top + (box.height - height) / 2]
+    };
+    let opposite = { top: "bottom", bottom: "top", left: "right", right: "left" };
+    let fits = function(side) {
+        let point = points[side];
+        return point[0] >= 0 && point[1] >= 0 &&
+            point[0] + width <= window.innerWidth &&
+            point[1] + height <= window.innerHeight;
+    };
+    let side = tip.dataset.position;
+    if (!fits(side) && fits(opposite[side])) {
+        side = opposite[side];
+    }
+    let clamp = function(value, max) {
+        return Math.max(0, Math.min(value, max));
+    };
+    tip.style.left = clamp(points[side][0], window.innerWidth - width) + "px";
+    tip.style.top = clamp(points[side][1], window.innerHeight - height) + "px";
+    ["top", "bottom", "left", "right"].forEach(function(position) {
+        tip.classList.toggle("tooltip-" + position, position === side);
+    });
+}
+
+function showTip() {
+    let tip = tooltip(hint.id);
+    let target = document.getElementById(hint.id);
+    if (!tip || !target) {
+        hideTip();
+        return;
+    }
+    hint.shown = true;
+    tip.classList.add("shown");
+    place(tip, target);
+}
+
+function hideTip() {
+    clearTimeout(hint.timer);
+    if (hint.id !== null && hint.shown) {
+        let tip = tooltip(hint.id);
+        if (tip) {
+            tip.classList.remove("shown");
+        }
+    }
+    hint.id = null;
+    hint.shown = false;
+}
+
+function retip() {
+    if (hint.shown) {
+        showTip();
+    }
+}
+
+document.addEventListener("mouseover", function(event) {
+    let target = owner(event.target);
+    if (target && target.id === hint.id) {
+        return;
+    }
+    hideTip();
+    if (target) {
+        hint.id = target.id;
+        hint.timer = setTimeout(
+            showTip, parseInt(tooltip(hint.id).dataset.delay, 10) || 0
+        );
+    }
+});
+
+document.addEventListener("mouseout", function(event) {
+    if (event.relatedTarget === null) {
+        hideTip();
+    }
+});
+
+document.addEventListener("mousedown", function() {
+    hint.keyboard = false;
+    hideTip();
+}, true);
+
+document.addEventListener("keydown", function(event) {
+    hint.keyboard = true;
+    if (event.key === "Escape") {
+        hideTip();
+    }
+}, true);
+
+document.addEventListener("focusin", function(event) {
+    let target = owner(event.target);
+    if (!hint.keyboard || !target) {
+        return;
+    }
+    hideTip();
+    hint.id = target.id;
+    showTip();
+});
+
+document.addEventListener("focusout", function(event) {
+    if (hint.keyboard && !owner(event.relatedTarget)) {
+        hideTip();
+    }
+});